@@ -0,0 +1,72 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+
+/* ----------------- Blueprint ---------------- */
+#[blueprint]
+mod mock_dex {
+    //] --------------- Mock Blueprint ------------- */
+    /// Constant-product DEX pool over a native `TwoResourcePool`, with the interface of an Ociswap basic pool, for
+    /// use in tests only. Swaps charge no fee.
+    struct MockDex {
+        pool: Global<TwoResourcePool>,
+    }
+
+    impl MockDex {
+        /// Instantiates a new `MockDex` component and its empty liquidity pool. The pool unit is the first resource
+        /// created by the transaction.
+        ///
+        /// # Parameters
+        /// - `a`: Resource address of the first resource.
+        /// - `b`: Resource address of the second resource.
+        ///
+        /// # Returns
+        /// A globally accessible `MockDex` component instance.
+        pub fn instantiate(a: ResourceAddress, b: ResourceAddress) -> Global<MockDex> {
+            assert_ne!(a, b, "Pool resources must differ");
+
+            let (address_reservation, component_address) = Runtime::allocate_component_address(MockDex::blueprint_id());
+            let pool = Blueprint::<TwoResourcePool>::instantiate(OwnerRole::None, rule!(require(global_caller(component_address))), (a, b), None);
+
+            Self { pool }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .with_address(address_reservation)
+                .globalize()
+        }
+
+        /// Provides liquidity to the pool, returning the pool units and any unused input.
+        pub fn add_liquidity(&mut self, a: FungibleBucket, b: FungibleBucket) -> (FungibleBucket, Option<FungibleBucket>) {
+            self.pool.contribute((a, b))
+        }
+
+        /// Redeems pool units for the underlying resources.
+        pub fn remove_liquidity(&mut self, lp: FungibleBucket) -> (FungibleBucket, FungibleBucket) {
+            self.pool.redeem(lp)
+        }
+
+        /// Swaps the input for the other resource of the pool, along the constant-product curve.
+        pub fn swap(&mut self, input: FungibleBucket) -> FungibleBucket {
+            let (input_reserve, output_resource, output_reserve) = self.__reserves(input.resource_address());
+            let output = output_reserve * input.amount() / (input_reserve + input.amount());
+
+            self.pool.protected_deposit(input);
+            self.pool
+                .protected_withdraw(output_resource, output, WithdrawStrategy::Rounded(RoundingMode::ToZero))
+        }
+
+        /// Returns the address of the underlying liquidity pool.
+        pub fn liquidity_pool(&self) -> ComponentAddress {
+            self.pool.address()
+        }
+
+        //] Private
+        /// Returns the reserve of `resource`, and the other resource of the pool and its reserve.
+        fn __reserves(&self, resource: ResourceAddress) -> (Decimal, ResourceAddress, Decimal) {
+            let reserves = self.pool.get_vault_amounts();
+            let reserve = *reserves.get(&resource).expect("Resource not in the pool");
+            let (&other, &other_reserve) = reserves.iter().find(|(address, _)| **address != resource).unwrap();
+
+            (reserve, other, other_reserve)
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub mod dex;
+pub mod oracle;
+pub mod root;
+pub mod weft;
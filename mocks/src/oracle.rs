@@ -0,0 +1,45 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+use shared::oracles::PriceInfo;
+use shared::utils::now;
+
+/* ----------------- Blueprint ---------------- */
+#[blueprint]
+mod mock_oracle {
+    //] --------------- Mock Blueprint ------------- */
+    /// Price oracle with the interface of Root's, reporting prices set by the tester, for use in tests only.
+    struct MockOracle {
+        prices: KeyValueStore<ResourceAddress, PriceInfo>,
+    }
+
+    impl MockOracle {
+        /// Instantiates a new `MockOracle` component without prices.
+        ///
+        /// # Returns
+        /// A globally accessible `MockOracle` component instance.
+        pub fn instantiate() -> Global<MockOracle> {
+            Self { prices: KeyValueStore::new() }
+                .instantiate()
+                .prepare_to_globalize(OwnerRole::None)
+                .globalize()
+        }
+
+        /// Sets the price of a resource, in the oracle's common unit.
+        ///
+        /// # Parameters
+        /// - `resource`: Resource address of the priced asset.
+        /// - `price`: Price of the asset.
+        /// - `timestamp`: Time of the update, in seconds since the unix epoch; now if `None`.
+        pub fn set_price(&mut self, resource: ResourceAddress, price: Decimal, timestamp: Option<i64>) {
+            assert!(price > dec!(0), "Price must be positive");
+
+            let timestamp = timestamp.unwrap_or_else(now);
+            self.prices.insert(resource, PriceInfo { timestamp, price });
+        }
+
+        /// Returns the price of a resource and the time it was set.
+        pub fn get_price(&self, resource: ResourceAddress) -> PriceInfo {
+            *self.prices.get(&resource).expect("No price for the resource")
+        }
+    }
+}
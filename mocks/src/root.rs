@@ -0,0 +1,232 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+use shared::utils::now;
+
+/* ----------------- Blueprint ---------------- */
+#[blueprint]
+mod mock_root_market {
+    //] --------------- Mock Blueprint ------------- */
+    /// Lending market with the CDP interface of Root, for use in tests only. Loans accrue no interest, so loan
+    /// units equal amounts, and CDPs are not health checked. Mints its own CDPs, with integer IDs from 0; the CDP
+    /// resource is the first resource created by the transaction.
+    struct MockRootMarket {
+        cdp_manager: NonFungibleResourceManager,
+        cdp_count: u64,
+        vaults: KeyValueStore<ResourceAddress, FungibleVault>,
+        market_states: KeyValueStore<ResourceAddress, MarketState>,
+    }
+
+    impl MockRootMarket {
+        /// Instantiates a new `MockRootMarket` component and its CDP resource.
+        ///
+        /// # Returns
+        /// A globally accessible `MockRootMarket` component instance.
+        pub fn instantiate() -> Global<MockRootMarket> {
+            let (address_reservation, component_address) = Runtime::allocate_component_address(MockRootMarket::blueprint_id());
+            let component_rule = rule!(require(global_caller(component_address)));
+
+            let cdp_manager = ResourceBuilder::new_integer_non_fungible::<CollaterizedDebtPositionData>(OwnerRole::None)
+                .mint_roles(mint_roles! {
+                    minter         => component_rule.clone();
+                    minter_updater => rule!(deny_all);
+                })
+                .non_fungible_data_update_roles(non_fungible_data_update_roles! {
+                    non_fungible_data_updater         => component_rule;
+                    non_fungible_data_updater_updater => rule!(deny_all);
+                })
+                .create_with_no_initial_supply();
+
+            Self {
+                cdp_manager,
+                cdp_count: 0,
+                vaults: KeyValueStore::new(),
+                market_states: KeyValueStore::new(),
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .with_address(address_reservation)
+            .globalize()
+        }
+
+        //] Tester
+        /// Deposits liquidity to be borrowed.
+        pub fn deposit_liquidity(&mut self, liquidity: FungibleBucket) {
+            self.__put(liquidity.into());
+        }
+
+        /// Sets the lending state of a resource; interest rates are per second.
+        pub fn set_market_state(&mut self, resource: ResourceAddress, state: MarketState) {
+            self.market_states.insert(resource, state);
+        }
+
+        //] CDPs
+        /// Opens a CDP with the given collaterals, borrowing the given loans against it.
+        ///
+        /// # Returns
+        /// - `NonFungibleBucket`: The new CDP.
+        /// - `Vec<Bucket>`: The borrowed funds.
+        pub fn create_cdp(&mut self, collaterals: Vec<Bucket>, loans: Vec<(ResourceAddress, Decimal)>) -> (NonFungibleBucket, Vec<Bucket>) {
+            let mut cdp = CollaterizedDebtPositionData {
+                key_image_url: String::new(),
+                name: format!("Mock Root CDP {}", self.cdp_count),
+                description: String::new(),
+                minted_at: now(),
+                updated_at: now(),
+                cdp_type: CDPType::Standard,
+                collaterals: IndexMap::new(),
+                loans: IndexMap::new(),
+                liquidable: None,
+            };
+
+            for bucket in collaterals {
+                let resource = bucket.resource_address();
+                let amount = PreciseDecimal::from(bucket.amount());
+                self.__put(bucket);
+
+                *cdp.collaterals.entry(resource).or_insert(PreciseDecimal::ZERO) += amount;
+            }
+
+            let mut borrowed = Vec::new();
+            for (resource, amount) in loans {
+                *cdp.loans.entry(resource).or_insert(PreciseDecimal::ZERO) += PreciseDecimal::from(amount);
+
+                borrowed.push(self.__take(resource, amount));
+            }
+
+            let cdp_id = NonFungibleLocalId::integer(self.cdp_count);
+            self.cdp_count += 1;
+
+            (self.cdp_manager.mint_non_fungible(&cdp_id, cdp), borrowed)
+        }
+
+        /// Withdraws collateral from the CDP; each withdrawal is a resource, an amount, and whether to keep the
+        /// deposit units, which the mock ignores.
+        pub fn remove_collateral(&mut self, cdp_proof: NonFungibleProof, withdrawals: Vec<(ResourceAddress, Decimal, bool)>) -> Vec<Bucket> {
+            let cdp_id = self.__check(cdp_proof);
+            let mut cdp = self.cdp_manager.get_non_fungible_data::<CollaterizedDebtPositionData>(&cdp_id);
+
+            let mut withdrawn = Vec::new();
+            for (resource, amount, _) in withdrawals {
+                let collateral = cdp.collaterals.get_mut(&resource).expect("No collateral of the resource");
+                assert!(*collateral >= PreciseDecimal::from(amount), "Insufficient collateral");
+
+                *collateral -= PreciseDecimal::from(amount);
+                if *collateral == PreciseDecimal::ZERO {
+                    cdp.collaterals.swap_remove(&resource);
+                }
+
+                withdrawn.push(self.__take(resource, amount));
+            }
+
+            self.__update(&cdp_id, cdp);
+
+            withdrawn
+        }
+
+        /// Repays the CDP's loans, returning any excess payment.
+        pub fn repay(&mut self, cdp_proof: NonFungibleProof, payments: Vec<Bucket>) -> Vec<Bucket> {
+            let cdp_id = self.__check(cdp_proof);
+            let mut cdp = self.cdp_manager.get_non_fungible_data::<CollaterizedDebtPositionData>(&cdp_id);
+
+            let mut excess = Vec::new();
+            for mut payment in payments {
+                let resource = payment.resource_address();
+                let loan = cdp.loans.get_mut(&resource).expect("No loan of the resource");
+
+                let repaid = payment.amount().min(loan.checked_truncate(RoundingMode::ToPositiveInfinity).unwrap());
+                *loan = (*loan - PreciseDecimal::from(repaid)).max(PreciseDecimal::ZERO);
+                if *loan == PreciseDecimal::ZERO {
+                    cdp.loans.swap_remove(&resource);
+                }
+
+                self.__put(payment.take(repaid));
+                excess.push(payment);
+            }
+
+            self.__update(&cdp_id, cdp);
+
+            excess
+        }
+
+        //] Market State
+        /// Returns the lending state of the given resources, as set by the tester.
+        pub fn get_market_state(&self, resources: IndexSet<ResourceAddress>) -> IndexMap<ResourceAddress, MarketState> {
+            resources
+                .into_iter()
+                .map(|resource| {
+                    (
+                        resource,
+                        self.market_states.get(&resource).expect("No market state for the resource").clone(),
+                    )
+                })
+                .collect()
+        }
+
+        //] Private
+        /// Checks the CDP proof and returns the CDP's local ID.
+        fn __check(&self, cdp_proof: NonFungibleProof) -> NonFungibleLocalId {
+            cdp_proof
+                .check_with_message(self.cdp_manager.address(), "Invalid CDP")
+                .non_fungible_local_id()
+        }
+
+        /// Writes the CDP's positions back to its data.
+        fn __update(&self, cdp_id: &NonFungibleLocalId, cdp: CollaterizedDebtPositionData) {
+            self.cdp_manager.update_non_fungible_data(cdp_id, "collaterals", cdp.collaterals);
+            self.cdp_manager.update_non_fungible_data(cdp_id, "loans", cdp.loans);
+            self.cdp_manager.update_non_fungible_data(cdp_id, "updated_at", now());
+        }
+
+        fn __put(&mut self, bucket: Bucket) {
+            let resource = bucket.resource_address();
+
+            if self.vaults.get(&resource).is_none() {
+                self.vaults.insert(resource, FungibleVault::new(resource));
+            }
+            self.vaults.get_mut(&resource).unwrap().put(bucket.as_fungible());
+        }
+
+        fn __take(&mut self, resource: ResourceAddress, amount: Decimal) -> Bucket {
+            let mut vault = self.vaults.get_mut(&resource).expect("No liquidity of the resource");
+
+            vault.take(amount).into()
+        }
+    }
+}
+
+/* --------------- Market State --------------- */
+/// Lending state of a resource on the market; interest rates are per second.
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct MarketState {
+    pub total_deposit: Decimal,
+    pub total_loan: Decimal,
+    pub supply_rate: Decimal,
+    pub borrow_rate: Decimal,
+}
+
+/* --------------- CDP Breakdown -------------- */
+#[derive(ScryptoSbor, NonFungibleData, Clone, Debug)]
+pub struct CollaterizedDebtPositionData {
+    #[mutable]
+    pub key_image_url: String,
+    #[mutable]
+    pub name: String,
+    #[mutable]
+    pub description: String,
+    pub minted_at: i64,
+    #[mutable]
+    pub updated_at: i64,
+    #[mutable]
+    pub cdp_type: CDPType,
+    #[mutable]
+    pub collaterals: IndexMap<ResourceAddress, PreciseDecimal>,
+    #[mutable]
+    pub loans: IndexMap<ResourceAddress, PreciseDecimal>,
+    #[mutable]
+    pub liquidable: Option<Decimal>,
+}
+
+#[derive(ScryptoSbor, Clone, PartialEq, Debug)]
+pub enum CDPType {
+    Standard,
+}
@@ -0,0 +1,469 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+
+/* ----------------- Blueprint ---------------- */
+#[blueprint]
+mod mock_weft_market {
+    //] --------------- Mock Blueprint ------------- */
+    /// Lending market with the CDP interface of Weft V2, for use in tests only. Loans accrue no interest, so loan
+    /// units equal amounts, and positions are valued at prices set by the tester.
+    struct MockWeftMarket {
+        cdp_manager: NonFungibleResourceManager,
+        vaults: KeyValueStore<ResourceAddress, FungibleVault>,
+        prices: KeyValueStore<ResourceAddress, Decimal>,
+        /// Value of the loans per unit of collateral value a CDP may reach through borrows and withdrawals.
+        loan_to_value: Decimal,
+        /// Value of the loans per unit of collateral value at which a CDP is liquidatable.
+        liquidation_threshold: Decimal,
+        /// Entry version of the collateral configs, taken on by a collateral position whenever it is deposited to.
+        collateral_config_version: u64,
+    }
+
+    impl MockWeftMarket {
+        /// Instantiates a new `MockWeftMarket` component for the given CDP resource. The CDP resource must allow
+        /// anyone to update its data, as the one minted by the tests does.
+        ///
+        /// # Parameters
+        /// - `cdp_resource`: Resource address of the CDP NFT.
+        ///
+        /// # Returns
+        /// A globally accessible `MockWeftMarket` component instance.
+        pub fn instantiate(cdp_resource: ResourceAddress) -> Global<MockWeftMarket> {
+            Self {
+                cdp_manager: cdp_resource.into(),
+                vaults: KeyValueStore::new(),
+                prices: KeyValueStore::new(),
+                loan_to_value: dec!(0.8),
+                liquidation_threshold: dec!(0.85),
+                collateral_config_version: 1,
+            }
+            .instantiate()
+            .prepare_to_globalize(OwnerRole::None)
+            .globalize()
+        }
+
+        //] Tester
+        /// Sets the price of a resource, in the market's common unit.
+        pub fn set_price(&mut self, resource: ResourceAddress, price: Decimal) {
+            assert!(price > dec!(0), "Price must be positive");
+            self.prices.insert(resource, price);
+        }
+
+        /// Deposits liquidity to be borrowed.
+        pub fn deposit_liquidity(&mut self, liquidity: FungibleBucket) {
+            self.__put(liquidity.into());
+        }
+
+        /// Updates the collateral configs, bumping their entry version as Weft V2 does; positions take on the new
+        /// version on their next deposit.
+        pub fn update_collateral_config(&mut self) {
+            self.collateral_config_version += 1;
+        }
+
+        //] CDPs
+        /// Deposits collateral into the CDP.
+        pub fn add_collateral(&mut self, cdp_proof: NonFungibleProof, collaterals: Vec<Bucket>) {
+            let cdp_id = self.__check(cdp_proof);
+            let mut cdp = self.cdp_manager.get_non_fungible_data::<CDPData>(&cdp_id);
+
+            let config_version = CollateralConfigVersion {
+                entry_version: self.collateral_config_version,
+                efficiency_mode: EfficiencyMode::None,
+            };
+
+            for bucket in collaterals {
+                let resource = bucket.resource_address();
+                let amount = bucket.amount();
+                self.__put(bucket);
+
+                match cdp.collaterals.get_mut(&resource) {
+                    Some(collateral) => {
+                        collateral.amount += amount;
+                        collateral.config_version = config_version;
+                    }
+                    None => {
+                        cdp.collaterals.insert(resource, CollateralInfo { amount, config_version });
+                    }
+                }
+            }
+
+            self.__update(&cdp_id, cdp);
+        }
+
+        /// Withdraws collateral from the CDP; each withdrawal is a resource and an amount.
+        pub fn remove_collateral(&mut self, cdp_proof: NonFungibleProof, withdrawals: Vec<(ResourceAddress, Decimal, bool)>) -> Vec<Bucket> {
+            let cdp_id = self.__check(cdp_proof);
+            let mut cdp = self.cdp_manager.get_non_fungible_data::<CDPData>(&cdp_id);
+
+            let mut withdrawn = Vec::new();
+            for (resource, amount, _) in withdrawals {
+                let collateral = cdp.collaterals.get_mut(&resource).expect("No collateral of the resource");
+                assert!(collateral.amount >= amount, "Insufficient collateral");
+
+                collateral.amount -= amount;
+                if collateral.amount == dec!(0) {
+                    cdp.collaterals.swap_remove(&resource);
+                }
+
+                withdrawn.push(self.__take(resource, amount));
+            }
+
+            self.__assert_healthy(&cdp);
+            self.__update(&cdp_id, cdp);
+
+            withdrawn
+        }
+
+        /// Borrows against the CDP; each loan is a resource and an amount.
+        pub fn borrow(&mut self, cdp_proof: NonFungibleProof, loans: Vec<(ResourceAddress, Decimal)>) -> Vec<Bucket> {
+            let cdp_id = self.__check(cdp_proof);
+            let mut cdp = self.cdp_manager.get_non_fungible_data::<CDPData>(&cdp_id);
+
+            let mut borrowed = Vec::new();
+            for (resource, amount) in loans {
+                match cdp.loans.get_mut(&resource) {
+                    Some(loan) => loan.units += amount,
+                    None => {
+                        cdp.loans.insert(resource, LoanInfo { units: amount, config_version: 1 });
+                    }
+                }
+
+                borrowed.push(self.__take(resource, amount));
+            }
+
+            self.__assert_healthy(&cdp);
+            self.__update(&cdp_id, cdp);
+
+            borrowed
+        }
+
+        /// Repays the CDP's loans, returning any excess payment.
+        pub fn repay(&mut self, cdp_proof: NonFungibleProof, payments: Vec<Bucket>) -> Vec<Bucket> {
+            let cdp_id = self.__check(cdp_proof);
+            let mut cdp = self.cdp_manager.get_non_fungible_data::<CDPData>(&cdp_id);
+
+            let mut excess = Vec::new();
+            for mut payment in payments {
+                let resource = payment.resource_address();
+                let loan = cdp.loans.get_mut(&resource).expect("No loan of the resource");
+
+                let repaid = payment.amount().min(loan.units);
+                loan.units -= repaid;
+                if loan.units == dec!(0) {
+                    cdp.loans.swap_remove(&resource);
+                }
+
+                self.__put(payment.take(repaid));
+                excess.push(payment);
+            }
+
+            self.__update(&cdp_id, cdp);
+
+            excess
+        }
+
+        /// Returns the health breakdown of the given CDPs.
+        pub fn get_cdp(&self, cdp_ids: IndexSet<NonFungibleLocalId>) -> IndexMap<NonFungibleLocalId, CDPHealthChecker> {
+            cdp_ids
+                .into_iter()
+                .map(|cdp_id| {
+                    let cdp = self.cdp_manager.get_non_fungible_data::<CDPData>(&cdp_id);
+                    (cdp_id, self.__health(&cdp))
+                })
+                .collect()
+        }
+
+        //] Private
+        /// Checks the CDP proof and returns the CDP's local ID.
+        fn __check(&self, cdp_proof: NonFungibleProof) -> NonFungibleLocalId {
+            cdp_proof
+                .check_with_message(self.cdp_manager.address(), "Invalid CDP")
+                .non_fungible_local_id()
+        }
+
+        /// Writes the CDP's positions back to its data.
+        fn __update(&self, cdp_id: &NonFungibleLocalId, cdp: CDPData) {
+            self.cdp_manager.update_non_fungible_data(cdp_id, "collaterals", cdp.collaterals);
+            self.cdp_manager.update_non_fungible_data(cdp_id, "loans", cdp.loans);
+        }
+
+        fn __put(&mut self, bucket: Bucket) {
+            let resource = bucket.resource_address();
+
+            if self.vaults.get(&resource).is_none() {
+                self.vaults.insert(resource, FungibleVault::new(resource));
+            }
+            self.vaults.get_mut(&resource).unwrap().put(bucket.as_fungible());
+        }
+
+        fn __take(&mut self, resource: ResourceAddress, amount: Decimal) -> Bucket {
+            let mut vault = self.vaults.get_mut(&resource).expect("No liquidity of the resource");
+
+            vault.take(amount).into()
+        }
+
+        fn __price(&self, resource: ResourceAddress) -> Decimal {
+            *self.prices.get(&resource).expect("No price for the resource")
+        }
+
+        fn __assert_healthy(&self, cdp: &CDPData) {
+            let health = self.__health(cdp);
+            assert!(health.health_ltv <= dec!(1), "CDP would be unhealthy");
+        }
+
+        /// Values the CDP's positions at the set prices.
+        fn __health(&self, cdp: &CDPData) -> CDPHealthChecker {
+            let collateral_positions: IndexMap<ResourceAddress, CollateralPositionData> = cdp
+                .collaterals
+                .iter()
+                .map(|(&resource, collateral)| {
+                    let price = self.__price(resource);
+                    let value = collateral.amount * price;
+
+                    let position = CollateralPositionData {
+                        price,
+                        amount: collateral.amount,
+                        value,
+                        health_value: value * self.loan_to_value,
+                        liquidation_value: value * self.liquidation_threshold,
+                        discounted_value: value,
+                        config: CollateralConfig {
+                            description: String::new(),
+                            loan_to_value_ratio: self.loan_to_value,
+                            liquidation_threshold_spread: self.liquidation_threshold - self.loan_to_value,
+                            liquidation_bonus_rate: dec!(0.05),
+                        },
+                        config_version: collateral.config_version,
+                        resource_config: CollateralResourceConfig {
+                            collateral_config_id: 0,
+                            isolation_group_id: None,
+                            efficiency_group_ids: IndexSet::new(),
+                        },
+                        is_from_nft: false,
+                        resource_type: RegisteredResourceType::RegisteredToken,
+                    };
+
+                    (resource, position)
+                })
+                .collect();
+
+            let loan_positions: IndexMap<ResourceAddress, LoanPositionData> = cdp
+                .loans
+                .iter()
+                .map(|(&resource, loan)| {
+                    let price = self.__price(resource);
+                    let value = loan.units * price;
+
+                    let position = LoanPositionData {
+                        price,
+                        units: loan.units,
+                        amount: loan.units,
+                        value,
+                        adjusted_value: value,
+                        config: LoanConfig {
+                            description: String::new(),
+                            loan_value_factor: dec!(1),
+                            loan_close_factor: dec!(0.5),
+                        },
+                        config_version: loan.config_version,
+                        resource_config: LoanResourceConfig {
+                            loan_config_id: 0,
+                            excluded_isolation_group_ids: IndexSet::new(),
+                            efficiency_group_id: None,
+                        },
+                    };
+
+                    (resource, position)
+                })
+                .collect();
+
+            let total_loan_value = loan_positions.values().fold(dec!(0), |total, position| total + position.value);
+            let total_collateral_value = collateral_positions.values().fold(dec!(0), |total, position| total + position.value);
+            let total_health_collateral_value = total_collateral_value * self.loan_to_value;
+            let total_liquidation_collateral_value = total_collateral_value * self.liquidation_threshold;
+
+            let ratio = |collateral_value: Decimal| match collateral_value > dec!(0) {
+                true => total_loan_value / collateral_value,
+                false => dec!(0),
+            };
+
+            CDPHealthChecker {
+                total_loan_value,
+                total_adjusted_loan_value: total_loan_value,
+                total_collateral_value,
+                total_health_collateral_value,
+                total_liquidation_collateral_value,
+                collateral_isolation_groups: IndexSet::new(),
+                loan_excluded_isolation_groups: IndexSet::new(),
+                health_ltv: ratio(total_health_collateral_value),
+                liquidation_ltv: ratio(total_liquidation_collateral_value),
+                discounted_nft_collateral_value: dec!(0),
+                discounted_collateral_value: total_collateral_value,
+                loan_positions,
+                collateral_positions,
+                nft_collateral_positions: IndexMap::new(),
+            }
+        }
+    }
+}
+
+/* --------------- CDP Breakdown -------------- */
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct CDPHealthChecker {
+    pub total_loan_value: Decimal,
+    pub total_adjusted_loan_value: Decimal,
+
+    pub total_collateral_value: Decimal,
+    pub total_health_collateral_value: Decimal,
+    pub total_liquidation_collateral_value: Decimal,
+
+    pub collateral_isolation_groups: IndexSet<u16>,
+    pub loan_excluded_isolation_groups: IndexSet<u16>,
+
+    pub health_ltv: Decimal,
+    pub liquidation_ltv: Decimal,
+
+    pub discounted_nft_collateral_value: Decimal,
+    pub discounted_collateral_value: Decimal,
+
+    pub loan_positions: IndexMap<ResourceAddress, LoanPositionData>,
+    pub collateral_positions: IndexMap<ResourceAddress, CollateralPositionData>,
+    pub nft_collateral_positions: IndexMap<ResourceAddress, IndexMap<NonFungibleLocalId, NFTCollateralPositionData>>,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct LoanPositionData {
+    pub price: Decimal,
+    pub units: Decimal,
+    pub amount: Decimal,
+    pub value: Decimal,
+    pub adjusted_value: Decimal,
+    pub config: LoanConfig,
+    pub config_version: u64,
+    pub resource_config: LoanResourceConfig,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct LoanConfig {
+    pub description: String,
+    pub loan_value_factor: Decimal,
+    pub loan_close_factor: Decimal,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct LoanResourceConfig {
+    pub loan_config_id: u16,
+    pub excluded_isolation_group_ids: IndexSet<u16>,
+    pub efficiency_group_id: Option<u16>,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct CollateralPositionData {
+    pub price: Decimal,
+    pub amount: Decimal,
+    pub value: Decimal,
+    pub health_value: Decimal,
+    pub liquidation_value: Decimal,
+    pub discounted_value: Decimal,
+    pub config: CollateralConfig,
+    pub config_version: CollateralConfigVersion,
+    pub resource_config: CollateralResourceConfig,
+    pub is_from_nft: bool,
+    pub resource_type: RegisteredResourceType,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct CollateralConfig {
+    pub description: String,
+    pub loan_to_value_ratio: Decimal,
+    pub liquidation_threshold_spread: Decimal,
+    pub liquidation_bonus_rate: Decimal,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct CollateralResourceConfig {
+    pub collateral_config_id: u16,
+    pub isolation_group_id: Option<u16>,
+    pub efficiency_group_ids: IndexSet<u16>,
+}
+
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq)]
+pub enum RegisteredResourceType {
+    RegisteredToken,
+    LSU(ComponentAddress),
+    DepositUnit(ResourceAddress),
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct NFTCollateralPositionData {
+    pub underlying_positions: IndexMap<ResourceAddress, CollateralPositionData>,
+    pub value: NFTLiquidationValue,
+    pub max_allowed_discounted_value: Decimal,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct NFTLiquidationValue {
+    pub value: Decimal,
+    pub discounted_value: Decimal,
+    pub loan_payment_value: Decimal,
+    pub compensation_value: Decimal,
+    pub liquidation_fee: Decimal,
+    pub resource_type: RegisteredNFTResourceType,
+}
+
+#[derive(ScryptoSbor, Debug, Clone, Copy)]
+pub enum RegisteredNFTResourceType {
+    RegisteredNFT,
+    ClaimNFT(ComponentAddress),
+}
+
+/* --------------- Raw CDP Data --------------- */
+#[derive(ScryptoSbor, NonFungibleData, Debug, Clone)]
+pub struct CDPData {
+    minted_at: Instant,
+    #[mutable]
+    updated_at: Instant,
+    #[mutable]
+    key_image_url: String,
+    #[mutable]
+    name: String,
+    #[mutable]
+    description: String,
+    #[mutable]
+    pub loans: IndexMap<ResourceAddress, LoanInfo>,
+    #[mutable]
+    pub collaterals: IndexMap<ResourceAddress, CollateralInfo>,
+    #[mutable]
+    pub nft_collaterals: IndexMap<ResourceAddress, NFTCollateralInfo>,
+}
+
+#[derive(ScryptoSbor, Debug, Clone, PartialEq, Copy)]
+pub enum EfficiencyMode {
+    None,
+    EfficiencyGroup(u16),
+    IdenticalResource,
+}
+
+#[derive(ScryptoSbor, Debug, Clone, PartialEq, Copy)]
+pub struct CollateralConfigVersion {
+    pub entry_version: u64,
+    pub efficiency_mode: EfficiencyMode,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct CollateralInfo {
+    pub amount: Decimal,
+    pub config_version: CollateralConfigVersion,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct NFTCollateralInfo {
+    pub nft_ids: IndexSet<NonFungibleLocalId>,
+    pub config_version: IndexMap<ResourceAddress, CollateralConfigVersion>,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct LoanInfo {
+    pub units: Decimal,
+    pub config_version: u64,
+}
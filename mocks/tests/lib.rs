@@ -0,0 +1,7 @@
+/* ------------------ Imports ----------------- */
+use mocks::weft::mock_weft_market_test::*;
+use scrypto_test::prelude::*;
+
+/* ------------------- Tests ------------------ */
+#[test]
+fn test() {}
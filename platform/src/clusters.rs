@@ -1,6 +1,7 @@
 /* ------------------ Imports ----------------- */
 use crate::services::cluster_services::ClusterServiceManager;
 use scrypto::prelude::*;
+use shared::services::ServicePolicy;
 
 /* -------------- Cluster Wrapper ------------- */
 #[derive(ScryptoSbor, Debug, Clone)]
@@ -13,7 +14,12 @@ pub struct ClusterWrapper {
 
 impl ClusterWrapper {
     pub fn new(cluster_address: ComponentAddress, blueprint_id: BlueprintId, link_id: NonFungibleLocalId) -> Self {
-        Self { cluster_address, blueprint_id, link_id, services: ClusterServiceManager::new() }
+        Self {
+            cluster_address,
+            blueprint_id,
+            link_id,
+            services: ClusterServiceManager::new(ServicePolicy::DefaultOn),
+        }
     }
 
     pub fn call<T: ScryptoDecode>(&self, method_name: &str, args: Vec<u8>) -> T {
@@ -0,0 +1,439 @@
+/* ------------------ Imports ----------------- */
+// Shared Modules
+use shared::links::Link;
+// Libraries
+use scrypto::prelude::*;
+
+/* ------------------- Terms ------------------ */
+/// Transient terms of an outstanding flash loan; cannot be deposited, so must be returned with the repayment.
+#[derive(ScryptoSbor, NonFungibleData, Clone, Debug)]
+pub struct FlashLoanTerms {
+    pub resource: ResourceAddress,
+    pub amount: Decimal,
+    pub fee: Decimal,
+}
+
+/* ------------------ Liquidity ----------------- */
+/// Liquidity of a single resource, and the pool units representing LPs' shares of it.
+#[derive(ScryptoSbor)]
+pub struct Liquidity {
+    pub vault: FungibleVault,
+    pub unit_manager: FungibleResourceManager,
+    /// Amount currently flash borrowed out of the vault.
+    pub outstanding: Decimal,
+}
+
+impl Liquidity {
+    /// Returns the total liquidity; held in the vault or flash borrowed out of it.
+    pub fn total(&self) -> Decimal {
+        self.vault.amount() + self.outstanding
+    }
+}
+
+/// Information about the liquidity of a single resource.
+#[derive(ScryptoSbor, Clone, Debug)]
+pub struct LiquidityInfo {
+    pub resource: ResourceAddress,
+    pub pool_units: ResourceAddress,
+    pub available: Decimal,
+    pub outstanding: Decimal,
+    pub unit_supply: Decimal,
+}
+
+/* ----------------- Blueprint ---------------- */
+type Unit = ();
+
+#[blueprint]
+#[types(Unit, ComponentAddress, ResourceAddress, Decimal, Link, FlashLoanTerms, Liquidity, LiquidityInfo)]
+mod flash_loan_pool {
+    //] --------------- Scrypto Setup -------------- */
+    enable_method_auth! {
+        roles {
+            can_manage_pool => updatable_by: [OWNER];
+        },
+        methods {
+            // Pool
+            add_resource   => restrict_to: [can_manage_pool, OWNER];
+            set_fee_rates  => restrict_to: [can_manage_pool, OWNER];
+            get_liquidity  => PUBLIC;
+            get_fee_rates  => PUBLIC;
+            // Liquidity
+            deposit  => PUBLIC;
+            withdraw => PUBLIC;
+            // Flash loans
+            take_flash_loan        => PUBLIC;
+            take_linked_flash_loan   => PUBLIC;
+            take_platform_flash_loan => PUBLIC;
+            repay_flash_loan         => PUBLIC;
+        }
+    }
+
+    //] ---------- Flash Loan Pool Blueprint -------- */
+    struct FlashLoanPool {
+        // Platform link
+        platform_address: ComponentAddress,
+        link_resource: ResourceAddress,
+        // Pool
+        liquidity: KeyValueStore<ResourceAddress, Liquidity>,
+        term_manager: NonFungibleResourceManager,
+        fee_rate: Decimal,
+        linked_fee_rate: Decimal,
+    }
+
+    impl FlashLoanPool {
+        /// Instantiates a new `FlashLoanPool` component, lending the platform's liquidity to flash borrowers; deployed
+        /// by the platform through `deploy_flash_loan_pool`. LPs deposit assets for pool units, and earn the fees paid on
+        /// flash loans; the platform and its linked clusters borrow at a discounted rate, which is free by default.
+        ///
+        /// # Parameters
+        /// - `owner_rule`: Access rule defining the owner of the pool.
+        /// - `admin_rule`: Access rule defining the admins of the pool.
+        /// - `platform_address`: The component address of the platform whose linked clusters receive the discounted rate.
+        /// - `link_resource`: Resource address of the platform's link badge.
+        /// - `fee_rate`: The fee charged on flash loans; e.g. `0.0005` for 0.05%.
+        ///
+        /// # Returns
+        /// A globally accessible `FlashLoanPool` component instance.
+        pub fn instantiate(
+            owner_rule: AccessRule,
+            admin_rule: AccessRule,
+            platform_address: ComponentAddress,
+            link_resource: ResourceAddress,
+            fee_rate: Decimal,
+        ) -> Global<FlashLoanPool> {
+            Self::__assert_fee_rates(fee_rate, dec!(0));
+
+            // Reserve component address
+            let (address_reservation, component_address) = Runtime::allocate_component_address(FlashLoanPool::blueprint_id());
+
+            //] Authorisation
+            // Component
+            let component_rule: AccessRule = rule!(require(global_caller(component_address)));
+
+            // Component owner
+            let owner_role: OwnerRole = OwnerRole::Fixed(owner_rule);
+
+            // Flash loan terms
+            let term_manager = ResourceBuilder::new_ruid_non_fungible::<FlashLoanTerms>(owner_role.clone())
+                .mint_roles(mint_roles! {
+                    minter         => component_rule.clone();
+                    minter_updater => rule!(deny_all);
+                })
+                .burn_roles(burn_roles! {
+                    burner         => component_rule.clone();
+                    burner_updater => rule!(deny_all);
+                })
+                .deposit_roles(deposit_roles! {
+                    depositor         => rule!(deny_all);
+                    depositor_updater => rule!(deny_all);
+                })
+                .create_with_no_initial_supply();
+
+            //] Component Instantisation
+            // Metadata
+            let component_metadata = metadata! {
+                roles {
+                    metadata_setter         => OWNER;
+                    metadata_setter_updater => OWNER;
+                    metadata_locker         => OWNER;
+                    metadata_locker_updater => rule!(deny_all);
+                },
+                init {
+                    "name"        => "L3//Flash Loan Pool", locked;
+                    "description" => "Lattic3 liquidity pool providing flash loans to the platform's clusters.", locked;
+                }
+            };
+
+            // Roles
+            let component_roles = roles! {
+                can_manage_pool => admin_rule;
+            };
+
+            // Instantisation
+            let initial_state = Self {
+                platform_address,
+                link_resource,
+                liquidity: KeyValueStore::new(),
+                term_manager,
+                fee_rate,
+                linked_fee_rate: dec!(0),
+            };
+
+            initial_state
+                .instantiate()
+                .prepare_to_globalize(owner_role)
+                .roles(component_roles)
+                .metadata(component_metadata)
+                .with_address(address_reservation)
+                .globalize()
+        }
+
+        //] ------------------- Pool ------------------- */
+        /// Adds a resource to the pool, creating the pool units of its liquidity.
+        ///
+        /// # Parameters
+        /// - `resource`: The fungible resource to lend.
+        ///
+        /// # Panics
+        /// - If the resource has already been added.
+        pub fn add_resource(&mut self, resource: ResourceAddress) {
+            assert!(self.liquidity.get(&resource).is_none(), "Resource already added");
+
+            let component_rule: AccessRule = rule!(require(global_caller(Runtime::global_address())));
+
+            let unit_manager = ResourceBuilder::new_fungible(OwnerRole::None)
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .metadata(metadata! {init {
+                    "name"        => "L3//Flash Pool Units", locked;
+                    "description" => "Units representing a share of the liquidity of a Lattic3 flash loan pool.", locked;
+                    "resource"    => resource, locked;
+                }})
+                .mint_roles(mint_roles! {
+                    minter         => component_rule.clone();
+                    minter_updater => rule!(deny_all);
+                })
+                .burn_roles(burn_roles! {
+                    burner         => component_rule;
+                    burner_updater => rule!(deny_all);
+                })
+                .create_with_no_initial_supply();
+
+            let liquidity = Liquidity { vault: FungibleVault::new(resource), unit_manager, outstanding: dec!(0) };
+            self.liquidity.insert(resource, liquidity);
+        }
+
+        /// Sets the fees charged on flash loans.
+        ///
+        /// # Parameters
+        /// - `fee_rate`: The fee charged on flash loans; e.g. `0.0005` for 0.05%.
+        /// - `linked_fee_rate`: The discounted fee charged on flash loans to linked clusters.
+        ///
+        /// # Panics
+        /// - If either rate is negative, or not below 1.
+        /// - If the linked rate exceeds the standard rate.
+        pub fn set_fee_rates(&mut self, fee_rate: Decimal, linked_fee_rate: Decimal) {
+            Self::__assert_fee_rates(fee_rate, linked_fee_rate);
+
+            self.fee_rate = fee_rate;
+            self.linked_fee_rate = linked_fee_rate;
+        }
+
+        /// Returns the liquidity of a resource in the pool.
+        ///
+        /// # Panics
+        /// - If the resource has not been added.
+        pub fn get_liquidity(&self, resource: ResourceAddress) -> LiquidityInfo {
+            let liquidity = self.liquidity.get(&resource).expect("Resource not in pool");
+
+            LiquidityInfo {
+                resource,
+                pool_units: liquidity.unit_manager.address(),
+                available: liquidity.vault.amount(),
+                outstanding: liquidity.outstanding,
+                unit_supply: liquidity.unit_manager.total_supply().unwrap(),
+            }
+        }
+
+        /// Returns the standard and linked fee rates of the pool.
+        pub fn get_fee_rates(&self) -> (Decimal, Decimal) {
+            (self.fee_rate, self.linked_fee_rate)
+        }
+
+        //] ----------------- Liquidity ---------------- */
+        /// Deposits liquidity into the pool.
+        ///
+        /// # Parameters
+        /// - `deposit`: The liquidity to deposit.
+        ///
+        /// # Returns
+        /// - `FungibleBucket`: The pool units representing the deposit's share of the liquidity.
+        ///
+        /// # Panics
+        /// - If the resource has not been added.
+        /// - If the deposit is empty.
+        pub fn deposit(&mut self, deposit: FungibleBucket) -> FungibleBucket {
+            assert!(deposit.amount() > dec!(0), "Deposit cannot be empty");
+
+            let mut liquidity = self.liquidity.get_mut(&deposit.resource_address()).expect("Resource not in pool");
+            let supply = liquidity.unit_manager.total_supply().unwrap();
+            let total = liquidity.total();
+
+            let units = match supply == dec!(0) || total == dec!(0) {
+                true => deposit.amount(),
+                false => deposit.amount() * supply / total,
+            };
+            liquidity.vault.put(deposit);
+
+            liquidity.unit_manager.mint(units)
+        }
+
+        /// Withdraws liquidity from the pool.
+        ///
+        /// # Parameters
+        /// - `units`: The pool units to redeem.
+        ///
+        /// # Returns
+        /// - `FungibleBucket`: The units' share of the liquidity.
+        ///
+        /// # Panics
+        /// - If the units are not of a resource in the pool.
+        /// - If too much of the liquidity is flash borrowed to redeem the units.
+        pub fn withdraw(&mut self, units: FungibleBucket) -> FungibleBucket {
+            let resource = self.__resource_of_units(units.resource_address());
+
+            let mut liquidity = self.liquidity.get_mut(&resource).unwrap();
+            let supply = liquidity.unit_manager.total_supply().unwrap();
+            let amount = units.amount() * liquidity.total() / supply;
+            assert!(amount <= liquidity.vault.amount(), "Insufficient available liquidity");
+
+            units.burn();
+
+            liquidity.vault.take_advanced(amount, WithdrawStrategy::Rounded(RoundingMode::ToZero))
+        }
+
+        //] ---------------- Flash Loans --------------- */
+        /// Takes a flash loan at the standard fee rate.
+        ///
+        /// # Parameters
+        /// - `resource`: The resource to borrow.
+        /// - `amount`: The amount to borrow.
+        ///
+        /// # Returns
+        /// - `FungibleBucket`: The borrowed funds.
+        /// - `NonFungibleBucket`: The terms of the loan, to be returned with the repayment.
+        ///
+        /// # Panics
+        /// - If the resource has not been added, or there is insufficient liquidity.
+        pub fn take_flash_loan(&mut self, resource: ResourceAddress, amount: Decimal) -> (FungibleBucket, NonFungibleBucket) {
+            self.__take(resource, amount, self.fee_rate)
+        }
+
+        /// Takes a flash loan at the linked fee rate, for a cluster linked to the platform.
+        ///
+        /// # Parameters
+        /// - `link_badge`: A proof of the cluster's link badge.
+        /// - `resource`: The resource to borrow.
+        /// - `amount`: The amount to borrow.
+        ///
+        /// # Returns
+        /// - `FungibleBucket`: The borrowed funds.
+        /// - `NonFungibleBucket`: The terms of the loan, to be returned with the repayment.
+        ///
+        /// # Panics
+        /// - If the link badge is invalid, not issued by the platform, or paused.
+        /// - If the link badge's cluster has since been unlinked from the platform.
+        /// - If the resource has not been added, or there is insufficient liquidity.
+        pub fn take_linked_flash_loan(
+            &mut self,
+            link_badge: NonFungibleProof,
+            resource: ResourceAddress,
+            amount: Decimal,
+        ) -> (FungibleBucket, NonFungibleBucket) {
+            let valid_link = link_badge.check_with_message(self.link_resource, "Link badge not valid");
+            assert_eq!(valid_link.amount(), dec!(1), "Invalid link badge quantity");
+
+            let link: Link = valid_link.non_fungible().data();
+            assert_eq!(link.issuing_platform, self.platform_address, "Link badge not issued by this platform");
+            assert!(!link.paused, "Platform is paused");
+
+            let platform: Global<AnyComponent> = self.platform_address.into();
+            let link_id = valid_link.non_fungible_local_id();
+            assert!(platform.call_raw::<bool>("is_linked", scrypto_args!(link_id)), "Cluster no longer linked");
+
+            self.__take(resource, amount, self.linked_fee_rate)
+        }
+
+        /// Takes a flash loan at the linked fee rate, for the platform itself; e.g. to refinance a user's account.
+        ///
+        /// # Parameters
+        /// - `resource`: The resource to borrow.
+        /// - `amount`: The amount to borrow.
+        ///
+        /// # Returns
+        /// - `FungibleBucket`: The borrowed funds.
+        /// - `NonFungibleBucket`: The terms of the loan, to be returned with the repayment.
+        ///
+        /// # Panics
+        /// - If the caller is not the platform.
+        /// - If the resource has not been added, or there is insufficient liquidity.
+        pub fn take_platform_flash_loan(&mut self, resource: ResourceAddress, amount: Decimal) -> (FungibleBucket, NonFungibleBucket) {
+            Runtime::assert_access_rule(rule!(require(global_caller(self.platform_address))));
+
+            self.__take(resource, amount, self.linked_fee_rate)
+        }
+
+        /// Repays a flash loan.
+        ///
+        /// # Parameters
+        /// - `repayment`: The repayment of the loan and its fee.
+        /// - `terms`: The terms of the loan.
+        ///
+        /// # Returns
+        /// - `FungibleBucket`: The part of the repayment in excess of the amount due.
+        ///
+        /// # Panics
+        /// - If the terms are invalid.
+        /// - If the repayment is not in the borrowed resource, or less than the amount due.
+        pub fn repay_flash_loan(&mut self, mut repayment: FungibleBucket, terms: NonFungibleBucket) -> FungibleBucket {
+            assert_eq!(terms.resource_address(), self.term_manager.address(), "Invalid flash loan terms");
+            assert_eq!(terms.amount(), dec!(1), "Invalid flash loan terms quantity");
+
+            let terms_data: FlashLoanTerms = terms.non_fungible().data();
+            assert_eq!(
+                repayment.resource_address(),
+                terms_data.resource,
+                "Repayment must be in the borrowed resource"
+            );
+            assert!(
+                repayment.amount() >= terms_data.amount + terms_data.fee,
+                "Insufficient flash loan repayment"
+            );
+
+            {
+                let mut liquidity = self.liquidity.get_mut(&terms_data.resource).unwrap();
+                liquidity.vault.put(repayment.take(terms_data.amount + terms_data.fee));
+                liquidity.outstanding -= terms_data.amount;
+            }
+
+            self.term_manager.burn(terms);
+
+            repayment
+        }
+
+        //] Private
+        /// Lends `amount` of `resource`, minting terms charging `fee_rate`.
+        fn __take(&mut self, resource: ResourceAddress, amount: Decimal, fee_rate: Decimal) -> (FungibleBucket, NonFungibleBucket) {
+            assert!(amount > dec!(0), "Flash loan amount must be positive");
+
+            let funds = {
+                let mut liquidity = self.liquidity.get_mut(&resource).expect("Resource not in pool");
+                assert!(amount <= liquidity.vault.amount(), "Insufficient available liquidity");
+
+                liquidity.outstanding += amount;
+                liquidity.vault.take(amount)
+            };
+
+            let terms = FlashLoanTerms { resource, amount, fee: amount * fee_rate };
+            let terms = self.term_manager.mint_ruid_non_fungible(terms);
+
+            (funds, terms)
+        }
+
+        /// Returns the resource whose liquidity `units` represents.
+        fn __resource_of_units(&self, units: ResourceAddress) -> ResourceAddress {
+            let manager = ResourceManager::from(units);
+            let resource: GlobalAddress = manager.get_metadata("resource").unwrap_or(None).expect("Not pool units");
+            let resource = ResourceAddress::try_from(resource).expect("Not pool units");
+
+            let liquidity = self.liquidity.get(&resource).expect("Not pool units");
+            assert_eq!(liquidity.unit_manager.address(), units, "Not pool units");
+
+            resource
+        }
+
+        fn __assert_fee_rates(fee_rate: Decimal, linked_fee_rate: Decimal) {
+            assert!(fee_rate >= dec!(0) && fee_rate < dec!(1), "Invalid fee rate");
+            assert!(linked_fee_rate >= dec!(0) && linked_fee_rate <= fee_rate, "Invalid linked fee rate");
+        }
+    }
+}
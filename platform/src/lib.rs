@@ -1,3 +1,7 @@
+// Scrypto's generated test bindings take an extra `env` argument beyond each method's own
+#![allow(clippy::too_many_arguments)]
+
 pub mod clusters;
+pub mod flash_loans;
 pub mod platform;
 pub mod services;
@@ -1,11 +1,13 @@
 /* ------------------ Imports ----------------- */
 // Modules
 use crate::clusters::ClusterWrapper;
+use crate::flash_loans::flash_loan_pool::{FlashLoanPool, FlashLoanPoolFunctions};
+use crate::flash_loans::FlashLoanTerms;
 use crate::services::cluster_services::{ClusterService, ClusterServiceManager};
 use crate::services::platform_services::{PlatformService, PlatformServiceManager};
 // Shared Modules
 use shared::links::Link;
-use shared::services::ServiceValue;
+use shared::services::{ServicePolicy, ServiceValue, SetLock};
 use shared::users::User;
 // Libraries
 use scrypto::prelude::*;
@@ -31,7 +33,9 @@ type Unit = ();
     PlatformService,
     ClusterServiceManager,
     ClusterService,
-    ServiceValue
+    ServiceValue,
+    ServicePolicy,
+    SetLock
 )]
 mod platform {
 
@@ -41,23 +45,39 @@ mod platform {
             can_manage_links    => updatable_by: [OWNER];
             can_update_services => updatable_by: [OWNER];
             can_lock_services   => updatable_by: [OWNER];
+            guardian            => updatable_by: [OWNER];
         },
         methods {
             // User
             new_user      => PUBLIC;
             open_account  => PUBLIC;
             close_account => PUBLIC;
+            refinance     => PUBLIC;
             // Links
-            link_cluster   => restrict_to: [can_manage_links];
+            link_cluster   => restrict_to: [can_manage_links, SELF];
             unlink_cluster => restrict_to: [can_manage_links];
+            deploy_cluster => restrict_to: [can_manage_links];
+            register_strategy   => restrict_to: [can_manage_links];
+            deregister_strategy => restrict_to: [can_manage_links];
+            get_strategy        => PUBLIC;
             update_cluster_service              => restrict_to: [can_update_services, can_lock_services];
             update_cluster_service_and_set_lock => restrict_to: [can_lock_services];
             // Platform
             get_user_badge_address => PUBLIC;
             get_link_badge_address => PUBLIC;
+            is_linked              => PUBLIC;
+            get_claimed_entities   => PUBLIC;
+            get_services           => PUBLIC;
+            get_cluster_services   => PUBLIC;
             new_admin_badge        => restrict_to: [OWNER];
+            deploy_flash_loan_pool => restrict_to: [OWNER];
+            get_flash_loan_pool    => PUBLIC;
             update_service              => restrict_to: [can_update_services, can_lock_services];
             update_service_and_set_lock => restrict_to: [can_lock_services];
+            // Emergency
+            is_paused   => PUBLIC;
+            pause_all   => restrict_to: [guardian, OWNER];
+            unpause_all => restrict_to: [OWNER];
         }
     }
 
@@ -65,6 +85,7 @@ mod platform {
     struct Platform {
         // Authorisation
         component_address: ComponentAddress,
+        owner_rule: AccessRule,
         admin_badge_manager: NonFungibleResourceManager,
         admin_count: u64,
         // User badges
@@ -72,10 +93,16 @@ mod platform {
         user_count: u64,
         // Operating services
         services: PlatformServiceManager,
+        paused: bool,
+        paused_services: Option<PlatformServiceManager>,
         // Links
         link_badge_manager: NonFungibleResourceManager,
         linked_clusters: KeyValueStore<ComponentAddress, ClusterWrapper>,
         linked_count: u64,
+        // Strategies
+        strategies: KeyValueStore<BlueprintId, String>,
+        // Flash loans
+        flash_loan_pool: Option<ComponentAddress>,
     }
 
     impl Platform {
@@ -217,23 +244,31 @@ mod platform {
                 can_manage_links    => OWNER;
                 can_update_services => admin_rule;
                 can_lock_services   => OWNER;
+                guardian            => OWNER;
             };
 
             // Instantisation
             let initial_state = Self {
                 // Authorisation
                 component_address,
+                owner_rule,
                 admin_badge_manager,
                 admin_count: 0,
                 // User badges
                 user_badge_manager,
                 user_count: 0,
                 // Operating services
-                services: PlatformServiceManager::new(),
+                services: PlatformServiceManager::new(ServicePolicy::DefaultOn),
+                paused: false,
+                paused_services: None,
                 // Links
                 link_badge_manager,
                 linked_clusters: KeyValueStore::new(),
                 linked_count: 0,
+                // Strategies
+                strategies: KeyValueStore::new(),
+                // Flash loans
+                flash_loan_pool: None,
             };
 
             let component: Global<Self> = initial_state
@@ -279,8 +314,8 @@ mod platform {
 
             // Validate the link
             let wrapper = self.__validate_link(link_badge);
-            let can_update_badge = wrapper.services.get_service(ClusterService::OpenAccount).value;
-            assert_eq!(can_update_badge, true, "ClusterService::OpenAccount disabled");
+            let can_update_badge = wrapper.services.get(ClusterService::OpenAccount).value;
+            assert!(can_update_badge, "ClusterService::OpenAccount disabled");
 
             // Open account and update badge
             let mut user: User = self.user_badge_manager.get_non_fungible_data::<User>(&user_id);
@@ -299,8 +334,8 @@ mod platform {
 
             // Validate the link
             let wrapper = self.__validate_link(link_badge);
-            let can_update_badge = wrapper.services.get_service(ClusterService::CloseAccount).value;
-            assert_eq!(can_update_badge, true, "ClusterService::CloseAccount disabled");
+            let can_update_badge = wrapper.services.get(ClusterService::CloseAccount).value;
+            assert!(can_update_badge, "ClusterService::CloseAccount disabled");
 
             // Close account and update badge
             let mut user: User = self.user_badge_manager.get_non_fungible_data::<User>(&user_id);
@@ -311,6 +346,100 @@ mod platform {
             self.user_badge_manager.update_non_fungible_data(&user_id, "open", user.open);
         }
 
+        /// Refinances a user's account from one linked cluster to another, moving the position between lenders in a
+        /// single transaction. The account's debt is flash borrowed from the platform's pool, with which the source
+        /// cluster repays its CDP and returns the collateral; the target cluster deposits the collateral into the given
+        /// CDP and borrows the repaid debt and the loan's fee, which repay the flash loan.
+        ///
+        /// # Parameters
+        /// - `user_badge`: Proof of the user's badge.
+        /// - `from_cluster`: The address of the cluster the account is moved from.
+        /// - `to_cluster`: The address of the cluster the account is moved to.
+        /// - `cdp`: A `NonFungibleBucket` containing the CDP with which to open the account on the target cluster.
+        ///
+        /// # Panics
+        /// - If the service `PlatformService::Refinance` is disabled.
+        /// - If either cluster is not linked, or they are the same cluster.
+        /// - If the source cluster has `ClusterService::CloseAccount`, or the target `ClusterService::OpenAccount`, disabled.
+        /// - If the clusters do not share the same supply and debt assets.
+        /// - If the user does not have an account on the source cluster, or already has one on the target.
+        /// - If the flash loan pool has not been deployed, or lacks the liquidity to repay the source CDP.
+        ///
+        /// # Returns
+        /// - `NonFungibleBucket`: The emptied CDP of the source cluster.
+        /// - `FungibleBucket`: The part of the target cluster's borrow in excess of the flash loan.
+        pub fn refinance(
+            &self,
+            user_badge: NonFungibleProof,
+            from_cluster: ComponentAddress,
+            to_cluster: ComponentAddress,
+            cdp: NonFungibleBucket,
+        ) -> (NonFungibleBucket, FungibleBucket) {
+            assert!(self.services.get(PlatformService::Refinance).value, "PlatformService::Refinance disabled");
+            assert!(!self.paused, "Platform paused");
+            assert_ne!(from_cluster, to_cluster, "Cannot refinance into the same cluster");
+
+            // Validate the user
+            let valid_user = self.__validate_user(user_badge);
+            let user_id = valid_user.non_fungible_local_id();
+
+            // Validate the links
+            let from = self.linked_clusters.get(&from_cluster).expect("Source cluster not linked").clone();
+            let to = self.linked_clusters.get(&to_cluster).expect("Target cluster not linked").clone();
+            assert!(
+                from.services.get(ClusterService::CloseAccount).value,
+                "ClusterService::CloseAccount disabled"
+            );
+            assert!(to.services.get(ClusterService::OpenAccount).value, "ClusterService::OpenAccount disabled");
+
+            // Ensure both clusters lever the same pair
+            for key in ["supply", "debt"] {
+                let from_res: Option<GlobalAddress> = Global::<AnyComponent>::from(from_cluster).get_metadata(key).unwrap_or(None);
+                let to_res: Option<GlobalAddress> = Global::<AnyComponent>::from(to_cluster).get_metadata(key).unwrap_or(None);
+                assert!(from_res.is_some() && from_res == to_res, "Clusters do not share the same {} asset", key);
+            }
+            let debt_res: GlobalAddress = Global::<AnyComponent>::from(from_cluster).get_metadata("debt").unwrap().unwrap();
+            let debt_res = ResourceAddress::try_from(debt_res).unwrap();
+
+            // Flash borrow the source position's debt
+            let pool: Global<AnyComponent> = self.flash_loan_pool.expect("Flash loan pool not deployed").into();
+            let debt: Decimal = from.call("get_account_debt", scrypto_args!(user_id.clone()));
+            let (repayment, terms) = match debt > dec!(0) {
+                true => {
+                    let (funds, terms) =
+                        pool.call_raw::<(FungibleBucket, NonFungibleBucket)>("take_platform_flash_loan", scrypto_args!(debt_res, debt));
+                    (funds, Some(terms))
+                }
+                false => (FungibleBucket::new(debt_res), None),
+            };
+
+            // Unwind the source position, and rebuild it on the target with the debt and the loan's fee
+            let (old_cdp, collateral, mut remainder): (NonFungibleBucket, FungibleBucket, FungibleBucket) =
+                from.call("export_account", scrypto_args!(user_id.clone(), repayment));
+            let fee = terms.as_ref().map_or(dec!(0), |terms| terms.non_fungible::<FlashLoanTerms>().data().fee);
+            let repaid = debt - remainder.amount();
+
+            let borrowed: FungibleBucket = to.call("import_account", scrypto_args!(user_id.clone(), cdp, collateral, repaid + fee));
+            remainder.put(borrowed);
+
+            // Repay the flash loan
+            let excess = match terms {
+                Some(terms) => pool.call_raw::<FungibleBucket>("repay_flash_loan", scrypto_args!(remainder, terms)),
+                None => remainder,
+            };
+
+            // Move the account on the user's badge
+            let mut user: User = self.user_badge_manager.get_non_fungible_data::<User>(&user_id);
+            user.remove_account(from_cluster);
+            user.add_account(to_cluster);
+
+            self.user_badge_manager
+                .update_non_fungible_data(&user_id, "accounts_in", user.accounts_in);
+            self.user_badge_manager.update_non_fungible_data(&user_id, "open", user.open);
+
+            (old_cdp, excess)
+        }
+
         //] Private
         fn __validate_user(&self, user_badge: NonFungibleProof) -> CheckedNonFungibleProof {
             let valid_user = user_badge.check_with_message(self.user_badge_manager.address(), "User badge not valid");
@@ -355,26 +484,117 @@ mod platform {
             );
 
             // let _wrapper = self.linked_clusters.get(&cluster_address).ok_or("Cluster already linked".to_string());
-            assert!(self.linked_clusters.get(&cluster_address).is_some(), "Cluster not linked");
+            let link_id = self.linked_clusters.get(&cluster_address).expect("Cluster not linked").link_id.clone();
+
+            // Lift a pause from the link badge, as it is no longer reached by `unpause_all`
+            if self.link_badge_manager.non_fungible_exists(&link_id) {
+                self.link_badge_manager.update_non_fungible_data(&link_id, "paused", false);
+            }
 
             self.linked_clusters.remove(&cluster_address);
         }
 
+        //] Strategies
+        /// Registers a strategy blueprint from which clusters can be deployed.
+        ///
+        /// # Parameters
+        /// - `package_address`: The package of the strategy.
+        /// - `blueprint_name`: The name of the strategy's cluster blueprint.
+        /// - `function_name`: The function instantiating a cluster; e.g. `instantiate`. It must take the owner rule,
+        ///   admin rule and platform address as its first parameters, as every strategy's `instantiate` does.
+        pub fn register_strategy(&mut self, package_address: PackageAddress, blueprint_name: String, function_name: String) {
+            let blueprint_id = BlueprintId::new(&package_address, blueprint_name);
+            self.strategies.insert(blueprint_id, function_name);
+        }
+
+        /// Deregisters a strategy blueprint; clusters already deployed from it stay linked.
+        ///
+        /// # Panics
+        /// - If the strategy is not registered.
+        pub fn deregister_strategy(&mut self, package_address: PackageAddress, blueprint_name: String) {
+            let blueprint_id = BlueprintId::new(&package_address, blueprint_name);
+            self.strategies.remove(&blueprint_id).expect("Strategy not registered");
+        }
+
+        /// Returns the function from which clusters of a strategy blueprint are deployed, if it is registered.
+        pub fn get_strategy(&self, package_address: PackageAddress, blueprint_name: String) -> Option<String> {
+            let blueprint_id = BlueprintId::new(&package_address, blueprint_name);
+            self.strategies.get(&blueprint_id).map(|function_name| function_name.clone())
+        }
+
+        /// Deploys a cluster from a registered strategy blueprint, to be linked afterwards through `link_cluster`.
+        /// The cluster is owned by the platform's owner alone and administered by the holders of admin badges; the
+        /// platform holds no role on the cluster, which instead takes the platform's dApp definition at instantiation.
+        /// Linking is left to the caller, as the cluster reads the platform's state while it is instantiated.
+        ///
+        /// # Parameters
+        /// - `package_address`: The package of the strategy.
+        /// - `blueprint_name`: The name of the strategy's cluster blueprint.
+        /// - `params`: A tuple of the instantiation parameters following the owner rule, admin rule and platform address.
+        ///
+        /// # Returns
+        /// - `ComponentAddress`: The address of the deployed cluster.
+        ///
+        /// # Panics
+        /// - If the service `PlatformService::DeployCluster` is disabled.
+        /// - If the strategy is not registered.
+        /// - If the parameters are not a tuple, or do not match the strategy's instantiation function.
+        pub fn deploy_cluster(&self, package_address: PackageAddress, blueprint_name: String, params: ScryptoValue) -> ComponentAddress {
+            assert!(
+                self.services.get(PlatformService::DeployCluster).value,
+                "PlatformService::DeployCluster disabled"
+            );
+
+            let blueprint_id = BlueprintId::new(&package_address, blueprint_name.clone());
+            let function_name = self.strategies.get(&blueprint_id).expect("Strategy not registered").clone();
+
+            // Arrange the platform-standard rules ahead of the strategy's own parameters
+            let owner_rule = self.owner_rule.clone();
+            let admin_rule = rule!(require(self.admin_badge_manager.address()));
+
+            let ScryptoValue::Tuple { fields: params } = params else {
+                panic!("Cluster parameters must be a tuple");
+            };
+            let mut fields = vec![
+                Self::__to_value(&owner_rule),
+                Self::__to_value(&admin_rule),
+                Self::__to_value(&self.component_address),
+            ];
+            fields.extend(params);
+
+            // Instantiate the cluster
+            let args = scrypto_encode(&ScryptoValue::Tuple { fields }).unwrap();
+            let output = ScryptoVmV1Api::blueprint_call(package_address, &blueprint_name, &function_name, args);
+            let cluster: Global<AnyComponent> = scrypto_decode(&output).expect("Strategy did not instantiate a cluster");
+
+            cluster.address()
+        }
+
         //] Services
         /// Updates a cluster service, assuming it is not locked.
         ///
         /// # Parameters
         /// - `service`: The service to update.
         /// - `value`: The value to set the service to.
+        /// - `enable_at`: Optional time at which the service is enabled.
+        /// - `disable_at`: Optional time at which the service is disabled.
         ///
         /// # Panics
         /// - If the service is currently locked.
-        pub fn update_cluster_service(&mut self, cluster_address: ComponentAddress, service: ClusterService, value: bool) {
+        pub fn update_cluster_service(
+            &mut self,
+            cluster_address: ComponentAddress,
+            service: ClusterService,
+            value: bool,
+            enable_at: Option<Instant>,
+            disable_at: Option<Instant>,
+        ) {
+            assert!(!self.paused, "Platform paused");
             let mut wrapper = self
                 .linked_clusters
                 .get_mut(&cluster_address)
                 .expect("Cluster with given address not linked");
-            wrapper.services.update_service(service, value, false);
+            wrapper.services.update(service, value, enable_at, disable_at, SetLock::None);
         }
 
         /// Updates a cluster service and sets the lock state.
@@ -383,15 +603,53 @@ mod platform {
         /// - `service`: The service to update.
         /// - `value`: The value to set the service to.
         /// - `locked`: The value to which the lock status of the service is set to.
-        pub fn update_cluster_service_and_set_lock(&mut self, cluster_address: ComponentAddress, service: ClusterService, value: bool, locked: bool) {
+        /// - `enable_at`: Optional time at which the service is enabled.
+        /// - `disable_at`: Optional time at which the service is disabled.
+        pub fn update_cluster_service_and_set_lock(
+            &mut self,
+            cluster_address: ComponentAddress,
+            service: ClusterService,
+            value: bool,
+            locked: bool,
+            enable_at: Option<Instant>,
+            disable_at: Option<Instant>,
+        ) {
+            assert!(!self.paused, "Platform paused");
             let mut wrapper = self
                 .linked_clusters
                 .get_mut(&cluster_address)
                 .expect("Cluster with given address not linked");
-            wrapper.services.update_service(service, value, locked);
+            wrapper.services.update(service, value, enable_at, disable_at, SetLock::Update(locked));
+        }
+
+        /// Returns every service of a linked cluster, alongside its value and lock state.
+        ///
+        /// # Parameters
+        /// - `cluster_address`: The address of the linked cluster.
+        pub fn get_cluster_services(&self, cluster_address: ComponentAddress) -> IndexMap<ClusterService, ServiceValue> {
+            let wrapper = self.linked_clusters.get(&cluster_address).expect("Cluster with given address not linked");
+            wrapper.services.list()
         }
 
         //] Private
+        fn __to_value<T: ScryptoEncode>(value: &T) -> ScryptoValue {
+            scrypto_decode(&scrypto_encode(value).unwrap()).unwrap()
+        }
+
+        /// Sets the `paused` flag on the link badge of every linked cluster, propagating it to the clusters.
+        fn __set_links_paused(&self, paused: bool) {
+            for index in 0..self.linked_count {
+                let link_id = NonFungibleLocalId::Integer(index.into());
+
+                // Skip link badges which have since been burned, or whose clusters have since been unlinked
+                if !self.is_linked(link_id.clone()) {
+                    continue;
+                }
+
+                self.link_badge_manager.update_non_fungible_data(&link_id, "paused", paused);
+            }
+        }
+
         fn __validate_link(&self, link_badge: NonFungibleProof) -> ClusterWrapper {
             // Validate the proof
             let valid_link = link_badge.check_with_message(self.link_badge_manager.address(), "Link badge not valid");
@@ -420,6 +678,48 @@ mod platform {
             self.link_badge_manager.address()
         }
 
+        /// Returns whether the link badge is that of a currently linked cluster; false for burned badges, and for
+        /// the stale badges of clusters which have since been unlinked.
+        ///
+        /// # Parameters
+        /// - `link_id`: The local ID of the link badge.
+        pub fn is_linked(&self, link_id: NonFungibleLocalId) -> bool {
+            if !self.link_badge_manager.non_fungible_exists(&link_id) {
+                return false;
+            }
+
+            let link: Link = self.link_badge_manager.get_non_fungible_data(&link_id);
+            self.linked_clusters
+                .get(&link.linked_cluster)
+                .is_some_and(|wrapper| wrapper.link_id == link_id)
+        }
+
+        /// Returns the addresses to be registered in the `claimed_entities` of the platform's dApp definition;
+        /// the platform, its badges, and every linked cluster's entities.
+        pub fn get_claimed_entities(&self) -> Vec<GlobalAddress> {
+            let mut entities: Vec<GlobalAddress> = vec![
+                self.component_address.into(),
+                self.admin_badge_manager.address().into(),
+                self.user_badge_manager.address().into(),
+                self.link_badge_manager.address().into(),
+            ];
+
+            for index in 0..self.linked_count {
+                let link_id = NonFungibleLocalId::Integer(index.into());
+
+                // Skip link badges which have since been burned, or whose clusters have since been unlinked
+                if !self.is_linked(link_id.clone()) {
+                    continue;
+                }
+                let link: Link = self.link_badge_manager.get_non_fungible_data(&link_id);
+                let wrapper = self.linked_clusters.get(&link.linked_cluster).unwrap();
+
+                entities.extend(wrapper.call::<Vec<GlobalAddress>>("get_claimed_entities", scrypto_args!()));
+            }
+
+            entities
+        }
+
         /// Mint a new admin badge.
         ///
         /// # Panics
@@ -442,17 +742,58 @@ mod platform {
             self.admin_badge_manager.mint_non_fungible(&badge_id, ())
         }
 
+        /// Deploys the platform's flash loan pool, from which the platform and its linked clusters flash borrow at the
+        /// linked fee rate. The pool is owned by the platform's owner, and its resources and fee rates are managed by the
+        /// holders of admin badges.
+        ///
+        /// # Parameters
+        /// - `fee_rate`: The fee charged on flash loans to other borrowers; e.g. `0.0005` for 0.05%.
+        ///
+        /// # Panics
+        /// - If the flash loan pool has already been deployed.
+        ///
+        /// # Returns
+        /// - `ComponentAddress`: The address of the flash loan pool.
+        pub fn deploy_flash_loan_pool(&mut self, fee_rate: Decimal) -> ComponentAddress {
+            assert!(self.flash_loan_pool.is_none(), "Flash loan pool already deployed");
+
+            let admin_rule = rule!(require(self.admin_badge_manager.address()));
+            let pool = Blueprint::<FlashLoanPool>::instantiate(
+                self.owner_rule.clone(),
+                admin_rule,
+                self.component_address,
+                self.link_badge_manager.address(),
+                fee_rate,
+            );
+
+            self.flash_loan_pool = Some(pool.address());
+            pool.address()
+        }
+
+        /// Returns the address of the platform's flash loan pool, if it has been deployed.
+        pub fn get_flash_loan_pool(&self) -> Option<ComponentAddress> {
+            self.flash_loan_pool
+        }
+
         //] Services
+        /// Returns every platform service, alongside its value and lock state.
+        pub fn get_services(&self) -> IndexMap<PlatformService, ServiceValue> {
+            self.services.list()
+        }
+
         /// Updates a platform service, assuming it is not locked.
         ///
         /// # Parameters
         /// - `service`: The service to update.
         /// - `value`: The value to set the service to.
+        /// - `enable_at`: Optional time at which the service is enabled.
+        /// - `disable_at`: Optional time at which the service is disabled.
         ///
         /// # Panics
         /// - If the service is currently locked.
-        pub fn update_service(&mut self, service: PlatformService, value: bool) {
-            self.services.update(service, value, false);
+        pub fn update_service(&mut self, service: PlatformService, value: bool, enable_at: Option<Instant>, disable_at: Option<Instant>) {
+            assert!(!self.paused, "Platform paused");
+            self.services.update(service, value, enable_at, disable_at, SetLock::None);
         }
 
         /// Updates a platform service and sets the lock state.
@@ -461,8 +802,57 @@ mod platform {
         /// - `service`: The service to update.
         /// - `value`: The value to set the service to.
         /// - `locked`: The value to which the lock status of the service is set to.
-        pub fn update_service_and_set_lock(&mut self, service: PlatformService, value: bool, locked: bool) {
-            self.services.update(service, value, locked);
+        /// - `enable_at`: Optional time at which the service is enabled.
+        /// - `disable_at`: Optional time at which the service is disabled.
+        pub fn update_service_and_set_lock(
+            &mut self,
+            service: PlatformService,
+            value: bool,
+            locked: bool,
+            enable_at: Option<Instant>,
+            disable_at: Option<Instant>,
+        ) {
+            assert!(!self.paused, "Platform paused");
+            self.services.update(service, value, enable_at, disable_at, SetLock::Update(locked));
+        }
+
+        //] Emergency
+        /// Returns whether the platform is currently paused.
+        pub fn is_paused(&self) -> bool {
+            self.paused
+        }
+
+        /// Pauses the platform. Disables all platform services and flags every linked cluster as paused via its link badge.
+        /// The current state of the platform services is stored, and restored by `unpause_all`.
+        ///
+        /// # Panics
+        /// - If the platform is already paused.
+        pub fn pause_all(&mut self) {
+            assert!(!self.paused, "Platform already paused");
+
+            // Disable all platform services, storing their current state
+            self.paused_services = Some(self.services.clone());
+            self.services.disable_all();
+
+            // Propagate the pause to the linked clusters
+            self.__set_links_paused(true);
+            self.paused = true;
+        }
+
+        /// Unpauses the platform. Restores the platform services to their state prior to `pause_all`,
+        /// and clears the pause flag on every linked cluster.
+        ///
+        /// # Panics
+        /// - If the platform is not paused.
+        pub fn unpause_all(&mut self) {
+            assert!(self.paused, "Platform not paused");
+
+            // Restore the platform services
+            self.services = self.paused_services.take().expect("Invalid state: paused without stored services");
+
+            // Propagate the unpause to the linked clusters
+            self.__set_links_paused(false);
+            self.paused = false;
         }
     }
 }
@@ -0,0 +1,229 @@
+use crate::helpers::prelude::*;
+use scrypto_test::prelude::*;
+
+//] ------------------ Factory ----------------- */
+#[derive(Debug, Clone, Copy)]
+pub struct DNWeftV2ClusterFactory {
+    pub package_address: PackageAddress,
+}
+
+impl DNWeftV2ClusterFactory {
+    pub fn new(ledger: &mut Ledger) -> Self {
+        let path = merge_path("../strategies/delta_neutral_weftv2");
+        let package_address = ledger.compile_and_publish(path);
+
+        Self { package_address }
+    }
+
+    pub fn instantiate(
+        &self,
+        runner: &mut Runner,
+        // Authorisation
+        owner_rule: AccessRule,
+        // Link
+        platform: ComponentAddress,
+        user_badge: ResourceAddress,
+        // Cluster
+        stable: ResourceAddress,
+        volatile: ResourceAddress,
+        // Integration
+        weft_market: ComponentAddress,
+        cdp: ResourceAddress,
+        dex: ComponentAddress,
+        lp: ResourceAddress,
+    ) -> DNWeftV2Cluster {
+        let owner_account = runner.owner_account;
+        let admin_rule = rule!(deny_all);
+
+        // Call instantiation function
+        #[rustfmt::skip]
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                self.package_address,
+                "DeltaNeutralWeftV2Cluster", "instantiate",
+                manifest_args!(
+                    owner_rule, admin_rule,
+                    platform,
+                    stable, volatile,
+                    weft_market, cdp, dex, lp
+                ),
+            );
+
+        let receipt = runner.exec_and_dump("instantiate", manifest, &owner_account, Some("clusters/delta_neutral_weftv2"));
+
+        // Collect output
+        let component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        DNWeftV2Cluster { component, user_badge, stable, volatile, cdp }
+    }
+}
+
+//] ------------------ Cluster ----------------- */
+#[derive(Debug, Clone, Copy)]
+pub struct DNWeftV2Cluster {
+    // General
+    pub component: ComponentAddress,
+    // Linking
+    pub user_badge: ResourceAddress,
+    // Cluster
+    pub stable: ResourceAddress,
+    pub volatile: ResourceAddress,
+    // WeftV2 integration
+    pub cdp: ResourceAddress,
+}
+
+impl DNWeftV2Cluster {
+    pub fn open_account(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId, cdp_id: NonFungibleLocalId) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .withdraw_non_fungibles_from_account(account.address, self.cdp, vec![cdp_id.clone()])
+            .take_non_fungibles_from_worktop(self.cdp, vec![cdp_id], "cdp_bucket")
+            .withdraw_from_account(account.address, XRD, dec!(16))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "open_account", |lookup| {
+                (lookup.proof("user_badge"), lookup.bucket("cdp_bucket"), lookup.bucket("fee_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        let receipt = runner.exec_and_dump("open_account", manifest, account, Some("clusters/delta_neutral_weftv2"));
+        receipt.expect_commit_success();
+    }
+
+    pub fn get_account_info(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId) -> DNWeftV2AccountInfo {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "get_account_info", manifest_args!(user_id));
+
+        let receipt = runner.exec_and_dump("get_account_info", manifest, account, Some("clusters/delta_neutral_weftv2"));
+        receipt.expect_commit_success().output(1)
+    }
+
+    pub fn enter(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId, amount: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .withdraw_from_account(account.address, self.stable, amount)
+            .take_all_from_worktop(self.stable, "deposit_bucket")
+            .withdraw_from_account(account.address, XRD, dec!(4))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "enter", |lookup| {
+                (lookup.proof("user_badge"), lookup.bucket("deposit_bucket"), lookup.bucket("fee_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("enter", manifest, account, Some("clusters/delta_neutral_weftv2"))
+    }
+
+    pub fn exit(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .withdraw_from_account(account.address, XRD, dec!(4))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "exit", |lookup| (lookup.proof("user_badge"), lookup.bucket("fee_bucket")))
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("exit", manifest, account, Some("clusters/delta_neutral_weftv2"))
+    }
+
+    pub fn rebalance(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .call_method_with_name_lookup(self.component, "rebalance", |lookup| (lookup.proof("user_badge"),));
+
+        runner.exec_and_dump("rebalance", manifest, account, Some("clusters/delta_neutral_weftv2"))
+    }
+
+    /// Rebalances an account on behalf of its user, presenting a proof of `keeper_badge` if given.
+    pub fn rebalance_account(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        keeper_badge: Option<ResourceAddress>,
+        user_id: NonFungibleLocalId,
+    ) -> TransactionReceipt {
+        let mut manifest = ManifestBuilder::new().lock_fee_from_faucet();
+        if let Some(keeper_badge) = keeper_badge {
+            manifest = manifest.create_proof_from_account_of_amount(account.address, keeper_badge, dec!(1));
+        }
+        let manifest = manifest.call_method(self.component, "rebalance_account", manifest_args!(user_id));
+
+        runner.exec_and_dump("rebalance_account", manifest, account, Some("clusters/delta_neutral_weftv2"))
+    }
+
+    pub fn set_hedge_config(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        owner_badge: ResourceAddress,
+        target_ltv: Decimal,
+        delta_band: Decimal,
+        exit_slippage: Decimal,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .call_method(self.component, "set_hedge_config", manifest_args!(target_ltv, delta_band, exit_slippage));
+
+        runner.exec_and_dump("set_hedge_config", manifest, account, Some("clusters/delta_neutral_weftv2"))
+    }
+
+    pub fn set_oracle(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        owner_badge: ResourceAddress,
+        sources: Vec<DNWeftV2OracleSource>,
+        max_staleness: i64,
+        max_deviation: Decimal,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .call_method(self.component, "set_oracle", manifest_args!(sources, max_staleness, max_deviation));
+
+        runner.exec_and_dump("set_oracle", manifest, account, Some("clusters/delta_neutral_weftv2"))
+    }
+
+    /// Grants the keeper role, allowed to rebalance any account, to holders of `keeper_badge`.
+    pub fn set_keeper(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        owner_badge: ResourceAddress,
+        keeper_badge: ResourceAddress,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .set_role(self.component, ModuleId::Main, "can_rebalance", rule!(require(keeper_badge)));
+
+        runner.exec_and_dump("set_keeper", manifest, account, Some("clusters/delta_neutral_weftv2"))
+    }
+}
+
+#[derive(ScryptoSbor, Debug, Clone, Copy, ManifestSbor)]
+pub enum DNWeftV2OracleSource {
+    Root(ComponentAddress),
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct DNWeftV2AccountInfo {
+    pub cdp_id: NonFungibleLocalId,
+    pub collateral: Decimal,
+    pub debt: Decimal,
+    pub lp_amount: Decimal,
+    pub lp_stable: Decimal,
+    pub lp_volatile: Decimal,
+    pub delta: Decimal,
+    pub delta_value: Decimal,
+    pub equity: Decimal,
+    pub health: Decimal,
+}
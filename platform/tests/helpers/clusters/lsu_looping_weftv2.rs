@@ -0,0 +1,170 @@
+use crate::helpers::prelude::*;
+use scrypto_test::prelude::*;
+
+//] ------------------ Factory ----------------- */
+#[derive(Debug, Clone, Copy)]
+pub struct LLWeftV2ClusterFactory {
+    pub package_address: PackageAddress,
+}
+
+impl LLWeftV2ClusterFactory {
+    pub fn new(ledger: &mut Ledger) -> Self {
+        let path = merge_path("../strategies/lsu_looping_weftv2");
+        let package_address = ledger.compile_and_publish(path);
+
+        Self { package_address }
+    }
+
+    pub fn instantiate(
+        &self,
+        runner: &mut Runner,
+        // Authorisation
+        owner_rule: AccessRule,
+        // Link
+        platform: ComponentAddress,
+        user_badge: ResourceAddress,
+        // Cluster
+        validator: ComponentAddress,
+        lsu: ResourceAddress,
+        // Integration
+        weft_market: ComponentAddress,
+        cdp: ResourceAddress,
+    ) -> LLWeftV2Cluster {
+        let owner_account = runner.owner_account;
+        let admin_rule = rule!(deny_all);
+
+        // Call instantiation function
+        #[rustfmt::skip]
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                self.package_address,
+                "LsuLoopingWeftV2Cluster", "instantiate",
+                manifest_args!(
+                    owner_rule, admin_rule,
+                    platform,
+                    validator, lsu,
+                    weft_market, cdp
+                ),
+            );
+
+        let receipt = runner.exec_and_dump("instantiate", manifest, &owner_account, Some("clusters/lsu_looping_weftv2"));
+
+        // Collect output
+        let component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        LLWeftV2Cluster { component, user_badge, validator, lsu, cdp }
+    }
+}
+
+//] ------------------ Cluster ----------------- */
+#[derive(Debug, Clone, Copy)]
+pub struct LLWeftV2Cluster {
+    // General
+    pub component: ComponentAddress,
+    // Linking
+    pub user_badge: ResourceAddress,
+    // Cluster
+    pub validator: ComponentAddress,
+    pub lsu: ResourceAddress,
+    // WeftV2 integration
+    pub cdp: ResourceAddress,
+}
+
+impl LLWeftV2Cluster {
+    pub fn open_account(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId, cdp_id: NonFungibleLocalId) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .withdraw_non_fungibles_from_account(account.address, self.cdp, vec![cdp_id.clone()])
+            .take_non_fungibles_from_worktop(self.cdp, vec![cdp_id], "cdp_bucket")
+            .withdraw_from_account(account.address, XRD, dec!(16))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "open_account", |lookup| {
+                (lookup.proof("user_badge"), lookup.bucket("cdp_bucket"), lookup.bucket("fee_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        let receipt = runner.exec_and_dump("open_account", manifest, account, Some("clusters/lsu_looping_weftv2"));
+        receipt.expect_commit_success();
+    }
+
+    pub fn get_account_info(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId) -> LLWeftV2AccountInfo {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "get_account_info", manifest_args!(user_id));
+
+        let receipt = runner.exec_and_dump("get_account_info", manifest, account, Some("clusters/lsu_looping_weftv2"));
+        receipt.expect_commit_success().output(1)
+    }
+
+    pub fn enter(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        user_id: NonFungibleLocalId,
+        amount: Decimal,
+        target_leverage: Decimal,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .withdraw_from_account(account.address, self.lsu, amount)
+            .take_all_from_worktop(self.lsu, "lsu_bucket")
+            .withdraw_from_account(account.address, XRD, dec!(4))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "enter", |lookup| {
+                (
+                    lookup.proof("user_badge"),
+                    lookup.bucket("lsu_bucket"),
+                    target_leverage,
+                    lookup.bucket("fee_bucket"),
+                )
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("enter", manifest, account, Some("clusters/lsu_looping_weftv2"))
+    }
+
+    pub fn exit(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId, repayment: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .withdraw_from_account(account.address, XRD, repayment)
+            .take_all_from_worktop(XRD, "repayment_bucket")
+            .withdraw_from_account(account.address, XRD, dec!(4))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "exit", |lookup| {
+                (lookup.proof("user_badge"), lookup.bucket("repayment_bucket"), lookup.bucket("fee_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("exit", manifest, account, Some("clusters/lsu_looping_weftv2"))
+    }
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct LLWeftV2LoopPosition {
+    pub deposited: Decimal,
+    pub principal: Decimal,
+    pub entry_rate: Decimal,
+    pub entered_at: Decimal,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct LLWeftV2AccountInfo {
+    pub cdp_id: NonFungibleLocalId,
+    pub collateral: Decimal,
+    pub collateral_value: Decimal,
+    pub debt: Decimal,
+    pub debt_value: Decimal,
+    pub health: Decimal,
+    pub leverage: Decimal,
+    pub staking_yield: Decimal,
+    pub borrow_cost: Decimal,
+    pub net_yield: Decimal,
+    pub position: Option<LLWeftV2LoopPosition>,
+}
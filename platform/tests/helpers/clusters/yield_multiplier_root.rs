@@ -0,0 +1,205 @@
+use crate::helpers::integrations::mocks::{MockRootMarket, MocksFactory};
+use crate::helpers::prelude::*;
+use scrypto_test::prelude::*;
+
+//] ------------------ Factory ----------------- */
+#[derive(Debug, Clone, Copy)]
+pub struct YMRootClusterFactory {
+    pub package_address: PackageAddress,
+    pub mocks_factory: MocksFactory,
+}
+
+impl YMRootClusterFactory {
+    pub fn new(ledger: &mut Ledger) -> Self {
+        let path = merge_path("../strategies/yield_multiplier_root");
+        let package_address = ledger.compile_and_publish(path);
+        let mocks_factory = MocksFactory::new(ledger);
+
+        Self { package_address, mocks_factory }
+    }
+
+    pub fn instantiate(
+        &self,
+        runner: &mut Runner,
+        // Authorisation
+        owner_rule: AccessRule,
+        // Link
+        platform: ComponentAddress,
+        link_badge: ResourceAddress,
+        user_badge: ResourceAddress,
+        // Cluster
+        supply: ResourceAddress,
+        debt: ResourceAddress,
+    ) -> (YMRootCluster, MockRootMarket) {
+        let owner_account = runner.owner_account;
+        let admin_rule = rule!(deny_all);
+
+        // Integrate with a mock Root market, which mints its own CDPs
+        let root_market = self.mocks_factory.instantiate_root_market(runner);
+
+        // Call instantiation function
+        #[rustfmt::skip]
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                self.package_address,
+                "YieldMultiplierRootCluster", "instantiate",
+                manifest_args!(
+                    owner_rule, admin_rule,
+                    platform,
+                    supply, debt,
+                    root_market.component, root_market.cdp
+                ),
+            );
+
+        let receipt = runner.exec_and_dump("instantiate", manifest, &owner_account, Some("clusters/yield_multiplier_root"));
+
+        // Collect output
+        let component = receipt.expect_commit_success().new_component_addresses()[0];
+        let execution_terms = receipt.expect_commit_success().new_resource_addresses()[0];
+
+        // Return YMRootCluster
+        let cluster = YMRootCluster {
+            component,
+            execution_terms,
+            platform,
+            link_badge,
+            user_badge,
+            supply,
+            debt,
+            root_market: root_market.component,
+            cdp: root_market.cdp,
+        };
+
+        (cluster, root_market)
+    }
+}
+
+//] ------------------ Cluster ----------------- */
+#[derive(Debug, Clone, Copy)]
+pub struct YMRootCluster {
+    // General
+    pub component: ComponentAddress,
+    pub execution_terms: ResourceAddress,
+    // Linking
+    pub platform: ComponentAddress,
+    pub link_badge: ResourceAddress,
+    pub user_badge: ResourceAddress,
+    // Cluster
+    pub supply: ResourceAddress,
+    pub debt: ResourceAddress,
+    // Root integration
+    pub root_market: ComponentAddress,
+    pub cdp: ResourceAddress,
+}
+
+impl YMRootCluster {
+    pub fn open_account(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId, cdp_id: NonFungibleLocalId) {
+        let receipt = self.try_open_account(runner, account, user_id, cdp_id);
+        receipt.expect_commit_success();
+    }
+
+    /// Opens an account for the user with the given CDP, returning the receipt whether or not it succeeds.
+    pub fn try_open_account(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        user_id: NonFungibleLocalId,
+        cdp_id: NonFungibleLocalId,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .withdraw_non_fungibles_from_account(account.address, self.cdp, vec![cdp_id.clone()])
+            .take_non_fungibles_from_worktop(self.cdp, vec![cdp_id], "cdp_bucket")
+            .withdraw_from_account(account.address, XRD, dec!(16))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "open_account", |lookup| {
+                (lookup.proof("user_badge"), lookup.bucket("cdp_bucket"), lookup.bucket("fee_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("open_account", manifest, account, Some("clusters/yield_multiplier_root"))
+    }
+
+    pub fn close_account(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .withdraw_from_account(account.address, XRD, dec!(16))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "close_account", |lookup| {
+                (lookup.proof("user_badge"), lookup.bucket("fee_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("close_account", manifest, account, Some("clusters/yield_multiplier_root"))
+    }
+
+    pub fn execute(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .call_method_with_name_lookup(self.component, "start_execution", |lookup| {
+                (Some(lookup.proof("user_badge")), None::<(NonFungibleLocalId, ManifestProof)>)
+            })
+            .take_all_from_worktop(self.cdp, "cdp_bucket")
+            .take_all_from_worktop(self.execution_terms, "terms_bucket")
+            .withdraw_from_account(account.address, XRD, dec!(4))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "end_execution", |lookup| {
+                (lookup.bucket("cdp_bucket"), lookup.bucket("terms_bucket"), lookup.bucket("fee_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("execute", manifest, account, Some("clusters/yield_multiplier_root"))
+    }
+
+    pub fn get_account_info(&self, runner: &mut Runner, user_id: NonFungibleLocalId) -> YMRootAccountInfo {
+        let owner_account = runner.owner_account;
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "get_account_info", manifest_args!(user_id));
+
+        let receipt = runner.exec_and_dump("get_account_info", manifest, &owner_account, Some("clusters/yield_multiplier_root"));
+        receipt.expect_commit_success().output(1)
+    }
+
+    pub fn get_market_rates(&self, runner: &mut Runner, leverage: Decimal) -> TransactionReceipt {
+        let owner_account = runner.owner_account;
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "get_market_rates", manifest_args!(leverage));
+
+        runner.exec_and_dump("get_market_rates", manifest, &owner_account, Some("clusters/yield_multiplier_root"))
+    }
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct YMRootAccountInfo {
+    pub cdp_id: NonFungibleLocalId,
+    pub supply_units: Decimal,
+    pub debt_units: Decimal,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct YMRootMarketRates {
+    pub supply: YMRootRateInfo,
+    pub debt: YMRootRateInfo,
+    pub leverage: Decimal,
+    pub net_apr: Decimal,
+    pub net_apy: Decimal,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct YMRootRateInfo {
+    pub resource: ResourceAddress,
+    pub supply_apr: Decimal,
+    pub borrow_apr: Decimal,
+    pub utilisation: Decimal,
+}
@@ -0,0 +1,175 @@
+use crate::helpers::integrations::mocks::MocksFactory;
+use crate::helpers::prelude::*;
+use scrypto_test::prelude::*;
+
+//] ------------------ Factory ----------------- */
+#[derive(Debug, Clone, Copy)]
+pub struct YMWeftV2VaultFactory {
+    pub package_address: PackageAddress,
+    pub mocks_factory: MocksFactory,
+}
+
+impl YMWeftV2VaultFactory {
+    pub fn new(ledger: &mut Ledger) -> Self {
+        let path = merge_path("../strategies/yield_multiplier_vault_weftv2");
+        let package_address = ledger.compile_and_publish(path);
+        let mocks_factory = MocksFactory::new(ledger);
+
+        Self { package_address, mocks_factory }
+    }
+
+    pub fn instantiate(
+        &self,
+        runner: &mut Runner,
+        // Authorisation
+        owner_rule: AccessRule,
+        // Link
+        platform: ComponentAddress,
+        // Vault
+        supply: ResourceAddress,
+        debt: ResourceAddress,
+        // Integration
+        cdp: ResourceAddress,
+        cdp_id: NonFungibleLocalId,
+    ) -> YMWeftV2Vault {
+        let owner_account = runner.owner_account;
+        let admin_rule = rule!(deny_all);
+
+        // Integrate with a mock Weft market pricing the faucet's assets
+        let weft_market = self.mocks_factory.instantiate_weft_market(runner, cdp);
+        weft_market.set_faucet_prices(runner);
+
+        // Call instantiation function, with a CDP held by the owner
+        #[rustfmt::skip]
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_non_fungibles_from_account(owner_account.address, cdp, vec![cdp_id.clone()])
+            .take_non_fungibles_from_worktop(cdp, vec![cdp_id], "cdp_bucket")
+            .call_function_with_name_lookup(
+                self.package_address,
+                "YieldMultiplierWeftV2Vault", "instantiate",
+                |lookup| (
+                    owner_rule, admin_rule,
+                    platform,
+                    supply, debt,
+                    weft_market.component, lookup.bucket("cdp_bucket")
+                ),
+            );
+
+        let receipt = runner.exec_and_dump("instantiate", manifest, &owner_account, Some("clusters/yield_multiplier_vault_weftv2"));
+
+        // Collect output; the share, ticket and execution terms resources, in order of creation
+        let commit = receipt.expect_commit_success();
+        let component = commit.new_component_addresses()[0];
+        let shares = commit.new_resource_addresses()[0];
+        let tickets = commit.new_resource_addresses()[1];
+        let execution_terms = commit.new_resource_addresses()[2];
+
+        YMWeftV2Vault { component, shares, tickets, execution_terms, supply, debt, cdp }
+    }
+}
+
+//] ------------------- Vault ------------------ */
+#[derive(Debug, Clone, Copy)]
+pub struct YMWeftV2Vault {
+    // General
+    pub component: ComponentAddress,
+    pub shares: ResourceAddress,
+    pub tickets: ResourceAddress,
+    pub execution_terms: ResourceAddress,
+    // Vault
+    pub supply: ResourceAddress,
+    pub debt: ResourceAddress,
+    // WeftV2 integration
+    pub cdp: ResourceAddress,
+}
+
+impl YMWeftV2Vault {
+    pub fn deposit(&self, runner: &mut Runner, account: &SimAccount, amount: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account.address, self.supply, amount)
+            .take_all_from_worktop(self.supply, "deposit_bucket")
+            .call_method_with_name_lookup(self.component, "deposit", |lookup| (lookup.bucket("deposit_bucket"),))
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("deposit", manifest, account, Some("clusters/yield_multiplier_vault_weftv2"))
+    }
+
+    pub fn withdraw(&self, runner: &mut Runner, account: &SimAccount, shares: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account.address, self.shares, shares)
+            .take_all_from_worktop(self.shares, "share_bucket")
+            .call_method_with_name_lookup(self.component, "withdraw", |lookup| (lookup.bucket("share_bucket"),))
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("withdraw", manifest, account, Some("clusters/yield_multiplier_vault_weftv2"))
+    }
+
+    pub fn process_queue(&self, runner: &mut Runner, account: &SimAccount, limit: u64) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "process_queue", manifest_args!(limit));
+
+        runner.exec_and_dump("process_queue", manifest, account, Some("clusters/yield_multiplier_vault_weftv2"))
+    }
+
+    pub fn claim(&self, runner: &mut Runner, account: &SimAccount, ticket_id: NonFungibleLocalId) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_non_fungibles_from_account(account.address, self.tickets, vec![ticket_id.clone()])
+            .take_non_fungibles_from_worktop(self.tickets, vec![ticket_id], "ticket_bucket")
+            .call_method_with_name_lookup(self.component, "claim", |lookup| (lookup.bucket("ticket_bucket"),))
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("claim", manifest, account, Some("clusters/yield_multiplier_vault_weftv2"))
+    }
+
+    pub fn set_deleveraging(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        owner_badge: ResourceAddress,
+        deleveraging: bool,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .call_method(self.component, "set_deleveraging", manifest_args!(deleveraging));
+
+        runner.exec_and_dump("set_deleveraging", manifest, account, Some("clusters/yield_multiplier_vault_weftv2"))
+    }
+
+    /// Executes on the vault as the owner, withdrawing `amount` of the idle funds and returning them unchanged. The
+    /// vault's CDP is returned, unless `cdp_id` names another CDP of the account's to return in its place.
+    pub fn execute(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        owner_badge: ResourceAddress,
+        amount: Decimal,
+        cdp_id: Option<NonFungibleLocalId>,
+    ) -> TransactionReceipt {
+        let mut manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .call_method(self.component, "start_execution", manifest_args!(amount))
+            .take_all_from_worktop(self.execution_terms, "terms_bucket")
+            .take_all_from_worktop(self.supply, "idle_bucket");
+
+        manifest = match cdp_id {
+            Some(cdp_id) => manifest
+                .deposit_batch(account.address, ManifestExpression::EntireWorktop)
+                .withdraw_non_fungibles_from_account(account.address, self.cdp, vec![cdp_id.clone()])
+                .take_non_fungibles_from_worktop(self.cdp, vec![cdp_id], "cdp_bucket"),
+            None => manifest.take_all_from_worktop(self.cdp, "cdp_bucket"),
+        };
+
+        let manifest = manifest.call_method_with_name_lookup(self.component, "end_execution", |lookup| {
+            (lookup.bucket("cdp_bucket"), lookup.bucket("terms_bucket"), lookup.bucket("idle_bucket"))
+        });
+
+        runner.exec_and_dump("execute", manifest, account, Some("clusters/yield_multiplier_vault_weftv2"))
+    }
+}
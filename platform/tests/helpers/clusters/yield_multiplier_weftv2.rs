@@ -1,18 +1,133 @@
+use crate::helpers::integrations::mocks::{MockPrices, MockWeftMarket, MocksFactory};
+use crate::helpers::integrations::weftv2::MockWeftV2;
+use crate::helpers::platform::Platform;
 use crate::helpers::prelude::*;
+use scrypto::prelude::indexmap::IndexMap;
 use scrypto_test::prelude::*;
 
+//] ------------------ Arrange ----------------- */
+/// Instantiates and links a cluster of USDT supply and xwBTC debt, and opens an account for Alice with a CDP holding
+/// `collateral` of USDT; an empty CDP if zero.
+pub fn arrange_with_account(collateral: Decimal) -> (Runner, Platform, YMWeftV2Cluster) {
+    // Create a test runner and platform
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    // Instantiate a YieldMultiplierWeftCluster
+    let mut weftv2 = MockWeftV2::new(&mut runner);
+    let ym_weftv2_cluster_factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let supply = runner.faucet.usdt.address;
+    let debt = runner.faucet.xwbtc.address;
+
+    let cluster = ym_weftv2_cluster_factory.instantiate(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        supply,
+        debt,
+        weftv2.cdp,
+    );
+
+    // Link cluster to platform
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    // Get a user badge and open an account
+    platform.new_user(&mut runner, &alice_account);
+
+    let cdp_id = match collateral > dec!(0) {
+        true => weftv2.mint(&mut runner, alice_account, Some(indexmap! { supply => collateral }), None, false),
+        false => weftv2.mint_empty(&mut runner, alice_account),
+    };
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+
+    (runner, platform, cluster)
+}
+
+/// Instantiates and links a cluster of USDT supply and USDC debt in the given mode, valued by its Weft market and
+/// priced by a Root-style oracle, both at the faucet's prices. Alice opens an account with 1,000 of the collateral
+/// and 500 of the loan asset, and the market holds the collateral for its withdrawal on unwind.
+pub fn arrange_unwind(mode: YMWeftV2LeverageMode) -> (Runner, Platform, YMWeftV2Cluster, MockPrices) {
+    // Create a test runner and platform
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    // Instantiate a YieldMultiplierWeftCluster in the given mode
+    let mut weftv2 = MockWeftV2::new(&mut runner);
+    let ym_weftv2_cluster_factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let supply = runner.faucet.usdt.address;
+    let debt = runner.faucet.usdc.address;
+    let (collateral, loan) = match mode {
+        YMWeftV2LeverageMode::Long => (supply, debt),
+        YMWeftV2LeverageMode::Short => (debt, supply),
+    };
+
+    let cluster = ym_weftv2_cluster_factory.instantiate_with_mode(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        supply,
+        debt,
+        mode,
+        weftv2.cdp,
+    );
+    let weft_market = MockWeftMarket { component: cluster.weft_market };
+
+    // Link cluster to platform
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    // Get a user badge and open an account
+    platform.new_user(&mut runner, &alice_account);
+
+    let cdp_id = weftv2.mint(
+        &mut runner,
+        alice_account,
+        Some(indexmap! { collateral => dec!(1000) }),
+        Some(indexmap! { loan => dec!(500) }),
+        false,
+    );
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+
+    // Fund the market with the collateral
+    runner.faucet.mint(&mut runner.ledger, collateral, owner_account, dec!(1000));
+    weft_market.deposit_liquidity(&mut runner, &owner_account, collateral, dec!(1000));
+
+    // Price the cluster by an oracle
+    let oracle = ym_weftv2_cluster_factory.mocks_factory.instantiate_oracle(&mut runner);
+    for asset in [runner.faucet.usdt, runner.faucet.usdc] {
+        oracle.set_price(&mut runner, asset.address, asset.price, None);
+    }
+
+    let sources = vec![YMWeftV2OracleSource::Root(oracle.component)];
+    let receipt = cluster.set_oracle(&mut runner, &owner_account, platform.owner_badge, sources, 600, dec!(0.02));
+    receipt.expect_commit_success();
+
+    (runner, platform, cluster, MockPrices { market: weft_market, oracle })
+}
+
 //] ------------------ Factory ----------------- */
 #[derive(Debug, Clone, Copy)]
 pub struct YMWeftV2ClusterFactory {
     pub package_address: PackageAddress,
+    pub mocks_factory: MocksFactory,
 }
 
 impl YMWeftV2ClusterFactory {
     pub fn new(ledger: &mut Ledger) -> Self {
         let path = merge_path("../strategies/yield_multiplier_weftv2");
         let package_address = ledger.compile_and_publish(path);
+        let mocks_factory = MocksFactory::new(ledger);
 
-        Self { package_address }
+        Self { package_address, mocks_factory }
     }
 
     pub fn instantiate(
@@ -29,10 +144,43 @@ impl YMWeftV2ClusterFactory {
         debt: ResourceAddress,
         // Integration
         cdp: ResourceAddress,
+    ) -> YMWeftV2Cluster {
+        self.instantiate_with_mode(
+            runner,
+            owner_rule,
+            platform,
+            link_badge,
+            user_badge,
+            supply,
+            debt,
+            YMWeftV2LeverageMode::Long,
+            cdp,
+        )
+    }
+
+    pub fn instantiate_with_mode(
+        &self,
+        runner: &mut Runner,
+        // Authorisation
+        owner_rule: AccessRule,
+        // Link
+        platform: ComponentAddress,
+        link_badge: ResourceAddress,
+        user_badge: ResourceAddress,
+        // Cluster
+        supply: ResourceAddress,
+        debt: ResourceAddress,
+        mode: YMWeftV2LeverageMode,
+        // Integration
+        cdp: ResourceAddress,
     ) -> YMWeftV2Cluster {
         let owner_account = runner.owner_account;
         let admin_rule = rule!(deny_all);
 
+        // Integrate with a mock Weft market pricing the faucet's assets
+        let weft_market = self.mocks_factory.instantiate_weft_market(runner, cdp);
+        weft_market.set_faucet_prices(runner);
+
         // Call instantiation function
         #[rustfmt::skip]
         let manifest = ManifestBuilder::new()
@@ -43,8 +191,8 @@ impl YMWeftV2ClusterFactory {
                 manifest_args!(
                     owner_rule, admin_rule,
                     platform,
-                    supply, debt,
-                    platform, cdp
+                    supply, debt, mode,
+                    weft_market.component, cdp
                 ),
             );
 
@@ -56,12 +204,82 @@ impl YMWeftV2ClusterFactory {
 
         // Collect output
         let component = receipt.expect_commit_success().new_component_addresses()[0];
-        // let execution_terms = receipt.expect_commit_success().new_resource_addresses()[0];
+        let execution_terms = receipt.expect_commit_success().new_resource_addresses()[0];
 
         // println!("Execution Terms: {:?}\n", execution_terms,);
 
         // Return YMWeftV2Cluster
-        YMWeftV2Cluster { component, platform, link_badge, user_badge, supply, debt, cdp }
+        YMWeftV2Cluster {
+            component,
+            execution_terms,
+            platform,
+            link_badge,
+            user_badge,
+            supply,
+            debt,
+            weft_market: weft_market.component,
+            cdp,
+        }
+    }
+
+    pub fn instantiate_basket(
+        &self,
+        runner: &mut Runner,
+        // Authorisation
+        owner_rule: AccessRule,
+        // Link
+        platform: ComponentAddress,
+        link_badge: ResourceAddress,
+        user_badge: ResourceAddress,
+        // Cluster
+        collaterals: IndexMap<ResourceAddress, Decimal>,
+        loans: IndexMap<ResourceAddress, Decimal>,
+        // Integration
+        cdp: ResourceAddress,
+    ) -> YMWeftV2Cluster {
+        let owner_account = runner.owner_account;
+        let admin_rule = rule!(deny_all);
+
+        // Integrate with a mock Weft market pricing the faucet's assets
+        let weft_market = self.mocks_factory.instantiate_weft_market(runner, cdp);
+        weft_market.set_faucet_prices(runner);
+
+        // Call instantiation function
+        #[rustfmt::skip]
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_function(
+                self.package_address,
+                "YieldMultiplierWeftV2Cluster", "instantiate_basket",
+                manifest_args!(
+                    owner_rule, admin_rule,
+                    platform,
+                    collaterals.clone(), loans.clone(),
+                    weft_market.component, cdp
+                ),
+            );
+
+        let receipt = runner.exec_and_dump("instantiate_basket", manifest, &owner_account, Some("clusters/yield_multiplier_weftv2"));
+
+        // Collect output
+        let component = receipt.expect_commit_success().new_component_addresses()[0];
+        let execution_terms = receipt.expect_commit_success().new_resource_addresses()[0];
+
+        // Return YMWeftV2Cluster, with the basket's primary assets
+        let supply = *collaterals.keys().next().unwrap();
+        let debt = *loans.keys().next().unwrap();
+
+        YMWeftV2Cluster {
+            component,
+            execution_terms,
+            platform,
+            link_badge,
+            user_badge,
+            supply,
+            debt,
+            weft_market: weft_market.component,
+            cdp,
+        }
     }
 }
 
@@ -70,6 +288,7 @@ impl YMWeftV2ClusterFactory {
 pub struct YMWeftV2Cluster {
     // General
     pub component: ComponentAddress,
+    pub execution_terms: ResourceAddress,
     // Linking
     pub platform: ComponentAddress,
     pub link_badge: ResourceAddress,
@@ -78,9 +297,502 @@ pub struct YMWeftV2Cluster {
     pub supply: ResourceAddress,
     pub debt: ResourceAddress,
     // WeftV2 integration
+    pub weft_market: ComponentAddress,
     pub cdp: ResourceAddress,
 }
 
+impl YMWeftV2Cluster {
+    pub fn open_account(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId, cdp_id: NonFungibleLocalId) {
+        let receipt = self.try_open_account(runner, account, user_id, cdp_id);
+        receipt.expect_commit_success();
+    }
+
+    /// Opens an account for the user with the given CDP, returning the receipt whether or not it succeeds.
+    pub fn try_open_account(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        user_id: NonFungibleLocalId,
+        cdp_id: NonFungibleLocalId,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .withdraw_non_fungibles_from_account(account.address, self.cdp, vec![cdp_id.clone()])
+            .take_non_fungibles_from_worktop(self.cdp, vec![cdp_id], "cdp_bucket")
+            .withdraw_from_account(account.address, XRD, dec!(16))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "open_account", |lookup| {
+                (lookup.proof("user_badge"), lookup.bucket("cdp_bucket"), lookup.bucket("fee_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("open_account", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    pub fn close_account(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .withdraw_from_account(account.address, XRD, dec!(16))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "close_account", |lookup| {
+                (lookup.proof("user_badge"), lookup.bucket("fee_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("close_account", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    pub fn set_delegate(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        user_id: NonFungibleLocalId,
+        rule: AccessRule,
+        max_debt_increase: Option<Decimal>,
+        min_collateral_retained: Option<Decimal>,
+    ) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .call_method_with_name_lookup(self.component, "set_delegate", |lookup| {
+                (
+                    lookup.proof("user_badge"),
+                    rule,
+                    max_debt_increase,
+                    min_collateral_retained,
+                    None::<Instant>,
+                )
+            });
+
+        let receipt = runner.exec_and_dump("set_delegate", manifest, account, Some("clusters/yield_multiplier_weftv2"));
+        receipt.expect_commit_success();
+    }
+
+    pub fn get_account_info(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId) -> YMWeftV2AccountInfo {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "get_account_info", manifest_args!(user_id));
+
+        let receipt = runner.exec_and_dump("get_account_info", manifest, account, Some("clusters/yield_multiplier_weftv2"));
+        receipt.expect_commit_success().output(1)
+    }
+
+    /// Executes on the user's account as their delegate, presenting the given badge from the account, making the given
+    /// adjustment to the CDP in between, if any.
+    pub fn execute_as_delegate(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        user_id: NonFungibleLocalId,
+        delegate_badge: NonFungibleGlobalId,
+        adjustment: Option<YMWeftV2Adjustment>,
+    ) -> TransactionReceipt {
+        let (badge_resource, badge_id) = delegate_badge.into_parts();
+
+        let mut manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, badge_resource, vec![badge_id])
+            .pop_from_auth_zone("delegate_proof")
+            .call_method_with_name_lookup(self.component, "start_execution", |lookup| {
+                (None::<ManifestProof>, Some((user_id, lookup.proof("delegate_proof"))))
+            })
+            .take_all_from_worktop(self.cdp, "cdp_bucket")
+            .take_all_from_worktop(self.execution_terms, "terms_bucket");
+
+        if let Some(adjustment) = adjustment {
+            manifest = self.adjust(manifest, account, adjustment);
+        }
+
+        let manifest = manifest
+            .withdraw_from_account(account.address, XRD, dec!(4))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "end_execution", |lookup| {
+                (lookup.bucket("cdp_bucket"), lookup.bucket("terms_bucket"), lookup.bucket("fee_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("execute_as_delegate", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    pub fn execute(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .call_method_with_name_lookup(self.component, "start_execution", |lookup| {
+                (Some(lookup.proof("user_badge")), None::<(NonFungibleLocalId, ManifestProof)>)
+            })
+            .take_all_from_worktop(self.cdp, "cdp_bucket")
+            .take_all_from_worktop(self.execution_terms, "terms_bucket")
+            .withdraw_from_account(account.address, XRD, dec!(4))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "end_execution", |lookup| {
+                (lookup.bucket("cdp_bucket"), lookup.bucket("terms_bucket"), lookup.bucket("fee_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("execute", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    /// Executes on the user's account, making the given adjustment to the CDP through the Weft market in between.
+    pub fn execute_adjusting(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        user_id: NonFungibleLocalId,
+        adjustment: YMWeftV2Adjustment,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .call_method_with_name_lookup(self.component, "start_execution", |lookup| {
+                (Some(lookup.proof("user_badge")), None::<(NonFungibleLocalId, ManifestProof)>)
+            })
+            .take_all_from_worktop(self.cdp, "cdp_bucket")
+            .take_all_from_worktop(self.execution_terms, "terms_bucket");
+
+        let manifest = self
+            .adjust(manifest, account, adjustment)
+            .withdraw_from_account(account.address, XRD, dec!(4))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "end_execution", |lookup| {
+                (lookup.bucket("cdp_bucket"), lookup.bucket("terms_bucket"), lookup.bucket("fee_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("execute_adjusting", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    /// Appends the given adjustment of the CDP held in `cdp_bucket` to an execution's manifest, funding any deposit
+    /// into the CDP from the account.
+    pub fn adjust(&self, manifest: ManifestBuilder, account: &SimAccount, adjustment: YMWeftV2Adjustment) -> ManifestBuilder {
+        let manifest = manifest.create_proof_from_bucket_of_all("cdp_bucket", "cdp_proof");
+
+        match adjustment {
+            YMWeftV2Adjustment::Borrow(resource, amount) => {
+                manifest.call_method_with_name_lookup(self.weft_market, "borrow", |lookup| (lookup.proof("cdp_proof"), vec![(resource, amount)]))
+            }
+            YMWeftV2Adjustment::RemoveCollateral(resource, amount) => {
+                manifest.call_method_with_name_lookup(self.weft_market, "remove_collateral", |lookup| {
+                    (lookup.proof("cdp_proof"), vec![(resource, amount, false)])
+                })
+            }
+            YMWeftV2Adjustment::AddCollateral(resource, amount) => manifest
+                .withdraw_from_account(account.address, resource, amount)
+                .take_all_from_worktop(resource, "collateral_bucket")
+                .call_method_with_name_lookup(self.weft_market, "add_collateral", |lookup| {
+                    (lookup.proof("cdp_proof"), vec![lookup.bucket("collateral_bucket")])
+                }),
+            YMWeftV2Adjustment::Repay(resource, amount) => manifest
+                .withdraw_from_account(account.address, resource, amount)
+                .take_all_from_worktop(resource, "repayment_bucket")
+                .call_method_with_name_lookup(self.weft_market, "repay", |lookup| {
+                    (lookup.proof("cdp_proof"), vec![lookup.bucket("repayment_bucket")])
+                }),
+        }
+    }
+
+    pub fn set_execution_invariants(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        owner_badge: ResourceAddress,
+        max_debt_increase: Option<Decimal>,
+        min_collateral_retained: Option<Decimal>,
+        max_leverage_change: Option<Decimal>,
+        freeze_config: bool,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .call_method(
+                self.component,
+                "set_execution_invariants",
+                manifest_args!(max_debt_increase, min_collateral_retained, max_leverage_change, freeze_config),
+            );
+
+        runner.exec_and_dump("set_execution_invariants", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    pub fn set_risk_limits(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        owner_badge: ResourceAddress,
+        max_accounts: Option<u64>,
+        max_total_collateral_value: Option<Decimal>,
+        min_collateral: Option<Decimal>,
+        max_collateral: Option<Decimal>,
+        min_debt: Option<Decimal>,
+        max_debt: Option<Decimal>,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .call_method(
+                self.component,
+                "set_risk_limits",
+                manifest_args!(
+                    max_accounts,
+                    max_total_collateral_value,
+                    min_collateral,
+                    max_collateral,
+                    min_debt,
+                    max_debt
+                ),
+            );
+
+        runner.exec_and_dump("set_risk_limits", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    pub fn set_access_mode(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        owner_badge: ResourceAddress,
+        access_mode: YMWeftV2AccessMode,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .call_method(self.component, "set_access_mode", manifest_args!(access_mode));
+
+        runner.exec_and_dump("set_access_mode", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    pub fn add_to_allow_list(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        owner_badge: ResourceAddress,
+        user_ids: Vec<NonFungibleLocalId>,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .call_method(self.component, "add_to_allow_list", manifest_args!(user_ids));
+
+        runner.exec_and_dump("add_to_allow_list", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    pub fn remove_from_allow_list(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        owner_badge: ResourceAddress,
+        user_ids: Vec<NonFungibleLocalId>,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .call_method(self.component, "remove_from_allow_list", manifest_args!(user_ids));
+
+        runner.exec_and_dump("remove_from_allow_list", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    pub fn set_nft_collaterals(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        owner_badge: ResourceAddress,
+        nft_collaterals: IndexMap<ResourceAddress, Decimal>,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .call_method(self.component, "set_nft_collaterals", manifest_args!(nft_collaterals));
+
+        runner.exec_and_dump("set_nft_collaterals", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+    pub fn import_account(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        user_id: NonFungibleLocalId,
+        cdp_id: NonFungibleLocalId,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_non_fungibles_from_account(account.address, self.cdp, vec![cdp_id.clone()])
+            .take_non_fungibles_from_worktop(self.cdp, vec![cdp_id], "cdp_bucket")
+            .take_all_from_worktop(self.supply, "collateral_bucket")
+            .call_method_with_name_lookup(self.component, "import_account", |lookup| {
+                (user_id, lookup.bucket("cdp_bucket"), lookup.bucket("collateral_bucket"), dec!(0))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("import_account", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+    pub fn get_account_history(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        user_id: NonFungibleLocalId,
+        offset: u64,
+        limit: u64,
+    ) -> TransactionReceipt {
+        let manifest =
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(self.component, "get_account_history", manifest_args!(user_id, offset, limit));
+
+        runner.exec_and_dump("get_account_history", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+    pub fn set_triggers(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        user_id: NonFungibleLocalId,
+        stop_loss: Option<Decimal>,
+        take_profit: Option<Decimal>,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .call_method_with_name_lookup(self.component, "set_triggers", |lookup| {
+                (lookup.proof("user_badge"), stop_loss, take_profit)
+            });
+
+        runner.exec_and_dump("set_triggers", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    pub fn set_oracle(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        owner_badge: ResourceAddress,
+        sources: Vec<YMWeftV2OracleSource>,
+        max_staleness: i64,
+        max_deviation: Decimal,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .call_method(self.component, "set_oracle", manifest_args!(sources, max_staleness, max_deviation));
+
+        runner.exec_and_dump("set_oracle", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    pub fn set_flash_loan_provider(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        owner_badge: ResourceAddress,
+        source: Option<YMWeftV2FlashLoanSource>,
+        fee_rate: Decimal,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .call_method(self.component, "set_flash_loan_provider", manifest_args!(source, fee_rate));
+
+        runner.exec_and_dump("set_flash_loan_provider", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    pub fn get_flash_loan_quote(&self, runner: &mut Runner, account: &SimAccount, amount: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "get_flash_loan_quote", manifest_args!(amount));
+
+        runner.exec_and_dump("get_flash_loan_quote", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    /// Executes on the user's account, flash borrowing `amount` of the debt asset through the cluster in between;
+    /// the loan is repaid in full if `repay` is set, and otherwise kept.
+    pub fn execute_with_flash_loan(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        user_id: NonFungibleLocalId,
+        loan_terms: ResourceAddress,
+        amount: Decimal,
+        repay: bool,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .call_method_with_name_lookup(self.component, "start_execution", |lookup| {
+                (Some(lookup.proof("user_badge")), None::<(NonFungibleLocalId, ManifestProof)>)
+            })
+            .take_all_from_worktop(self.cdp, "cdp_bucket")
+            .take_all_from_worktop(self.execution_terms, "terms_bucket")
+            .create_proof_from_bucket_of_all("terms_bucket", "terms_proof")
+            .call_method_with_name_lookup(self.component, "take_flash_loan", |lookup| (lookup.proof("terms_proof"), amount));
+
+        let manifest = match repay {
+            true => manifest
+                .take_all_from_worktop(self.debt, "loan_bucket")
+                .take_all_from_worktop(loan_terms, "loan_terms_bucket")
+                .call_method_with_name_lookup(self.component, "repay_flash_loan", |lookup| {
+                    (lookup.bucket("loan_bucket"), lookup.bucket("loan_terms_bucket"))
+                }),
+            false => manifest,
+        };
+
+        let manifest = manifest
+            .withdraw_from_account(account.address, XRD, dec!(4))
+            .take_all_from_worktop(XRD, "fee_bucket")
+            .call_method_with_name_lookup(self.component, "end_execution", |lookup| {
+                (lookup.bucket("cdp_bucket"), lookup.bucket("terms_bucket"), lookup.bucket("fee_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("execute_with_flash_loan", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    /// Executes the user's trigger as a keeper, repaying the CDP's debt with `repayment` of the loan asset.
+    pub fn execute_trigger(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        user_id: NonFungibleLocalId,
+        loan: ResourceAddress,
+        repayment: Decimal,
+    ) -> TransactionReceipt {
+        let manifest = match repayment > dec!(0) {
+            true => ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .withdraw_from_account(account.address, loan, repayment),
+            false => ManifestBuilder::new().lock_fee_from_faucet(),
+        };
+
+        let manifest = manifest
+            .take_all_from_worktop(loan, "repayment_bucket")
+            .call_method_with_name_lookup(self.component, "execute_trigger", |lookup| (user_id, lookup.bucket("repayment_bucket")))
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("execute_trigger", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+
+    pub fn claim(&self, runner: &mut Runner, account: &SimAccount, user_id: NonFungibleLocalId) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .call_method_with_name_lookup(self.component, "claim", |lookup| (lookup.proof("user_badge"),))
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("claim", manifest, account, Some("clusters/yield_multiplier_weftv2"))
+    }
+}
+
+/// Change made to a CDP through the Weft market during an execution.
+#[derive(Debug, Clone, Copy)]
+pub enum YMWeftV2Adjustment {
+    Borrow(ResourceAddress, Decimal),
+    RemoveCollateral(ResourceAddress, Decimal),
+    AddCollateral(ResourceAddress, Decimal),
+    Repay(ResourceAddress, Decimal),
+}
+
 #[derive(ScryptoSbor, Debug, Clone, Copy, ManifestSbor)]
 pub enum YMWeftV2ClusterService {
     OpenAccount,
@@ -89,4 +801,95 @@ pub enum YMWeftV2ClusterService {
     Link,
     // Unlink,
     CallLinked,
+    Refinance,
+    Trigger,
+}
+
+#[derive(ScryptoSbor, Debug, Clone, Copy, ManifestSbor)]
+pub enum YMWeftV2LeverageMode {
+    Long,
+    Short,
+}
+
+#[derive(ScryptoSbor, Debug, Clone, Copy, ManifestSbor)]
+pub enum YMWeftV2FlashLoanSource {
+    Weft(ComponentAddress),
+    Root(ComponentAddress),
+    Vault(ComponentAddress),
+}
+
+#[derive(ScryptoSbor, Debug, Clone, Copy, ManifestSbor)]
+pub enum YMWeftV2AccessMode {
+    Open,
+    AllowList,
+    AccessResource(ResourceAddress),
+}
+
+#[derive(ScryptoSbor, Debug, Clone, Copy, ManifestSbor)]
+pub enum YMWeftV2OracleSource {
+    Root(ComponentAddress),
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct YMWeftV2AccountInfo {
+    pub cdp_id: NonFungibleLocalId,
+    pub supply: Decimal,
+    pub supply_value: Decimal,
+    pub debt: Decimal,
+    pub debt_value: Decimal,
+    pub health: Decimal,
+    pub collaterals: IndexMap<ResourceAddress, YMWeftV2PositionInfo>,
+    pub loans: IndexMap<ResourceAddress, YMWeftV2PositionInfo>,
+    pub nft_collaterals: IndexMap<ResourceAddress, IndexMap<NonFungibleLocalId, YMWeftV2NFTPositionInfo>>,
+    pub nft_value: Decimal,
+    pub triggers: YMWeftV2Triggers,
+    pub cost_basis: Decimal,
+    pub unrealised_pnl: Decimal,
+    pub realised_pnl: Decimal,
+    pub principal: Decimal,
+    pub accrued_interest: Decimal,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct YMWeftV2PositionInfo {
+    pub amount: Decimal,
+    pub price: Decimal,
+    pub value: Decimal,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct YMWeftV2NFTPositionInfo {
+    pub value: Decimal,
+    pub discounted_value: Decimal,
+    pub underlying: IndexMap<ResourceAddress, YMWeftV2PositionInfo>,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct YMWeftV2Triggers {
+    pub stop_loss: Option<Decimal>,
+    pub take_profit: Option<Decimal>,
+}
+
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum YMWeftV2JournalEvent {
+    Open,
+    Execution,
+    Close,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct YMWeftV2JournalEntry {
+    pub event: YMWeftV2JournalEvent,
+    pub timestamp: Instant,
+    pub collateral: Decimal,
+    pub debt_units: Decimal,
+    pub valuation: Option<YMWeftV2JournalValuation>,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct YMWeftV2JournalValuation {
+    pub debt: Decimal,
+    pub collateral_value: Decimal,
+    pub debt_value: Decimal,
+    pub health: Decimal,
 }
@@ -0,0 +1,158 @@
+use crate::helpers::{platform::Platform, prelude::*};
+use scrypto_test::prelude::*;
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct LiquidityInfo {
+    pub resource: ResourceAddress,
+    pub pool_units: ResourceAddress,
+    pub available: Decimal,
+    pub outstanding: Decimal,
+    pub unit_supply: Decimal,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct FlashLoanPool {
+    pub component: ComponentAddress,
+    pub terms: ResourceAddress,
+}
+
+impl FlashLoanPool {
+    /// Deploys the platform's pool.
+    pub fn deploy(runner: &mut Runner, platform: &Platform, fee_rate: Decimal) -> Self {
+        let receipt = Self::try_deploy(runner, platform, fee_rate);
+
+        // Collect output
+        let component = receipt.expect_commit_success().new_component_addresses()[0];
+        let terms = receipt.expect_commit_success().new_resource_addresses()[0];
+
+        Self { component, terms }
+    }
+
+    pub fn try_deploy(runner: &mut Runner, platform: &Platform, fee_rate: Decimal) -> TransactionReceipt {
+        let owner_account = runner.owner_account;
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(owner_account.address, platform.owner_badge, dec!(1))
+            .call_method(platform.component, "deploy_flash_loan_pool", manifest_args!(fee_rate));
+
+        runner.exec_and_dump("deploy_flash_loan_pool", manifest, &owner_account, Some("flash_loans"))
+    }
+
+    /// Adds a resource to the pool, returning the address of its pool units.
+    pub fn add_resource(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        admin_badge: ResourceAddress,
+        resource: ResourceAddress,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, admin_badge, dec!(1))
+            .call_method(self.component, "add_resource", manifest_args!(resource));
+
+        runner.exec_and_dump("add_resource", manifest, account, Some("flash_loans"))
+    }
+
+    pub fn set_fee_rates(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        admin_badge: ResourceAddress,
+        fee_rate: Decimal,
+        linked_fee_rate: Decimal,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, admin_badge, dec!(1))
+            .call_method(self.component, "set_fee_rates", manifest_args!(fee_rate, linked_fee_rate));
+
+        runner.exec_and_dump("set_fee_rates", manifest, account, Some("flash_loans"))
+    }
+
+    pub fn deposit(&self, runner: &mut Runner, account: &SimAccount, resource: ResourceAddress, amount: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account.address, resource, amount)
+            .take_all_from_worktop(resource, "deposit_bucket")
+            .call_method_with_name_lookup(self.component, "deposit", |lookup| (lookup.bucket("deposit_bucket"),))
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("deposit", manifest, account, Some("flash_loans"))
+    }
+
+    pub fn withdraw(&self, runner: &mut Runner, account: &SimAccount, pool_units: ResourceAddress, amount: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account.address, pool_units, amount)
+            .take_all_from_worktop(pool_units, "units_bucket")
+            .call_method_with_name_lookup(self.component, "withdraw", |lookup| (lookup.bucket("units_bucket"),))
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("withdraw", manifest, account, Some("flash_loans"))
+    }
+
+    pub fn get_liquidity(&self, runner: &mut Runner, account: &SimAccount, resource: ResourceAddress) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "get_liquidity", manifest_args!(resource));
+
+        runner.exec_and_dump("get_liquidity", manifest, account, Some("flash_loans"))
+    }
+
+    /// Takes a flash loan, and repays it along with `fee`; minted from the faucet.
+    pub fn flash_loan(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        resource: ResourceAddress,
+        amount: Decimal,
+        fee: Decimal,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "take_flash_loan", manifest_args!(resource, amount))
+            .mint_fungible(resource, fee)
+            .take_all_from_worktop(resource, "repayment_bucket")
+            .take_all_from_worktop(self.terms, "terms_bucket")
+            .call_method_with_name_lookup(self.component, "repay_flash_loan", |lookup| {
+                (lookup.bucket("repayment_bucket"), lookup.bucket("terms_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("flash_loan", manifest, account, Some("flash_loans"))
+    }
+
+    /// Takes a flash loan, and attempts to keep it.
+    pub fn take_flash_loan(&self, runner: &mut Runner, account: &SimAccount, resource: ResourceAddress, amount: Decimal) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "take_flash_loan", manifest_args!(resource, amount))
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("take_flash_loan", manifest, account, Some("flash_loans"))
+    }
+
+    /// Takes a linked flash loan, presenting a proof of `badge` in place of a link badge.
+    pub fn take_linked_flash_loan(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        badge: ResourceAddress,
+        badge_id: NonFungibleLocalId,
+        resource: ResourceAddress,
+        amount: Decimal,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, badge, vec![badge_id])
+            .pop_from_auth_zone("badge_proof")
+            .call_method_with_name_lookup(self.component, "take_linked_flash_loan", |lookup| {
+                (lookup.proof("badge_proof"), resource, amount)
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("take_linked_flash_loan", manifest, account, Some("flash_loans"))
+    }
+}
@@ -0,0 +1,325 @@
+use crate::helpers::prelude::*;
+use scrypto_test::prelude::*;
+
+//] ------------------- Factory ---------------- */
+/// Publishes the `mocks` package, whose blueprints stand in for Weft V2's and Root's markets, a DEX pool and Root's
+/// price oracle.
+#[derive(Debug, Clone, Copy)]
+pub struct MocksFactory {
+    pub package_address: PackageAddress,
+}
+
+impl MocksFactory {
+    pub fn new(ledger: &mut Ledger) -> Self {
+        let path = merge_path("../mocks");
+        let package_address = ledger.compile_and_publish(path);
+
+        Self { package_address }
+    }
+
+    pub fn instantiate_weft_market(&self, runner: &mut Runner, cdp: ResourceAddress) -> MockWeftMarket {
+        let owner_account = runner.owner_account;
+
+        let manifest =
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(self.package_address, "MockWeftMarket", "instantiate", manifest_args!(cdp));
+
+        let receipt = runner.exec_and_dump("instantiate_weft_market", manifest, &owner_account, Some("integrations/mocks"));
+        let component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        MockWeftMarket { component }
+    }
+
+    /// Instantiates a mock Root market, which mints its own CDPs.
+    pub fn instantiate_root_market(&self, runner: &mut Runner) -> MockRootMarket {
+        let owner_account = runner.owner_account;
+
+        let manifest =
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(self.package_address, "MockRootMarket", "instantiate", manifest_args!());
+
+        let receipt = runner.exec_and_dump("instantiate_root_market", manifest, &owner_account, Some("integrations/mocks"));
+
+        // Collect output; the CDP is the only resource created
+        let commit = receipt.expect_commit_success();
+        let component = commit.new_component_addresses()[0];
+        let cdp = commit.new_resource_addresses()[0];
+
+        MockRootMarket { component, cdp, cdp_count: 0 }
+    }
+
+    /// Instantiates a mock DEX pool, minting `amount_a` and `amount_b` as its initial liquidity.
+    pub fn instantiate_dex(&self, runner: &mut Runner, a: ResourceAddress, amount_a: Decimal, b: ResourceAddress, amount_b: Decimal) -> MockDex {
+        let owner_account = runner.owner_account;
+
+        let manifest =
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(self.package_address, "MockDex", "instantiate", manifest_args!(a, b));
+
+        let receipt = runner.exec_and_dump("instantiate_dex", manifest, &owner_account, Some("integrations/mocks"));
+
+        // Collect output; the pool unit is the only resource created
+        let commit = receipt.expect_commit_success();
+        let component = commit.new_component_addresses()[0];
+        let lp = commit.new_resource_addresses()[0];
+
+        let dex = MockDex { component, lp };
+
+        // Provide the initial liquidity
+        runner.faucet.mint(&mut runner.ledger, a, owner_account, amount_a);
+        runner.faucet.mint(&mut runner.ledger, b, owner_account, amount_b);
+
+        let receipt = dex.add_liquidity(runner, &owner_account, a, amount_a, b, amount_b);
+        receipt.expect_commit_success();
+
+        dex
+    }
+
+    pub fn instantiate_oracle(&self, runner: &mut Runner) -> MockOracle {
+        let owner_account = runner.owner_account;
+
+        let manifest =
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_function(self.package_address, "MockOracle", "instantiate", manifest_args!());
+
+        let receipt = runner.exec_and_dump("instantiate_oracle", manifest, &owner_account, Some("integrations/mocks"));
+        let component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        MockOracle { component }
+    }
+}
+
+//] ---------------- Weft Market --------------- */
+#[derive(Debug, Clone, Copy)]
+pub struct MockWeftMarket {
+    pub component: ComponentAddress,
+}
+
+impl MockWeftMarket {
+    pub fn set_price(&self, runner: &mut Runner, resource: ResourceAddress, price: Decimal) {
+        let owner_account = runner.owner_account;
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "set_price", manifest_args!(resource, price));
+
+        let receipt = runner.exec_and_dump("set_price", manifest, &owner_account, Some("integrations/mocks"));
+        receipt.expect_commit_success();
+    }
+
+    /// Prices each of the faucet's assets at its price in the faucet.
+    pub fn set_faucet_prices(&self, runner: &mut Runner) {
+        let faucet = runner.faucet;
+
+        for asset in [faucet.usdt, faucet.usdc, faucet.xwbtc, faucet.hug] {
+            self.set_price(runner, asset.address, asset.price);
+        }
+    }
+
+    /// Deposits `amount` of the resource from the account, as liquidity to be borrowed.
+    pub fn deposit_liquidity(&self, runner: &mut Runner, account: &SimAccount, resource: ResourceAddress, amount: Decimal) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account.address, resource, amount)
+            .take_all_from_worktop(resource, "liquidity_bucket")
+            .call_method_with_name_lookup(self.component, "deposit_liquidity", |lookup| (lookup.bucket("liquidity_bucket"),));
+
+        let receipt = runner.exec_and_dump("deposit_liquidity", manifest, account, Some("integrations/mocks"));
+        receipt.expect_commit_success();
+    }
+
+    /// Bumps the entry version of the market's collateral configs, taken on by positions on their next deposit.
+    pub fn update_collateral_config(&self, runner: &mut Runner) {
+        let owner_account = runner.owner_account;
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "update_collateral_config", manifest_args!());
+
+        let receipt = runner.exec_and_dump("update_collateral_config", manifest, &owner_account, Some("integrations/mocks"));
+        receipt.expect_commit_success();
+    }
+}
+
+//] ---------------- Root Market --------------- */
+#[derive(Debug, Clone, Copy)]
+pub struct MockRootMarket {
+    pub component: ComponentAddress,
+    pub cdp: ResourceAddress,
+    pub cdp_count: u64,
+}
+
+impl MockRootMarket {
+    /// Deposits `amount` of the resource, minted to the owner beforehand, as liquidity to be borrowed.
+    pub fn deposit_liquidity(&self, runner: &mut Runner, resource: ResourceAddress, amount: Decimal) {
+        let owner_account = runner.owner_account;
+        runner.faucet.mint(&mut runner.ledger, resource, owner_account, amount);
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(owner_account.address, resource, amount)
+            .take_all_from_worktop(resource, "liquidity_bucket")
+            .call_method_with_name_lookup(self.component, "deposit_liquidity", |lookup| (lookup.bucket("liquidity_bucket"),));
+
+        let receipt = runner.exec_and_dump("deposit_liquidity", manifest, &owner_account, Some("integrations/mocks"));
+        receipt.expect_commit_success();
+    }
+
+    pub fn set_market_state(&self, runner: &mut Runner, resource: ResourceAddress, state: MockMarketState) {
+        let owner_account = runner.owner_account;
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "set_market_state", manifest_args!(resource, state));
+
+        let receipt = runner.exec_and_dump("set_market_state", manifest, &owner_account, Some("integrations/mocks"));
+        receipt.expect_commit_success();
+    }
+
+    /// Opens a CDP for the account, with `collateral` of the supply asset, minted to the account beforehand, and
+    /// `loan` of the debt asset borrowed against it.
+    ///
+    /// # Returns
+    /// The local ID of the new CDP.
+    pub fn create_cdp(
+        &mut self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        supply: ResourceAddress,
+        collateral: Decimal,
+        debt: ResourceAddress,
+        loan: Decimal,
+    ) -> NonFungibleLocalId {
+        runner.faucet.mint(&mut runner.ledger, supply, *account, collateral);
+
+        let loans = match loan > dec!(0) {
+            true => vec![(debt, loan)],
+            false => vec![],
+        };
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account.address, supply, collateral)
+            .take_all_from_worktop(supply, "collateral_bucket")
+            .call_method_with_name_lookup(self.component, "create_cdp", |lookup| (vec![lookup.bucket("collateral_bucket")], loans))
+            .deposit_entire_worktop(account.address);
+
+        let receipt = runner.exec_and_dump("create_cdp", manifest, account, Some("integrations/mocks"));
+        receipt.expect_commit_success();
+
+        let cdp_id = NonFungibleLocalId::integer(self.cdp_count);
+        self.cdp_count += 1;
+
+        cdp_id
+    }
+}
+
+/// Lending state of a resource on the mock Root market; interest rates are per second.
+#[derive(ScryptoSbor, Debug, Clone, Copy, ManifestSbor)]
+pub struct MockMarketState {
+    pub total_deposit: Decimal,
+    pub total_loan: Decimal,
+    pub supply_rate: Decimal,
+    pub borrow_rate: Decimal,
+}
+
+//] -------------------- DEX ------------------- */
+#[derive(Debug, Clone, Copy)]
+pub struct MockDex {
+    pub component: ComponentAddress,
+    pub lp: ResourceAddress,
+}
+
+impl MockDex {
+    pub fn add_liquidity(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        a: ResourceAddress,
+        amount_a: Decimal,
+        b: ResourceAddress,
+        amount_b: Decimal,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account.address, a, amount_a)
+            .withdraw_from_account(account.address, b, amount_b)
+            .take_all_from_worktop(a, "a_bucket")
+            .take_all_from_worktop(b, "b_bucket")
+            .call_method_with_name_lookup(self.component, "add_liquidity", |lookup| {
+                (lookup.bucket("a_bucket"), lookup.bucket("b_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("add_liquidity", manifest, account, Some("integrations/mocks"))
+    }
+
+    /// Swaps `amount` of the input resource, minted to the account beforehand, for the other resource of the pool.
+    pub fn swap(&self, runner: &mut Runner, account: &SimAccount, input: ResourceAddress, amount: Decimal) -> TransactionReceipt {
+        runner.faucet.mint(&mut runner.ledger, input, *account, amount);
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account.address, input, amount)
+            .take_all_from_worktop(input, "input_bucket")
+            .call_method_with_name_lookup(self.component, "swap", |lookup| (lookup.bucket("input_bucket"),))
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("swap", manifest, account, Some("integrations/mocks"))
+    }
+
+    /// Returns the pool's spot price of `base` in units of `quote`, from the reserves of its liquidity pool.
+    pub fn get_price(&self, runner: &mut Runner, base: ResourceAddress, quote: ResourceAddress) -> Decimal {
+        let owner_account = runner.owner_account;
+
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "liquidity_pool", manifest_args!());
+
+        let receipt = runner.exec_and_dump("liquidity_pool", manifest, &owner_account, Some("integrations/mocks"));
+        let pool: ComponentAddress = receipt.expect_commit_success().output(1);
+
+        runner.ledger.get_component_balance(pool, quote) / runner.ledger.get_component_balance(pool, base)
+    }
+}
+
+//] ------------------ Oracle ------------------ */
+#[derive(Debug, Clone, Copy)]
+pub struct MockOracle {
+    pub component: ComponentAddress,
+}
+
+impl MockOracle {
+    pub fn set_price(&self, runner: &mut Runner, resource: ResourceAddress, price: Decimal, timestamp: Option<i64>) {
+        let owner_account = runner.owner_account;
+
+        let manifest =
+            ManifestBuilder::new()
+                .lock_fee_from_faucet()
+                .call_method(self.component, "set_price", manifest_args!(resource, price, timestamp));
+
+        let receipt = runner.exec_and_dump("set_price", manifest, &owner_account, Some("integrations/mocks"));
+        receipt.expect_commit_success();
+    }
+}
+
+//] ------------------ Prices ------------------ */
+/// A mock Weft market and a Root-style oracle priced alike, for clusters which value their positions on the
+/// market and verify prices against the oracle.
+#[derive(Debug, Clone, Copy)]
+pub struct MockPrices {
+    pub market: MockWeftMarket,
+    pub oracle: MockOracle,
+}
+
+impl MockPrices {
+    /// Sets the price of a resource on both the market and the oracle.
+    pub fn set_price(&self, runner: &mut Runner, resource: ResourceAddress, price: Decimal) {
+        self.market.set_price(runner, resource, price);
+        self.oracle.set_price(runner, resource, price, None);
+    }
+}
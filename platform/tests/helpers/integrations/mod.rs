@@ -1 +1,3 @@
+pub mod mocks;
+pub mod swap_router;
 pub mod weftv2;
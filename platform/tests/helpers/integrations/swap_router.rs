@@ -0,0 +1,115 @@
+use crate::helpers::prelude::*;
+use scrypto_test::prelude::*;
+
+//] ------------------ Manifest ---------------- */
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq, ManifestSbor)]
+pub enum SwapPoolInterface {
+    Ociswap,
+    DefiPlaza,
+    Caviarnine,
+}
+
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq, ManifestSbor)]
+pub struct SwapRouteLeg {
+    pub pool: ComponentAddress,
+    pub interface: SwapPoolInterface,
+    pub input: ResourceAddress,
+    pub output: ResourceAddress,
+}
+
+#[derive(ScryptoSbor, Debug, Clone, PartialEq, Eq)]
+pub struct SwapRoute {
+    pub legs: Vec<SwapRouteLeg>,
+}
+
+//] ------------------- Factory ---------------- */
+#[derive(Debug, Clone, Copy)]
+pub struct SwapRouterFactory {
+    pub package_address: PackageAddress,
+}
+
+impl SwapRouterFactory {
+    pub fn new(ledger: &mut Ledger) -> Self {
+        let path = merge_path("../swap_router");
+        let package_address = ledger.compile_and_publish(path);
+
+        Self { package_address }
+    }
+
+    pub fn instantiate(&self, runner: &mut Runner, owner_rule: AccessRule) -> SwapRouter {
+        let owner_account = runner.owner_account;
+        let admin_rule = rule!(deny_all);
+
+        let manifest = ManifestBuilder::new().lock_fee_from_faucet().call_function(
+            self.package_address,
+            "SwapRouter",
+            "instantiate",
+            manifest_args!(owner_rule, admin_rule),
+        );
+
+        let receipt = runner.exec_and_dump("instantiate", manifest, &owner_account, Some("integrations/swap_router"));
+        let component = receipt.expect_commit_success().new_component_addresses()[0];
+
+        SwapRouter { component }
+    }
+}
+
+//] ------------------- Router ----------------- */
+#[derive(Debug, Clone, Copy)]
+pub struct SwapRouter {
+    pub component: ComponentAddress,
+}
+
+impl SwapRouter {
+    pub fn set_route(&self, runner: &mut Runner, account: &SimAccount, owner_badge: ResourceAddress, legs: Vec<SwapRouteLeg>) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .call_method(self.component, "set_route", manifest_args!(legs));
+
+        runner.exec_and_dump("set_route", manifest, account, Some("integrations/swap_router"))
+    }
+
+    pub fn remove_route(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        owner_badge: ResourceAddress,
+        input: ResourceAddress,
+        output: ResourceAddress,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, owner_badge, dec!(1))
+            .call_method(self.component, "remove_route", manifest_args!(input, output));
+
+        runner.exec_and_dump("remove_route", manifest, account, Some("integrations/swap_router"))
+    }
+
+    pub fn get_route(&self, runner: &mut Runner, account: &SimAccount, input: ResourceAddress, output: ResourceAddress) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "get_route", manifest_args!(input, output));
+
+        runner.exec_and_dump("get_route", manifest, account, Some("integrations/swap_router"))
+    }
+
+    pub fn swap(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        input: ResourceAddress,
+        amount: Decimal,
+        output: ResourceAddress,
+        min_output: Decimal,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .withdraw_from_account(account.address, input, amount)
+            .take_all_from_worktop(input, "input_bucket")
+            .call_method_with_name_lookup(self.component, "swap", |lookup| (lookup.bucket("input_bucket"), output, min_output))
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("swap", manifest, account, Some("integrations/swap_router"))
+    }
+}
@@ -1,5 +1,6 @@
 pub mod clusters;
 pub mod faucet;
+pub mod flash_loans;
 pub mod integrations;
 pub mod platform;
 pub mod prelude;
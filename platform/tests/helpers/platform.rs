@@ -38,7 +38,14 @@ impl PlatformFactory {
         println!("Link Badge: {:?}\n", link_badge);
 
         // Return HelperPlatform
-        Platform { component, owner_account: owner.clone(), owner_badge, user_badge, link_badge }
+        Platform {
+            component,
+            owner_account: owner.clone(),
+            owner_badge,
+            admin_badge,
+            user_badge,
+            link_badge,
+        }
     }
 }
 
@@ -47,11 +54,71 @@ pub struct Platform {
     pub component: ComponentAddress,
     pub owner_account: SimAccount,
     pub owner_badge: ResourceAddress,
+    pub admin_badge: ResourceAddress,
     pub user_badge: ResourceAddress,
     pub link_badge: ResourceAddress,
 }
 
 impl Platform {
+    pub fn get_claimed_entities(&self, runner: &mut Runner, account: &SimAccount) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .call_method(self.component, "get_claimed_entities", manifest_args!());
+
+        runner.exec_and_dump("get_claimed_entities", manifest, account, None)
+    }
+
+    pub fn register_strategy(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        package: PackageAddress,
+        blueprint_name: &str,
+        function_name: &str,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, self.owner_badge, dec!(1))
+            .call_method(
+                self.component,
+                "register_strategy",
+                manifest_args!(package, blueprint_name, function_name),
+            );
+
+        runner.exec_and_dump("register_strategy", manifest, account, None)
+    }
+
+    pub fn deregister_strategy(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        package: PackageAddress,
+        blueprint_name: &str,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, self.owner_badge, dec!(1))
+            .call_method(self.component, "deregister_strategy", manifest_args!(package, blueprint_name));
+
+        runner.exec_and_dump("deregister_strategy", manifest, account, None)
+    }
+
+    pub fn deploy_cluster<P: ManifestEncode>(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        package: PackageAddress,
+        blueprint_name: &str,
+        params: P,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, self.owner_badge, dec!(1))
+            .call_method(self.component, "deploy_cluster", manifest_args!(package, blueprint_name, params));
+
+        runner.exec_and_dump("deploy_cluster", manifest, account, None)
+    }
+
     pub fn link(&self, runner: &mut Runner, account: &SimAccount, cluster: ComponentAddress) {
         let manifest = ManifestBuilder::new()
             .lock_fee_from_faucet()
@@ -72,6 +139,36 @@ impl Platform {
         receipt.expect_commit_success();
     }
 
+    pub fn pause_all(&self, runner: &mut Runner, account: &SimAccount) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, self.owner_badge, dec!(1))
+            .call_method(self.component, "pause_all", manifest_args!());
+
+        let receipt = runner.exec_and_dump("pause_all", manifest, &account, None);
+        receipt.expect_commit_success();
+    }
+
+    pub fn unpause_all(&self, runner: &mut Runner, account: &SimAccount) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, self.owner_badge, dec!(1))
+            .call_method(self.component, "unpause_all", manifest_args!());
+
+        let receipt = runner.exec_and_dump("unpause_all", manifest, &account, None);
+        receipt.expect_commit_success();
+    }
+
+    /// Returns the data of the user's badge, listing the clusters the user has an account in.
+    pub fn get_user(&self, runner: &mut Runner, user_id: NonFungibleLocalId) -> PlatformUser {
+        runner.ledger.get_non_fungible_data(self.user_badge, user_id)
+    }
+
+    /// Returns the data of a link badge, including whether it has been flagged as paused.
+    pub fn get_link(&self, runner: &mut Runner, link_id: NonFungibleLocalId) -> PlatformLink {
+        runner.ledger.get_non_fungible_data(self.link_badge, link_id)
+    }
+
     pub fn new_user(&self, runner: &mut Runner, account: &SimAccount) {
         let manifest = ManifestBuilder::new()
             .lock_fee_from_faucet()
@@ -81,6 +178,58 @@ impl Platform {
         let receipt = runner.exec("new_user", manifest, &account, None);
         receipt.expect_commit_success();
     }
+
+    pub fn new_admin_badge(&self, runner: &mut Runner, account: &SimAccount) {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_amount(account.address, self.owner_badge, dec!(1))
+            .call_method(self.component, "new_admin_badge", manifest_args!())
+            .deposit_batch(account.address, ManifestExpression::EntireWorktop);
+
+        let receipt = runner.exec("new_admin_badge", manifest, account, None);
+        receipt.expect_commit_success();
+    }
+
+    pub fn refinance(
+        &self,
+        runner: &mut Runner,
+        account: &SimAccount,
+        user_id: NonFungibleLocalId,
+        from_cluster: ComponentAddress,
+        to_cluster: ComponentAddress,
+        cdp: ResourceAddress,
+        cdp_id: NonFungibleLocalId,
+    ) -> TransactionReceipt {
+        let manifest = ManifestBuilder::new()
+            .lock_fee_from_faucet()
+            .create_proof_from_account_of_non_fungibles(account.address, self.user_badge, vec![user_id])
+            .pop_from_auth_zone("user_badge")
+            .withdraw_non_fungibles_from_account(account.address, cdp, vec![cdp_id.clone()])
+            .take_non_fungibles_from_worktop(cdp, vec![cdp_id], "cdp_bucket")
+            .call_method_with_name_lookup(self.component, "refinance", |lookup| {
+                (lookup.proof("user_badge"), from_cluster, to_cluster, lookup.bucket("cdp_bucket"))
+            })
+            .deposit_entire_worktop(account.address);
+
+        runner.exec_and_dump("refinance", manifest, account, None)
+    }
+}
+
+#[derive(ScryptoSbor, NonFungibleData, Debug, Clone)]
+pub struct PlatformUser {
+    pub minted_at: Instant,
+    #[mutable]
+    pub accounts_in: IndexSet<ComponentAddress>,
+    #[mutable]
+    pub open: u64,
+}
+
+#[derive(ScryptoSbor, NonFungibleData, Debug, Clone)]
+pub struct PlatformLink {
+    pub issuing_platform: ComponentAddress,
+    pub linked_cluster: ComponentAddress,
+    #[mutable]
+    pub paused: bool,
 }
 
 #[derive(ScryptoSbor, Debug, Clone, Copy, ManifestSbor)]
@@ -91,6 +240,8 @@ pub enum PlatformService {
     // AuthoriseExecution,
     LinkCluster,
     UnlinkCluster,
+    Refinance,
+    DeployCluster,
 }
 
 #[derive(ScryptoSbor, Debug, Clone, Copy, ManifestSbor)]
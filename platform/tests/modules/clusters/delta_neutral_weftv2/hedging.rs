@@ -0,0 +1,292 @@
+use crate::helpers::prelude::*;
+use crate::helpers::{
+    clusters::delta_neutral_weftv2::{DNWeftV2AccountInfo, DNWeftV2Cluster, DNWeftV2ClusterFactory, DNWeftV2OracleSource},
+    integrations::{
+        mocks::{MockDex, MockPrices, MocksFactory},
+        weftv2::MockWeftV2,
+    },
+    platform::Platform,
+};
+use scrypto_test::prelude::*;
+
+/// Instantiates and links a cluster over a mock Weft market, oracle and DEX pool priced at the faucet's prices, and opens
+/// an account for Alice with an empty CDP and funds her with 10,000 of the stable asset. The delta band is set to 2%.
+fn arrange() -> (Runner, Platform, DNWeftV2Cluster, MockPrices, MockDex) {
+    // Create a test runner and platform
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    let stable = runner.faucet.usdt.address;
+    let volatile = runner.faucet.xwbtc.address;
+    let price = runner.faucet.xwbtc.price;
+
+    // Instantiate the mock integrations, with 1,000,000 of the stable asset in the pool
+    let mut weftv2 = MockWeftV2::new(&mut runner);
+    let mocks_factory = MocksFactory::new(&mut runner.ledger);
+
+    let market = mocks_factory.instantiate_weft_market(&mut runner, weftv2.cdp);
+    let oracle = mocks_factory.instantiate_oracle(&mut runner);
+    let prices = MockPrices { market, oracle };
+    prices.set_price(&mut runner, stable, dec!(1));
+    prices.set_price(&mut runner, volatile, price);
+    runner.faucet.mint(&mut runner.ledger, volatile, owner_account, dec!(10));
+    market.deposit_liquidity(&mut runner, &owner_account, volatile, dec!(10));
+
+    let dex = mocks_factory.instantiate_dex(&mut runner, stable, dec!(1_000_000), volatile, dec!(1_000_000) / price);
+
+    // Instantiate a DeltaNeutralWeftV2Cluster, priced from the mock oracle
+    let dn_weftv2_cluster_factory = DNWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let cluster = dn_weftv2_cluster_factory.instantiate(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.user_badge,
+        stable,
+        volatile,
+        market.component,
+        weftv2.cdp,
+        dex.component,
+        dex.lp,
+    );
+
+    let sources = vec![DNWeftV2OracleSource::Root(oracle.component)];
+    let receipt = cluster.set_oracle(&mut runner, &owner_account, platform.owner_badge, sources, 60, dec!(0.02));
+    receipt.expect_commit_success();
+
+    let receipt = cluster.set_hedge_config(&mut runner, &owner_account, platform.owner_badge, dec!(0.5), dec!(0.02), dec!(0.02));
+    receipt.expect_commit_success();
+
+    // Link cluster to platform
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    // Get a user badge, open an account and fund it
+    platform.new_user(&mut runner, &alice_account);
+
+    let cdp_id = weftv2.mint_empty(&mut runner, alice_account);
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+
+    runner.faucet.mint(&mut runner.ledger, stable, alice_account, dec!(10_000));
+
+    (runner, platform, cluster, prices, dex)
+}
+
+/// Swaps `amount` of `input` through the pool as Bob, and moves the market's and oracle's price of the volatile asset
+/// to the pool's new spot price.
+fn move_price(runner: &mut Runner, cluster: &DNWeftV2Cluster, prices: &MockPrices, dex: &MockDex, input: ResourceAddress, amount: Decimal) {
+    let bob_account = runner.bob_account;
+
+    let receipt = dex.swap(runner, &bob_account, input, amount);
+    receipt.expect_commit_success();
+
+    let price = dex.get_price(runner, cluster.volatile, cluster.stable);
+    prices.set_price(runner, cluster.volatile, price);
+}
+
+/// Asserts that the account's net exposure is within the 2% delta band.
+fn assert_within_band(info: &DNWeftV2AccountInfo) {
+    assert!(
+        info.delta_value.checked_abs().unwrap() <= info.equity * dec!(0.02),
+        "Delta {} outside the band of equity {}",
+        info.delta_value,
+        info.equity
+    );
+}
+
+#[test]
+fn test_valid_enter() {
+    //] Arrange
+    let (mut runner, _platform, cluster, _prices, _dex) = arrange();
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    //] Act
+    let receipt = cluster.enter(&mut runner, &alice_account, user_id.clone(), dec!(10_000));
+    receipt.expect_commit_success();
+
+    let info = cluster.get_account_info(&mut runner, &alice_account, user_id);
+
+    //] Assert
+    assert!(info.lp_amount > dec!(0));
+    assert!(info.debt > dec!(0));
+    assert!(info.equity >= dec!(9_900));
+    assert_within_band(&info);
+}
+
+#[test]
+fn test_valid_exit() {
+    //] Arrange
+    let (mut runner, _platform, cluster, _prices, _dex) = arrange();
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let receipt = cluster.enter(&mut runner, &alice_account, user_id.clone(), dec!(10_000));
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = cluster.exit(&mut runner, &alice_account, user_id.clone());
+    receipt.expect_commit_success();
+
+    let info = cluster.get_account_info(&mut runner, &alice_account, user_id);
+
+    //] Assert
+    assert_eq!(info.lp_amount, dec!(0));
+    assert_eq!(info.debt, dec!(0));
+    assert_eq!(info.collateral, dec!(0));
+    assert!(runner.ledger.get_component_balance(alice_account.address, cluster.stable) >= dec!(9_900));
+}
+
+#[test]
+fn test_valid_rebalance_long() {
+    //] Arrange
+    let (mut runner, _platform, cluster, prices, dex) = arrange();
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let receipt = cluster.enter(&mut runner, &alice_account, user_id.clone(), dec!(10_000));
+    receipt.expect_commit_success();
+
+    // Sell the volatile asset into the pool until its price falls by ~25%, leaving the LP long
+    let amount = dec!(1_000_000) / runner.faucet.xwbtc.price * dec!(0.1547);
+    move_price(&mut runner, &cluster, &prices, &dex, cluster.volatile, amount);
+
+    let before = cluster.get_account_info(&mut runner, &alice_account, user_id.clone());
+
+    //] Act
+    let receipt = cluster.rebalance(&mut runner, &alice_account, user_id.clone());
+    receipt.expect_commit_success();
+
+    let after = cluster.get_account_info(&mut runner, &alice_account, user_id);
+
+    //] Assert
+    assert!(before.delta > dec!(0));
+    assert!(before.delta_value > before.equity * dec!(0.02));
+    assert!(after.debt > before.debt);
+    assert!(after.collateral > before.collateral);
+    assert_within_band(&after);
+}
+
+#[test]
+fn test_valid_rebalance_short() {
+    //] Arrange
+    let (mut runner, _platform, cluster, prices, dex) = arrange();
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let receipt = cluster.enter(&mut runner, &alice_account, user_id.clone(), dec!(10_000));
+    receipt.expect_commit_success();
+
+    // Buy the volatile asset from the pool until its price rises by ~25%, leaving the LP short
+    move_price(&mut runner, &cluster, &prices, &dex, cluster.stable, dec!(118_000));
+
+    let before = cluster.get_account_info(&mut runner, &alice_account, user_id.clone());
+
+    //] Act
+    let receipt = cluster.rebalance(&mut runner, &alice_account, user_id.clone());
+    receipt.expect_commit_success();
+
+    let after = cluster.get_account_info(&mut runner, &alice_account, user_id);
+
+    //] Assert
+    assert!(before.delta < dec!(0));
+    assert!(-before.delta_value > before.equity * dec!(0.02));
+    assert!(after.debt < before.debt);
+    assert!(after.collateral < before.collateral);
+    assert_within_band(&after);
+}
+
+#[test]
+fn test_invalid_rebalance_within_band() {
+    //] Arrange
+    let (mut runner, _platform, cluster, _prices, _dex) = arrange();
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let receipt = cluster.enter(&mut runner, &alice_account, user_id.clone(), dec!(10_000));
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = cluster.rebalance(&mut runner, &alice_account, user_id);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_valid_rebalance_account_by_owner() {
+    //] Arrange
+    let (mut runner, platform, cluster, prices, dex) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let receipt = cluster.enter(&mut runner, &alice_account, user_id.clone(), dec!(10_000));
+    receipt.expect_commit_success();
+
+    let amount = dec!(1_000_000) / runner.faucet.xwbtc.price * dec!(0.1547);
+    move_price(&mut runner, &cluster, &prices, &dex, cluster.volatile, amount);
+
+    //] Act
+    let receipt = cluster.rebalance_account(&mut runner, &owner_account, Some(platform.owner_badge), user_id.clone());
+    receipt.expect_commit_success();
+
+    let info = cluster.get_account_info(&mut runner, &alice_account, user_id);
+
+    //] Assert
+    assert_within_band(&info);
+}
+
+#[test]
+fn test_valid_rebalance_account_by_keeper() {
+    //] Arrange
+    let (mut runner, platform, cluster, prices, dex) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let receipt = cluster.enter(&mut runner, &alice_account, user_id.clone(), dec!(10_000));
+    receipt.expect_commit_success();
+
+    // Grant the keeper role to Bob, through a badge of his own
+    let keeper_badge = runner.faucet.hug.address;
+    runner.faucet.mint(&mut runner.ledger, keeper_badge, bob_account, dec!(1));
+
+    let receipt = cluster.set_keeper(&mut runner, &owner_account, platform.owner_badge, keeper_badge);
+    receipt.expect_commit_success();
+
+    let amount = dec!(1_000_000) / runner.faucet.xwbtc.price * dec!(0.1547);
+    move_price(&mut runner, &cluster, &prices, &dex, cluster.volatile, amount);
+
+    //] Act
+    let receipt = cluster.rebalance_account(&mut runner, &bob_account, Some(keeper_badge), user_id.clone());
+    receipt.expect_commit_success();
+
+    let info = cluster.get_account_info(&mut runner, &alice_account, user_id);
+
+    //] Assert
+    assert_within_band(&info);
+}
+
+#[test]
+fn test_invalid_rebalance_account_without_keeper_role() {
+    //] Arrange
+    let (mut runner, _platform, cluster, prices, dex) = arrange();
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let receipt = cluster.enter(&mut runner, &alice_account, user_id.clone(), dec!(10_000));
+    receipt.expect_commit_success();
+
+    let amount = dec!(1_000_000) / runner.faucet.xwbtc.price * dec!(0.1547);
+    move_price(&mut runner, &cluster, &prices, &dex, cluster.volatile, amount);
+
+    //] Act
+    let receipt = cluster.rebalance_account(&mut runner, &bob_account, None, user_id);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
@@ -0,0 +1,217 @@
+use crate::helpers::prelude::*;
+use crate::helpers::{
+    clusters::lsu_looping_weftv2::{LLWeftV2Cluster, LLWeftV2ClusterFactory},
+    integrations::{mocks::MocksFactory, weftv2::MockWeftV2},
+    platform::Platform,
+};
+use scrypto_test::prelude::*;
+
+/// Instantiates and links a cluster over a new validator and a mock Weft market pricing its LSU at par with XRD, and
+/// opens an account for Alice with an empty CDP and stakes 2,000 XRD for her LSU.
+fn arrange() -> (Runner, Platform, LLWeftV2Cluster) {
+    // Create a test runner and platform
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    // Create a validator accepting delegated stake, owned by the owner account
+    let validator = runner.ledger.new_validator_with_pub_key(owner_account.public_key, owner_account.address);
+    let lsu = runner.ledger.get_validator_info(validator).stake_unit_resource;
+    let validator_owner_id = NonFungibleLocalId::bytes(validator.as_node_id().0).unwrap();
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(owner_account.address, VALIDATOR_OWNER_BADGE, vec![validator_owner_id])
+        .call_method(validator, "update_accept_delegated_stake", manifest_args!(true));
+
+    let receipt = runner.exec_and_dump("accept_delegated_stake", manifest, &owner_account, Some("clusters/lsu_looping_weftv2"));
+    receipt.expect_commit_success();
+
+    // Instantiate the mock market, with XRD to be borrowed
+    let mut weftv2 = MockWeftV2::new(&mut runner);
+    let mocks_factory = MocksFactory::new(&mut runner.ledger);
+
+    let market = mocks_factory.instantiate_weft_market(&mut runner, weftv2.cdp);
+    market.set_price(&mut runner, XRD, dec!(1));
+    market.set_price(&mut runner, lsu, dec!(1));
+    market.deposit_liquidity(&mut runner, &owner_account, XRD, dec!(5_000));
+
+    // Instantiate a LsuLoopingWeftV2Cluster
+    let ll_weftv2_cluster_factory = LLWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let cluster = ll_weftv2_cluster_factory.instantiate(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.user_badge,
+        validator,
+        lsu,
+        market.component,
+        weftv2.cdp,
+    );
+
+    // Link cluster to platform
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    // Get a user badge and open an account
+    platform.new_user(&mut runner, &alice_account);
+
+    let cdp_id = weftv2.mint_empty(&mut runner, alice_account);
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+
+    // Stake XRD for Alice's LSU, minted at par by the new validator
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(alice_account.address, XRD, dec!(2_000))
+        .take_all_from_worktop(XRD, "xrd_bucket")
+        .call_method_with_name_lookup(validator, "stake", |lookup| (lookup.bucket("xrd_bucket"),))
+        .deposit_entire_worktop(alice_account.address);
+
+    let receipt = runner.exec_and_dump("stake", manifest, &alice_account, Some("clusters/lsu_looping_weftv2"));
+    receipt.expect_commit_success();
+
+    (runner, platform, cluster)
+}
+
+#[test]
+fn test_valid_enter() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange();
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    //] Act
+    let receipt = cluster.enter(&mut runner, &alice_account, user_id.clone(), dec!(1_000), dec!(2));
+    receipt.expect_commit_success();
+
+    let info = cluster.get_account_info(&mut runner, &alice_account, user_id);
+    let position = info.position.expect("No position recorded");
+
+    //] Assert
+    assert_eq!(info.collateral, dec!(2_000));
+    assert_eq!(info.debt, dec!(1_000));
+    assert_eq!(info.leverage, dec!(2));
+    assert_eq!(position.deposited, dec!(1_000));
+    assert_eq!(position.principal, dec!(1_000));
+    assert_eq!(position.entry_rate, dec!(1));
+}
+
+#[test]
+fn test_invalid_enter_above_max_leverage() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = cluster.enter(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), dec!(1_000), dec!(4));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_valid_exit_in_full() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange();
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let receipt = cluster.enter(&mut runner, &alice_account, user_id.clone(), dec!(1_000), dec!(2));
+    receipt.expect_commit_success();
+
+    let lsu_before = runner.ledger.get_component_balance(alice_account.address, cluster.lsu);
+
+    //] Act
+    let receipt = cluster.exit(&mut runner, &alice_account, user_id.clone(), dec!(1_000));
+    receipt.expect_commit_success();
+
+    let info = cluster.get_account_info(&mut runner, &alice_account, user_id);
+
+    //] Assert
+    assert_eq!(info.collateral, dec!(0));
+    assert_eq!(info.debt, dec!(0));
+    assert!(info.position.is_none());
+    assert_eq!(
+        runner.ledger.get_component_balance(alice_account.address, cluster.lsu),
+        lsu_before + dec!(2_000)
+    );
+}
+
+#[test]
+fn test_valid_exit_in_part() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange();
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let receipt = cluster.enter(&mut runner, &alice_account, user_id.clone(), dec!(1_000), dec!(2));
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = cluster.exit(&mut runner, &alice_account, user_id.clone(), dec!(500));
+    receipt.expect_commit_success();
+
+    let info = cluster.get_account_info(&mut runner, &alice_account, user_id);
+    let position = info.position.expect("No position recorded");
+
+    //] Assert
+    assert_eq!(info.collateral, dec!(1_000));
+    assert_eq!(info.debt, dec!(500));
+    assert_eq!(position.deposited, dec!(500));
+    assert_eq!(position.principal, dec!(500));
+}
+
+#[test]
+fn test_valid_yield_report() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange();
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let receipt = cluster.enter(&mut runner, &alice_account, user_id.clone(), dec!(1_000), dec!(2));
+    receipt.expect_commit_success();
+
+    // Let a day pass; without emissions or interest, neither the LSU nor the debt grow
+    let now = runner.ledger.get_current_time(TimePrecisionV2::Second).seconds_since_unix_epoch;
+    runner.ledger.advance_to_round_at_timestamp(Round::of(1), (now + 86_400) * 1_000);
+
+    //] Act
+    let info = cluster.get_account_info(&mut runner, &alice_account, user_id);
+
+    //] Assert
+    assert_eq!(info.staking_yield, dec!(0));
+    assert_eq!(info.borrow_cost, dec!(0));
+    assert_eq!(info.net_yield, dec!(0));
+    assert_eq!(info.leverage, dec!(2));
+}
+
+#[test]
+fn test_valid_repeat_enter_averages_entry() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange();
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let receipt = cluster.enter(&mut runner, &alice_account, user_id.clone(), dec!(1_000), dec!(2));
+    receipt.expect_commit_success();
+
+    let first = cluster.get_account_info(&mut runner, &alice_account, user_id.clone()).position.unwrap();
+
+    // Let 100 days pass before entering again
+    let now = runner.ledger.get_current_time(TimePrecisionV2::Second).seconds_since_unix_epoch;
+    runner.ledger.advance_to_round_at_timestamp(Round::of(1), (now + 100 * 86_400) * 1_000);
+
+    //] Act
+    let receipt = cluster.enter(&mut runner, &alice_account, user_id.clone(), dec!(1_000), dec!(2));
+    receipt.expect_commit_success();
+
+    let second = cluster.get_account_info(&mut runner, &alice_account, user_id).position.unwrap();
+
+    //] Assert
+    // Equal deposits place the entry halfway between the two
+    assert_eq!(second.entered_at, first.entered_at + dec!(50) * dec!(86_400));
+    assert_eq!(second.entry_rate, dec!(1));
+    assert_eq!(second.deposited, dec!(2_000));
+    assert_eq!(second.principal, dec!(2_000));
+}
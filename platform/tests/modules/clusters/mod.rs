@@ -1 +1,5 @@
+pub mod delta_neutral_weftv2;
+pub mod lsu_looping_weftv2;
+pub mod yield_multiplier_root;
+pub mod yield_multiplier_vault_weftv2;
 pub mod yield_multiplier_weftv2;
@@ -0,0 +1,131 @@
+use crate::helpers::prelude::*;
+use crate::helpers::{
+    clusters::yield_multiplier_root::{YMRootCluster, YMRootClusterFactory, YMRootMarketRates},
+    integrations::mocks::{MockMarketState, MockRootMarket},
+    platform::Platform,
+};
+use scrypto_test::prelude::*;
+
+/// Instantiates a cluster over USDT and xwBTC on a mock Root market, and links it to the platform.
+fn arrange() -> (Runner, Platform, YMRootCluster, MockRootMarket) {
+    // Create a test runner and platform
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+
+    // Instantiate a YieldMultiplierRootCluster
+    let ym_root_cluster_factory = YMRootClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let supply = runner.faucet.usdt.address;
+    let debt = runner.faucet.xwbtc.address;
+
+    let (cluster, root_market) = ym_root_cluster_factory.instantiate(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        supply,
+        debt,
+    );
+
+    // Link cluster to platform
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    (runner, platform, cluster, root_market)
+}
+
+/* ------------------ Account ----------------- */
+#[test]
+fn test_valid_get_account_info() {
+    //] Arrange
+    let (mut runner, platform, cluster, mut root_market) = arrange();
+    let alice_account = runner.alice_account;
+
+    // Lend out xwBTC on the market
+    root_market.deposit_liquidity(&mut runner, cluster.debt, dec!(1));
+
+    // Get a user badge, and open an account with a CDP supplying 1000 USDT and borrowing 0.005 xwBTC
+    platform.new_user(&mut runner, &alice_account);
+
+    let cdp_id = root_market.create_cdp(&mut runner, &alice_account, cluster.supply, dec!(1000), cluster.debt, dec!(0.005));
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id.clone());
+
+    //] Act
+    let info = cluster.get_account_info(&mut runner, NonFungibleLocalId::Integer(0.into()));
+
+    //] Assert
+    assert_eq!(info.cdp_id, cdp_id);
+    assert_eq!(info.supply_units, dec!(1000));
+    assert_eq!(info.debt_units, dec!(0.005));
+}
+
+/* ------------------ Rates ------------------ */
+#[test]
+fn test_valid_get_market_rates() {
+    //] Arrange
+    let (mut runner, _platform, cluster, root_market) = arrange();
+
+    // Set the lending state of both assets on the market, at rates per second
+    #[rustfmt::skip]
+    root_market.set_market_state(&mut runner, cluster.supply, MockMarketState {
+        total_deposit: dec!(1000), total_loan: dec!(250), supply_rate: dec!(0.000000002), borrow_rate: dec!(0.000000004),
+    });
+    #[rustfmt::skip]
+    root_market.set_market_state(&mut runner, cluster.debt, MockMarketState {
+        total_deposit: dec!(2), total_loan: dec!(1), supply_rate: dec!(0.0000000005), borrow_rate: dec!(0.000000001),
+    });
+
+    //] Act
+    // Get market rates at 2x leverage
+    let receipt = cluster.get_market_rates(&mut runner, dec!(2));
+
+    //] Assert
+    let rates: YMRootMarketRates = receipt.expect_commit_success().output(1);
+
+    // The supply asset is the collateral, earning its supply rate; the debt asset is the loan, paying its borrow rate
+    assert_eq!(rates.supply.resource, cluster.supply);
+    assert_eq!(rates.supply.supply_apr, dec!(0.063072));
+    assert_eq!(rates.supply.utilisation, dec!(0.25));
+    assert_eq!(rates.debt.resource, cluster.debt);
+    assert_eq!(rates.debt.borrow_apr, dec!(0.031536));
+    assert_eq!(rates.debt.utilisation, dec!(0.5));
+
+    // 2 units of collateral earn 0.063072 each, while 1 unit of loan costs 0.031536
+    assert_eq!(rates.leverage, dec!(2));
+    assert_eq!(rates.net_apr, dec!(0.094608));
+    assert!(rates.net_apy > rates.net_apr, "Daily compounding should raise a positive APR");
+}
+
+#[test]
+fn test_invalid_get_market_rates_without_market_state() {
+    //] Arrange
+    let (mut runner, _platform, cluster, _root_market) = arrange();
+
+    //] Act
+    let receipt = cluster.get_market_rates(&mut runner, dec!(2));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_get_market_rates_below_unit_leverage() {
+    //] Arrange
+    let (mut runner, _platform, cluster, root_market) = arrange();
+
+    #[rustfmt::skip]
+    root_market.set_market_state(&mut runner, cluster.supply, MockMarketState {
+        total_deposit: dec!(1000), total_loan: dec!(250), supply_rate: dec!(0.000000002), borrow_rate: dec!(0.000000004),
+    });
+    #[rustfmt::skip]
+    root_market.set_market_state(&mut runner, cluster.debt, MockMarketState {
+        total_deposit: dec!(2), total_loan: dec!(1), supply_rate: dec!(0.0000000005), borrow_rate: dec!(0.000000001),
+    });
+
+    //] Act
+    let receipt = cluster.get_market_rates(&mut runner, dec!(0.5));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
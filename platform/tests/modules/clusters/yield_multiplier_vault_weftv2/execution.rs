@@ -0,0 +1,35 @@
+use super::shares::arrange;
+use scrypto_test::prelude::*;
+
+#[test]
+fn test_valid_execution() {
+    //] Arrange
+    let (mut runner, platform, vault, _) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    let receipt = vault.deposit(&mut runner, &alice_account, dec!(100));
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = vault.execute(&mut runner, &owner_account, platform.owner_badge, dec!(100), None);
+
+    //] Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_invalid_execution_returning_other_cdp() {
+    //] Arrange
+    let (mut runner, platform, vault, mut weftv2) = arrange();
+    let owner_account = runner.owner_account;
+
+    // Another empty CDP, valid on its own contents
+    let cdp_id = weftv2.mint_empty(&mut runner, owner_account);
+
+    //] Act
+    let receipt = vault.execute(&mut runner, &owner_account, platform.owner_badge, dec!(0), Some(cdp_id));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
@@ -0,0 +1,3 @@
+pub mod execution;
+pub mod queue;
+pub mod shares;
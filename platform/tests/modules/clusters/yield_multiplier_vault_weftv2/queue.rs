@@ -0,0 +1,133 @@
+use super::shares::arrange;
+use scrypto_test::prelude::*;
+
+#[test]
+fn test_valid_deposit_queued_while_deleveraging() {
+    //] Arrange
+    let (mut runner, platform, vault, _) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    let receipt = vault.set_deleveraging(&mut runner, &owner_account, platform.owner_badge, true);
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = vault.deposit(&mut runner, &alice_account, dec!(100));
+    receipt.expect_commit_success();
+
+    // Deposits are held while deleveraging
+    let receipt = vault.process_queue(&mut runner, &alice_account, 10);
+    let held: u64 = receipt.expect_commit_success().output(1);
+
+    let receipt = vault.set_deleveraging(&mut runner, &owner_account, platform.owner_badge, false);
+    receipt.expect_commit_success();
+
+    let receipt = vault.process_queue(&mut runner, &alice_account, 10);
+    let processed: u64 = receipt.expect_commit_success().output(1);
+
+    let receipt = vault.claim(&mut runner, &alice_account, NonFungibleLocalId::integer(0));
+    receipt.expect_commit_success();
+
+    //] Assert
+    assert_eq!(held, 0);
+    assert_eq!(processed, 1);
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, vault.shares), dec!(100));
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, vault.tickets), dec!(0));
+}
+
+#[test]
+fn test_valid_withdrawal_queued_while_deleveraging() {
+    //] Arrange
+    let (mut runner, platform, vault, _) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    let receipt = vault.deposit(&mut runner, &alice_account, dec!(100));
+    receipt.expect_commit_success();
+
+    let receipt = vault.set_deleveraging(&mut runner, &owner_account, platform.owner_badge, true);
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = vault.withdraw(&mut runner, &alice_account, dec!(100));
+    receipt.expect_commit_success();
+
+    let receipt = vault.process_queue(&mut runner, &alice_account, 10);
+    receipt.expect_commit_success();
+
+    let receipt = vault.claim(&mut runner, &alice_account, NonFungibleLocalId::integer(0));
+    receipt.expect_commit_success();
+
+    //] Assert
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, vault.shares), dec!(0));
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, vault.supply), dec!(1000));
+}
+
+#[test]
+fn test_valid_withdrawal_processed_past_deposit_held_while_deleveraging() {
+    //] Arrange
+    let (mut runner, platform, vault, _) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+
+    let receipt = vault.deposit(&mut runner, &alice_account, dec!(100));
+    receipt.expect_commit_success();
+
+    let receipt = vault.set_deleveraging(&mut runner, &owner_account, platform.owner_badge, true);
+    receipt.expect_commit_success();
+
+    // Bob's deposit is queued ahead of Alice's withdrawal
+    let receipt = vault.deposit(&mut runner, &bob_account, dec!(50));
+    receipt.expect_commit_success();
+
+    let receipt = vault.withdraw(&mut runner, &alice_account, dec!(100));
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = vault.process_queue(&mut runner, &alice_account, 10);
+    let processed: u64 = receipt.expect_commit_success().output(1);
+
+    let receipt = vault.claim(&mut runner, &alice_account, NonFungibleLocalId::integer(1));
+    receipt.expect_commit_success();
+
+    let bob_claim = vault.claim(&mut runner, &bob_account, NonFungibleLocalId::integer(0));
+
+    //] Assert
+    assert_eq!(processed, 1);
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, vault.supply), dec!(1000));
+    bob_claim.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_claim_before_processed() {
+    //] Arrange
+    let (mut runner, platform, vault, _) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    let receipt = vault.set_deleveraging(&mut runner, &owner_account, platform.owner_badge, true);
+    receipt.expect_commit_success();
+
+    let receipt = vault.deposit(&mut runner, &alice_account, dec!(100));
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = vault.claim(&mut runner, &alice_account, NonFungibleLocalId::integer(0));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_unauthorised_set_deleveraging() {
+    //] Arrange
+    let (mut runner, platform, vault, _) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = vault.set_deleveraging(&mut runner, &alice_account, platform.owner_badge, true);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
@@ -0,0 +1,147 @@
+use crate::helpers::prelude::*;
+use crate::helpers::{
+    clusters::yield_multiplier_vault_weftv2::{YMWeftV2Vault, YMWeftV2VaultFactory},
+    integrations::weftv2::MockWeftV2,
+    platform::Platform,
+};
+use scrypto_test::prelude::*;
+
+/// Instantiates and links a vault with an empty CDP, and funds Alice and Bob with the supply asset.
+pub fn arrange() -> (Runner, Platform, YMWeftV2Vault, MockWeftV2) {
+    arrange_with_debt(None)
+}
+
+/// Instantiates and links a vault with a CDP holding only the given debt, and funds Alice and Bob with the supply asset.
+fn arrange_with_debt(debt_amount: Option<Decimal>) -> (Runner, Platform, YMWeftV2Vault, MockWeftV2) {
+    // Create a test runner and platform
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+
+    // Instantiate a YieldMultiplierWeftV2Vault
+    let supply = runner.faucet.usdt.address;
+    let debt = runner.faucet.xwbtc.address;
+
+    let mut weftv2 = MockWeftV2::new(&mut runner);
+    let loans = debt_amount.map(|amount| indexmap! { debt => amount });
+    let cdp_id = weftv2.mint(&mut runner, owner_account, None, loans, false);
+    let ym_weftv2_vault_factory = YMWeftV2VaultFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+
+    let vault = ym_weftv2_vault_factory.instantiate(&mut runner, owner_rule, platform.component, supply, debt, weftv2.cdp, cdp_id);
+
+    // Link vault to platform
+    platform.link(&mut runner, &owner_account, vault.component);
+
+    // Fund the users
+    runner.faucet.mint(&mut runner.ledger, supply, alice_account, dec!(1000));
+    runner.faucet.mint(&mut runner.ledger, supply, bob_account, dec!(1000));
+
+    (runner, platform, vault, weftv2)
+}
+
+#[test]
+fn test_valid_deposit_and_withdraw() {
+    //] Arrange
+    let (mut runner, _platform, vault, _) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = vault.deposit(&mut runner, &alice_account, dec!(100));
+    receipt.expect_commit_success();
+
+    let shares = runner.ledger.get_component_balance(alice_account.address, vault.shares);
+
+    let receipt = vault.withdraw(&mut runner, &alice_account, dec!(40));
+    receipt.expect_commit_success();
+
+    //] Assert
+    assert_eq!(shares, dec!(100));
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, vault.shares), dec!(60));
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, vault.supply), dec!(940));
+}
+
+#[test]
+fn test_valid_shares_priced_from_net_value() {
+    //] Arrange
+    let (mut runner, _platform, vault, _) = arrange();
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+
+    //] Act
+    let receipt = vault.deposit(&mut runner, &alice_account, dec!(100));
+    receipt.expect_commit_success();
+
+    let receipt = vault.deposit(&mut runner, &bob_account, dec!(50));
+    receipt.expect_commit_success();
+
+    //] Assert
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, vault.shares), dec!(100));
+    assert_eq!(runner.ledger.get_component_balance(bob_account.address, vault.shares), dec!(50));
+}
+
+#[test]
+fn test_valid_shares_priced_without_equity_in_cdp() {
+    //] Arrange
+    // A CDP with debt but no supply collateral holds no equity
+    let (mut runner, _platform, vault, _) = arrange_with_debt(Some(dec!(1)));
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+
+    //] Act
+    let receipt = vault.deposit(&mut runner, &alice_account, dec!(100));
+    receipt.expect_commit_success();
+
+    let receipt = vault.deposit(&mut runner, &bob_account, dec!(50));
+    receipt.expect_commit_success();
+
+    //] Assert
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, vault.shares), dec!(100));
+    assert_eq!(runner.ledger.get_component_balance(bob_account.address, vault.shares), dec!(50));
+}
+
+#[test]
+fn test_invalid_deposit_of_other_asset() {
+    //] Arrange
+    let (mut runner, _platform, vault, _) = arrange();
+    let alice_account = runner.alice_account;
+    let usdc = runner.faucet.usdc.address;
+
+    runner.faucet.mint(&mut runner.ledger, usdc, alice_account, dec!(100));
+
+    //] Act
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .withdraw_from_account(alice_account.address, usdc, dec!(100))
+        .take_all_from_worktop(usdc, "deposit_bucket")
+        .call_method_with_name_lookup(vault.component, "deposit", |lookup| (lookup.bucket("deposit_bucket"),))
+        .deposit_entire_worktop(alice_account.address);
+
+    let receipt = runner.exec_and_dump(
+        "deposit_other_asset",
+        manifest,
+        &alice_account,
+        Some("clusters/yield_multiplier_vault_weftv2"),
+    );
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_deposit_while_paused() {
+    //] Arrange
+    let (mut runner, platform, vault, _) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    platform.pause_all(&mut runner, &owner_account);
+
+    //] Act
+    let receipt = vault.deposit(&mut runner, &alice_account, dec!(100));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
@@ -0,0 +1,208 @@
+use crate::helpers::prelude::*;
+use crate::helpers::{
+    clusters::yield_multiplier_weftv2::{YMWeftV2AccessMode, YMWeftV2Cluster, YMWeftV2ClusterFactory},
+    integrations::weftv2::MockWeftV2,
+    platform::Platform,
+};
+use scrypto_test::prelude::*;
+
+/// Instantiates and links a cluster, and gives Alice a user badge.
+fn arrange() -> (Runner, Platform, YMWeftV2Cluster, MockWeftV2) {
+    // Create a test runner and platform
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    // Instantiate a YieldMultiplierWeftCluster
+    let weftv2 = MockWeftV2::new(&mut runner);
+    let ym_weftv2_cluster_factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let supply = runner.faucet.usdt.address;
+    let debt = runner.faucet.xwbtc.address;
+
+    let cluster = ym_weftv2_cluster_factory.instantiate(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        supply,
+        debt,
+        weftv2.cdp,
+    );
+
+    // Link cluster to platform
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    // Get a user badge
+    platform.new_user(&mut runner, &alice_account);
+
+    (runner, platform, cluster, weftv2)
+}
+
+/// Opens an account for Alice with an empty CDP, presenting a proof of the access resource if given.
+fn open_account(
+    runner: &mut Runner,
+    cluster: &YMWeftV2Cluster,
+    weftv2: &mut MockWeftV2,
+    access_resource: Option<ResourceAddress>,
+) -> TransactionReceipt {
+    let alice_account = runner.alice_account;
+    let cdp_id = weftv2.mint_empty(runner, alice_account);
+
+    let mut manifest = ManifestBuilder::new().lock_fee_from_faucet();
+    if let Some(access_resource) = access_resource {
+        manifest = manifest.create_proof_from_account_of_amount(alice_account.address, access_resource, dec!(1));
+    }
+
+    let manifest = manifest
+        .create_proof_from_account_of_non_fungibles(alice_account.address, cluster.user_badge, vec![NonFungibleLocalId::Integer(0.into())])
+        .pop_from_auth_zone("user_badge")
+        .withdraw_non_fungibles_from_account(alice_account.address, weftv2.cdp, vec![cdp_id.clone()])
+        .take_non_fungibles_from_worktop(weftv2.cdp, vec![cdp_id], "cdp_bucket")
+        .withdraw_from_account(alice_account.address, XRD, dec!(16))
+        .take_all_from_worktop(XRD, "fee_bucket")
+        .call_method_with_name_lookup(cluster.component, "open_account", |lookup| {
+            (lookup.proof("user_badge"), lookup.bucket("cdp_bucket"), lookup.bucket("fee_bucket"))
+        })
+        .deposit_entire_worktop(alice_account.address);
+
+    runner.exec_and_dump("open_account", manifest, &alice_account, Some("clusters/yield_multiplier_weftv2"))
+}
+
+#[test]
+fn test_account_open_on_allow_list() {
+    //] Arrange
+    let (mut runner, platform, cluster, mut weftv2) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    let receipt = cluster.set_access_mode(&mut runner, &owner_account, platform.owner_badge, YMWeftV2AccessMode::AllowList);
+    receipt.expect_commit_success();
+
+    let receipt = cluster.add_to_allow_list(
+        &mut runner,
+        &owner_account,
+        platform.owner_badge,
+        vec![NonFungibleLocalId::Integer(0.into())],
+    );
+    receipt.expect_commit_success();
+
+    //] Act & Assert
+    let cdp_id = weftv2.mint_empty(&mut runner, alice_account);
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_account_open_off_allow_list() {
+    //] Arrange
+    let (mut runner, platform, cluster, mut weftv2) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    let receipt = cluster.set_access_mode(&mut runner, &owner_account, platform.owner_badge, YMWeftV2AccessMode::AllowList);
+    receipt.expect_commit_success();
+
+    //] Act & Assert
+    let cdp_id = weftv2.mint_empty(&mut runner, alice_account);
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_account_open_after_removal_from_allow_list() {
+    //] Arrange
+    let (mut runner, platform, cluster, mut weftv2) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let user_ids = vec![NonFungibleLocalId::Integer(0.into())];
+
+    let receipt = cluster.set_access_mode(&mut runner, &owner_account, platform.owner_badge, YMWeftV2AccessMode::AllowList);
+    receipt.expect_commit_success();
+
+    let receipt = cluster.add_to_allow_list(&mut runner, &owner_account, platform.owner_badge, user_ids.clone());
+    receipt.expect_commit_success();
+
+    let receipt = cluster.remove_from_allow_list(&mut runner, &owner_account, platform.owner_badge, user_ids);
+    receipt.expect_commit_success();
+
+    //] Act & Assert
+    let cdp_id = weftv2.mint_empty(&mut runner, alice_account);
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+}
+
+#[test]
+fn test_account_open_with_access_resource() {
+    //] Arrange
+    let (mut runner, platform, cluster, mut weftv2) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let access_resource = runner.faucet.hug.address;
+
+    let receipt = cluster.set_access_mode(
+        &mut runner,
+        &owner_account,
+        platform.owner_badge,
+        YMWeftV2AccessMode::AccessResource(access_resource),
+    );
+    receipt.expect_commit_success();
+
+    runner.faucet.mint(&mut runner.ledger, access_resource, alice_account, dec!(1));
+
+    //] Act
+    let receipt = open_account(&mut runner, &cluster, &mut weftv2, Some(access_resource));
+
+    //] Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_invalid_account_open_without_access_resource() {
+    //] Arrange
+    let (mut runner, platform, cluster, mut weftv2) = arrange();
+    let owner_account = runner.owner_account;
+    let access_resource = runner.faucet.hug.address;
+
+    let receipt = cluster.set_access_mode(
+        &mut runner,
+        &owner_account,
+        platform.owner_badge,
+        YMWeftV2AccessMode::AccessResource(access_resource),
+    );
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = open_account(&mut runner, &cluster, &mut weftv2, None);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_unauthorised_set_access_mode() {
+    //] Arrange
+    let (mut runner, platform, cluster, _) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let set_mode = cluster.set_access_mode(&mut runner, &alice_account, platform.owner_badge, YMWeftV2AccessMode::AllowList);
+    let add = cluster.add_to_allow_list(
+        &mut runner,
+        &alice_account,
+        platform.owner_badge,
+        vec![NonFungibleLocalId::Integer(0.into())],
+    );
+    let remove = cluster.remove_from_allow_list(
+        &mut runner,
+        &alice_account,
+        platform.owner_badge,
+        vec![NonFungibleLocalId::Integer(0.into())],
+    );
+
+    //] Assert
+    set_mode.expect_commit_failure();
+    add.expect_commit_failure();
+    remove.expect_commit_failure();
+}
@@ -0,0 +1,193 @@
+use crate::helpers::clusters::yield_multiplier_weftv2::arrange_with_account;
+use crate::helpers::prelude::*;
+use scrypto_test::prelude::*;
+
+#[test]
+fn test_valid_execution_then_close() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    //] Act & Assert
+    // Idle -> Executing -> Idle
+    let receipt = cluster.execute(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+    receipt.expect_commit_success();
+
+    // Idle -> Closing -> Closed
+    let receipt = cluster.close_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_invalid_close_during_execution() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    //] Act
+    // Executing -> Closing
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(alice_account.address, platform.user_badge, vec![NonFungibleLocalId::Integer(0.into())])
+        .pop_from_auth_zone("start_badge")
+        .call_method_with_name_lookup(cluster.component, "start_execution", |lookup| {
+            (Some(lookup.proof("start_badge")), None::<(NonFungibleLocalId, ManifestProof)>)
+        })
+        .create_proof_from_account_of_non_fungibles(alice_account.address, platform.user_badge, vec![NonFungibleLocalId::Integer(0.into())])
+        .pop_from_auth_zone("close_badge")
+        .withdraw_from_account(alice_account.address, XRD, dec!(16))
+        .take_all_from_worktop(XRD, "fee_bucket")
+        .call_method_with_name_lookup(cluster.component, "close_account", |lookup| {
+            (lookup.proof("close_badge"), lookup.bucket("fee_bucket"))
+        })
+        .deposit_entire_worktop(alice_account.address);
+
+    let receipt = runner.exec_and_dump(
+        "close_during_execution",
+        manifest,
+        &alice_account,
+        Some("clusters/yield_multiplier_weftv2"),
+    );
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_double_start_execution() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    //] Act
+    // Executing -> Executing
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(alice_account.address, platform.user_badge, vec![NonFungibleLocalId::Integer(0.into())])
+        .pop_from_auth_zone("first_badge")
+        .call_method_with_name_lookup(cluster.component, "start_execution", |lookup| {
+            (Some(lookup.proof("first_badge")), None::<(NonFungibleLocalId, ManifestProof)>)
+        })
+        .create_proof_from_account_of_non_fungibles(alice_account.address, platform.user_badge, vec![NonFungibleLocalId::Integer(0.into())])
+        .pop_from_auth_zone("second_badge")
+        .call_method_with_name_lookup(cluster.component, "start_execution", |lookup| {
+            (Some(lookup.proof("second_badge")), None::<(NonFungibleLocalId, ManifestProof)>)
+        })
+        .deposit_entire_worktop(alice_account.address);
+
+    let receipt = runner.exec_and_dump(
+        "double_start_execution",
+        manifest,
+        &alice_account,
+        Some("clusters/yield_multiplier_weftv2"),
+    );
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_start_execution_after_close() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    let receipt = cluster.close_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+    receipt.expect_commit_success();
+
+    //] Act
+    // Closed -> Executing
+    let receipt = cluster.execute(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_double_close() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    let receipt = cluster.close_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+    receipt.expect_commit_success();
+
+    //] Act
+    // Closed -> Closing
+    let receipt = cluster.close_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_valid_reopen_after_close() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    let receipt = cluster.close_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+    receipt.expect_commit_success();
+
+    //] Act & Assert
+    // Closed -> Idle
+    cluster.open_account(
+        &mut runner,
+        &alice_account,
+        NonFungibleLocalId::Integer(0.into()),
+        NonFungibleLocalId::Integer(0.into()),
+    );
+}
+
+#[test]
+fn test_invalid_open_account_during_execution() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    //] Act
+    // Executing -> Idle, reopening the account with the CDP taken out for the execution
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_non_fungibles(alice_account.address, platform.user_badge, vec![NonFungibleLocalId::Integer(0.into())])
+        .pop_from_auth_zone("start_badge")
+        .call_method_with_name_lookup(cluster.component, "start_execution", |lookup| {
+            (Some(lookup.proof("start_badge")), None::<(NonFungibleLocalId, ManifestProof)>)
+        })
+        .take_all_from_worktop(cluster.cdp, "cdp_bucket")
+        .create_proof_from_account_of_non_fungibles(alice_account.address, platform.user_badge, vec![NonFungibleLocalId::Integer(0.into())])
+        .pop_from_auth_zone("open_badge")
+        .withdraw_from_account(alice_account.address, XRD, dec!(16))
+        .take_all_from_worktop(XRD, "fee_bucket")
+        .call_method_with_name_lookup(cluster.component, "open_account", |lookup| {
+            (lookup.proof("open_badge"), lookup.bucket("cdp_bucket"), lookup.bucket("fee_bucket"))
+        })
+        .deposit_entire_worktop(alice_account.address);
+
+    let receipt = runner.exec_and_dump(
+        "open_during_execution",
+        manifest,
+        &alice_account,
+        Some("clusters/yield_multiplier_weftv2"),
+    );
+
+    //] Assert
+    // The cluster rejects the reopening itself, before the platform is asked to record the account
+    receipt.expect_specific_failure(|error| format!("{:?}", error).contains("User already has an open account"));
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_set_delegate_after_close() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+
+    let receipt = cluster.close_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+    receipt.expect_commit_success();
+
+    //] Act & Assert
+    let rule = rule!(require(bob_account.global_id()));
+    cluster.set_delegate(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), rule, None, None);
+}
@@ -1,7 +1,10 @@
 use crate::helpers::clusters::yield_multiplier_weftv2::YMWeftV2ClusterService;
 use crate::helpers::platform::{PlatformClusterService, PlatformService};
 use crate::helpers::prelude::*;
-use crate::helpers::{clusters::yield_multiplier_weftv2::YMWeftV2ClusterFactory, integrations::weftv2::MockWeftV2};
+use crate::helpers::{
+    clusters::yield_multiplier_weftv2::{arrange_unwind, YMWeftV2Adjustment, YMWeftV2ClusterFactory, YMWeftV2LeverageMode},
+    integrations::weftv2::MockWeftV2,
+};
 use scrypto_test::prelude::*;
 
 #[test]
@@ -591,7 +594,11 @@ fn test_invalid_account_open_when_platform_wide_service_disabled() {
     let manifest = ManifestBuilder::new()
         .lock_fee_from_faucet()
         .create_proof_from_account_of_amount(owner_account.address, platform.owner_badge, dec!(1))
-        .call_method(platform.component, "update_service", manifest_args!(PlatformService::OpenAccount, false,));
+        .call_method(
+            platform.component,
+            "update_service",
+            manifest_args!(PlatformService::OpenAccount, false, None::<Instant>, None::<Instant>,),
+        );
     let receipt = runner.exec("update_platform_service", manifest, &owner_account, None);
 
     receipt.expect_commit_success();
@@ -670,7 +677,7 @@ fn test_invalid_account_close_when_platform_wide_service_disabled() {
         .call_method(
             platform.component,
             "update_service",
-            manifest_args!(PlatformService::CloseAccount, false,),
+            manifest_args!(PlatformService::CloseAccount, false, None::<Instant>, None::<Instant>,),
         );
     let receipt = runner.exec("update_platform_service", manifest, &owner_account, None);
 
@@ -733,7 +740,13 @@ fn test_invalid_account_open_when_platform_specific_service_disabled() {
         .call_method(
             platform.component,
             "update_cluster_service",
-            manifest_args!(cluster.component, PlatformClusterService::OpenAccount, false,),
+            manifest_args!(
+                cluster.component,
+                PlatformClusterService::OpenAccount,
+                false,
+                None::<Instant>,
+                None::<Instant>,
+            ),
         );
     let receipt = runner.exec_and_dump("update_platform_specific_service", manifest, &owner_account, None);
 
@@ -813,7 +826,13 @@ fn test_invalid_account_close_when_platform_specific_service_disabled() {
         .call_method(
             platform.component,
             "update_cluster_service",
-            manifest_args!(cluster.component, PlatformClusterService::CloseAccount, false,),
+            manifest_args!(
+                cluster.component,
+                PlatformClusterService::CloseAccount,
+                false,
+                None::<Instant>,
+                None::<Instant>,
+            ),
         );
     let receipt = runner.exec("update_platform_specific_service", manifest, &owner_account, None);
 
@@ -876,7 +895,7 @@ fn test_invalid_account_open_when_cluster_service_disabled() {
         .call_method(
             cluster.component,
             "update_service",
-            manifest_args!(YMWeftV2ClusterService::OpenAccount, false,),
+            manifest_args!(YMWeftV2ClusterService::OpenAccount, false, None::<Instant>, None::<Instant>,),
         );
     let receipt = runner.exec_and_dump(
         "update_cluster_service",
@@ -961,7 +980,7 @@ fn test_invalid_account_close_when_cluster_service_disabled() {
         .call_method(
             cluster.component,
             "update_service",
-            manifest_args!(YMWeftV2ClusterService::CloseAccount, false,),
+            manifest_args!(YMWeftV2ClusterService::CloseAccount, false, None::<Instant>, None::<Instant>,),
         );
     let receipt = runner.exec(
         "update_cluster_service",
@@ -983,3 +1002,88 @@ fn test_invalid_account_close_when_cluster_service_disabled() {
     let receipt = runner.exec("close_account", manifest, &alice_account, Some("clusters/yield_multiplier_weftv2"));
     receipt.expect_commit_success();
 }
+
+/* ---------------- Cost Basis ---------------- */
+// Alice's account opens with 1000 USDT of collateral and 500 USDC of debt, both priced at 1, for a cost basis of 500
+
+#[test]
+fn test_valid_cost_basis_after_deposit() {
+    //] Arrange
+    let (mut runner, _platform, cluster, _prices) = arrange_unwind(YMWeftV2LeverageMode::Long);
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    runner.faucet.mint(&mut runner.ledger, cluster.supply, alice_account, dec!(200));
+
+    //] Act
+    let adjustment = YMWeftV2Adjustment::AddCollateral(cluster.supply, dec!(200));
+    let receipt = cluster.execute_adjusting(&mut runner, &alice_account, user_id.clone(), adjustment);
+    receipt.expect_commit_success();
+
+    //] Assert
+    // The deposit adds its full value to the basis, realising nothing
+    let info = cluster.get_account_info(&mut runner, &alice_account, user_id);
+
+    assert_eq!(info.cost_basis, dec!(700));
+    assert_eq!(info.unrealised_pnl, dec!(0));
+    assert_eq!(info.realised_pnl, dec!(0));
+    assert_eq!(info.principal, dec!(500));
+}
+
+#[test]
+fn test_valid_cost_basis_after_partial_withdrawal() {
+    //] Arrange
+    let (mut runner, _platform, cluster, prices) = arrange_unwind(YMWeftV2LeverageMode::Long);
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    // Raise the collateral's price, for a net value of 1000 on the basis of 500
+    prices.set_price(&mut runner, cluster.supply, dec!(1.5));
+
+    //] Act
+    // Withdraw 300 of the net value
+    let adjustment = YMWeftV2Adjustment::RemoveCollateral(cluster.supply, dec!(200));
+    let receipt = cluster.execute_adjusting(&mut runner, &alice_account, user_id.clone(), adjustment);
+    receipt.expect_commit_success();
+
+    //] Assert
+    // The withdrawal releases 30% of the basis, realising the gain on it and leaving the rest unrealised
+    let info = cluster.get_account_info(&mut runner, &alice_account, user_id);
+
+    assert_eq!(info.cost_basis, dec!(350));
+    assert_eq!(info.unrealised_pnl, dec!(350));
+    assert_eq!(info.realised_pnl, dec!(150));
+    assert_eq!(info.principal, dec!(500));
+}
+
+#[test]
+fn test_valid_cost_basis_after_full_withdrawal() {
+    //] Arrange
+    let (mut runner, _platform, cluster, prices) = arrange_unwind(YMWeftV2LeverageMode::Long);
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    // Repay the debt, depositing its value and clearing the principal
+    runner.faucet.mint(&mut runner.ledger, cluster.debt, alice_account, dec!(500));
+
+    let adjustment = YMWeftV2Adjustment::Repay(cluster.debt, dec!(500));
+    let receipt = cluster.execute_adjusting(&mut runner, &alice_account, user_id.clone(), adjustment);
+    receipt.expect_commit_success();
+
+    // Raise the collateral's price, for a net value of 1200 on the basis of 1000
+    prices.set_price(&mut runner, cluster.supply, dec!(1.2));
+
+    //] Act
+    let adjustment = YMWeftV2Adjustment::RemoveCollateral(cluster.supply, dec!(1000));
+    let receipt = cluster.execute_adjusting(&mut runner, &alice_account, user_id.clone(), adjustment);
+    receipt.expect_commit_success();
+
+    //] Assert
+    // The withdrawal releases the whole basis, realising the full gain
+    let info = cluster.get_account_info(&mut runner, &alice_account, user_id);
+
+    assert_eq!(info.cost_basis, dec!(0));
+    assert_eq!(info.unrealised_pnl, dec!(0));
+    assert_eq!(info.realised_pnl, dec!(200));
+    assert_eq!(info.principal, dec!(0));
+}
@@ -0,0 +1,172 @@
+use crate::helpers::prelude::*;
+use crate::helpers::{
+    clusters::yield_multiplier_weftv2::{YMWeftV2Adjustment, YMWeftV2Cluster, YMWeftV2ClusterFactory},
+    integrations::{mocks::MockWeftMarket, weftv2::MockWeftV2},
+    platform::Platform,
+};
+use scrypto_test::prelude::*;
+
+/// Instantiates and links an uncapped basket cluster of USDT/USDC collateral and xwBTC debt, and registers Alice.
+fn arrange() -> (Runner, Platform, MockWeftV2, YMWeftV2Cluster) {
+    // Create a test runner and platform
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    // Instantiate a basket YieldMultiplierWeftCluster
+    let weftv2 = MockWeftV2::new(&mut runner);
+    let ym_weftv2_cluster_factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let collaterals = indexmap! {
+        runner.faucet.usdt.address => dec!(1),
+        runner.faucet.usdc.address => dec!(1),
+    };
+    let loans = indexmap! { runner.faucet.xwbtc.address => dec!(1) };
+
+    let cluster = ym_weftv2_cluster_factory.instantiate_basket(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        collaterals,
+        loans,
+        weftv2.cdp,
+    );
+
+    // Link cluster to platform
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    // Get a user badge
+    platform.new_user(&mut runner, &alice_account);
+
+    (runner, platform, weftv2, cluster)
+}
+
+#[test]
+fn test_valid_basket_account_open() {
+    //] Arrange
+    let (mut runner, _platform, mut weftv2, cluster) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act & Assert
+    let collateral = indexmap! {
+        runner.faucet.usdt.address => dec!(1000),
+        runner.faucet.usdc.address => dec!(500),
+    };
+    let loan = indexmap! { runner.faucet.xwbtc.address => dec!(100) };
+    let cdp_id = weftv2.mint(&mut runner, alice_account, Some(collateral), Some(loan), false);
+
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_basket_account_open_with_collateral_outside_basket() {
+    //] Arrange
+    let (mut runner, _platform, mut weftv2, cluster) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act & Assert
+    let collateral = indexmap! {
+        runner.faucet.usdt.address => dec!(1000),
+        runner.faucet.hug.address => dec!(500),
+    };
+    let cdp_id = weftv2.mint(&mut runner, alice_account, Some(collateral), None, false);
+
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_basket_account_open_with_debt_outside_basket() {
+    //] Arrange
+    let (mut runner, _platform, mut weftv2, cluster) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act & Assert
+    let collateral = indexmap! { runner.faucet.usdt.address => dec!(1000) };
+    let loan = indexmap! { runner.faucet.usdc.address => dec!(100) };
+    let cdp_id = weftv2.mint(&mut runner, alice_account, Some(collateral), Some(loan), false);
+
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+}
+
+/// Opens an account for Alice with USDT/USDC collateral, and funds the market with USDC to withdraw.
+fn arrange_account(runner: &mut Runner, weftv2: &mut MockWeftV2, cluster: &YMWeftV2Cluster) {
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let usdc = runner.faucet.usdc.address;
+
+    let collateral = indexmap! {
+        runner.faucet.usdt.address => dec!(1000),
+        usdc => dec!(500),
+    };
+    let cdp_id = weftv2.mint(runner, alice_account, Some(collateral), None, false);
+    cluster.open_account(runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+
+    let market = MockWeftMarket { component: cluster.weft_market };
+    runner.faucet.mint(&mut runner.ledger, usdc, owner_account, dec!(500));
+    market.deposit_liquidity(runner, &owner_account, usdc, dec!(500));
+}
+
+#[test]
+fn test_valid_execution_removing_secondary_collateral() {
+    //] Arrange
+    let (mut runner, _platform, mut weftv2, cluster) = arrange();
+    let alice_account = runner.alice_account;
+    arrange_account(&mut runner, &mut weftv2, &cluster);
+
+    //] Act
+    let adjustment = YMWeftV2Adjustment::RemoveCollateral(runner.faucet.usdc.address, dec!(100));
+    let receipt = cluster.execute_adjusting(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), adjustment);
+
+    //] Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_invalid_execution_removing_secondary_collateral_past_invariants() {
+    //] Arrange
+    let (mut runner, platform, mut weftv2, cluster) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    arrange_account(&mut runner, &mut weftv2, &cluster);
+
+    // Every collateral of the basket must be retained in full, not only the primary USDT
+    let receipt = cluster.set_execution_invariants(&mut runner, &owner_account, platform.owner_badge, None, Some(dec!(1)), None, false);
+    receipt.expect_commit_success();
+
+    //] Act
+    let adjustment = YMWeftV2Adjustment::RemoveCollateral(runner.faucet.usdc.address, dec!(100));
+    let receipt = cluster.execute_adjusting(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), adjustment);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_basket_instantiation_with_zero_weight_cap() {
+    //] Arrange
+    let (mut runner, platform) = Runner::new_base();
+    let weftv2 = MockWeftV2::new(&mut runner);
+    let ym_weftv2_cluster_factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let collaterals = indexmap! { runner.faucet.usdt.address => dec!(0) };
+    let loans = indexmap! { runner.faucet.xwbtc.address => dec!(1) };
+
+    //] Act & Assert
+    ym_weftv2_cluster_factory.instantiate_basket(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        collaterals,
+        loans,
+        weftv2.cdp,
+    );
+}
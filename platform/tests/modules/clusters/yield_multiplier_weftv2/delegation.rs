@@ -0,0 +1,161 @@
+use crate::helpers::prelude::*;
+use crate::helpers::{
+    clusters::yield_multiplier_weftv2::{arrange_with_account, YMWeftV2Adjustment, YMWeftV2Cluster},
+    integrations::mocks::MockWeftMarket,
+    platform::Platform,
+};
+use scrypto_test::prelude::*;
+
+/// Instantiates and links a cluster, opens an account for Alice with a CDP of 1,000 USDT collateral, and funds the
+/// market with USDT and xwBTC to withdraw and borrow. Bob gets a user badge, which Alice may name as her delegate.
+fn arrange() -> (Runner, Platform, YMWeftV2Cluster, NonFungibleGlobalId) {
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(1000));
+    let owner_account = runner.owner_account;
+    let bob_account = runner.bob_account;
+    let (supply, debt) = (cluster.supply, cluster.debt);
+
+    // Fund the market
+    let market = MockWeftMarket { component: cluster.weft_market };
+    for (resource, amount) in [(supply, dec!(1000)), (debt, dec!(1))] {
+        runner.faucet.mint(&mut runner.ledger, resource, owner_account, amount);
+        market.deposit_liquidity(&mut runner, &owner_account, resource, amount);
+    }
+
+    // Get a user badge for Bob
+    platform.new_user(&mut runner, &bob_account);
+    let bob_badge = NonFungibleGlobalId::new(platform.user_badge, NonFungibleLocalId::Integer(1.into()));
+
+    (runner, platform, cluster, bob_badge)
+}
+
+#[test]
+fn test_valid_delegated_execution() {
+    //] Arrange
+    let (mut runner, _platform, cluster, bob_badge) = arrange();
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    // Delegate execution rights to Bob
+    let rule = rule!(require(bob_badge.clone()));
+    cluster.set_delegate(&mut runner, &alice_account, user_id.clone(), rule, Some(dec!(0)), Some(dec!(1)));
+
+    //] Act
+    let receipt = cluster.execute_as_delegate(&mut runner, &bob_account, user_id, bob_badge, None);
+
+    //] Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_valid_delegated_execution_within_max_debt_increase() {
+    //] Arrange
+    let (mut runner, _platform, cluster, bob_badge) = arrange();
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let rule = rule!(require(bob_badge.clone()));
+    cluster.set_delegate(&mut runner, &alice_account, user_id.clone(), rule, Some(dec!(0.002)), None);
+
+    //] Act
+    let adjustment = YMWeftV2Adjustment::Borrow(cluster.debt, dec!(0.002));
+    let receipt = cluster.execute_as_delegate(&mut runner, &bob_account, user_id, bob_badge, Some(adjustment));
+
+    //] Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_invalid_delegated_execution_past_max_debt_increase() {
+    //] Arrange
+    let (mut runner, _platform, cluster, bob_badge) = arrange();
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let rule = rule!(require(bob_badge.clone()));
+    cluster.set_delegate(&mut runner, &alice_account, user_id.clone(), rule, Some(dec!(0.001)), None);
+
+    //] Act
+    let adjustment = YMWeftV2Adjustment::Borrow(cluster.debt, dec!(0.002));
+    let receipt = cluster.execute_as_delegate(&mut runner, &bob_account, user_id, bob_badge, Some(adjustment));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_delegated_execution_below_collateral_floor() {
+    //] Arrange
+    let (mut runner, _platform, cluster, bob_badge) = arrange();
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let rule = rule!(require(bob_badge.clone()));
+    cluster.set_delegate(&mut runner, &alice_account, user_id.clone(), rule, None, Some(dec!(0.9)));
+
+    //] Act
+    let adjustment = YMWeftV2Adjustment::RemoveCollateral(cluster.supply, dec!(200));
+    let receipt = cluster.execute_as_delegate(&mut runner, &bob_account, user_id, bob_badge, Some(adjustment));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_set_delegate_with_collateral_floor_above_one() {
+    //] Arrange
+    let (mut runner, _platform, cluster, bob_badge) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act & Assert
+    let rule = rule!(require(bob_badge));
+    cluster.set_delegate(
+        &mut runner,
+        &alice_account,
+        NonFungibleLocalId::Integer(0.into()),
+        rule,
+        None,
+        Some(dec!(1.1)),
+    );
+}
+
+#[test]
+fn test_invalid_delegated_execution_without_delegate() {
+    //] Arrange
+    let (mut runner, _platform, cluster, bob_badge) = arrange();
+    let bob_account = runner.bob_account;
+
+    //] Act
+    let receipt = cluster.execute_as_delegate(&mut runner, &bob_account, NonFungibleLocalId::Integer(0.into()), bob_badge, None);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_delegated_execution_by_other() {
+    //] Arrange
+    let (mut runner, platform, cluster, bob_badge) = arrange();
+    let alice_account = runner.alice_account;
+    let owner_account = runner.owner_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    // Delegate execution rights to Bob
+    let rule = rule!(require(bob_badge));
+    cluster.set_delegate(&mut runner, &alice_account, user_id.clone(), rule, None, None);
+
+    // Get a user badge for the owner
+    platform.new_user(&mut runner, &owner_account);
+    let owner_badge = NonFungibleGlobalId::new(platform.user_badge, NonFungibleLocalId::Integer(2.into()));
+
+    //] Act
+    // Attempt to execute as the owner, presenting its own user badge
+    let receipt = cluster.execute_as_delegate(&mut runner, &owner_account, user_id, owner_badge, None);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
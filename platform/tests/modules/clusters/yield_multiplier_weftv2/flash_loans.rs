@@ -0,0 +1,156 @@
+use crate::helpers::prelude::*;
+use crate::helpers::{
+    clusters::yield_multiplier_weftv2::{arrange_with_account, YMWeftV2Cluster, YMWeftV2FlashLoanSource},
+    flash_loans::{FlashLoanPool, LiquidityInfo},
+    platform::Platform,
+};
+use scrypto_test::prelude::*;
+
+/// Deploys the platform's pool charging 0.1% on flash loans, free for linked clusters, with 1 xwBTC of liquidity;
+/// and sets it as the cluster's provider.
+fn arrange_pool(runner: &mut Runner, platform: &Platform, cluster: &YMWeftV2Cluster) -> FlashLoanPool {
+    let owner_account = runner.owner_account;
+    let xwbtc = runner.faucet.xwbtc.address;
+
+    platform.new_admin_badge(runner, &owner_account);
+    let pool = FlashLoanPool::deploy(runner, platform, dec!(0.001));
+
+    let receipt = pool.add_resource(runner, &owner_account, platform.admin_badge, xwbtc);
+    receipt.expect_commit_success();
+
+    runner.faucet.mint(&mut runner.ledger, xwbtc, owner_account, dec!(1));
+    let receipt = pool.deposit(runner, &owner_account, xwbtc, dec!(1));
+    receipt.expect_commit_success();
+
+    let source = YMWeftV2FlashLoanSource::Vault(pool.component);
+    let receipt = cluster.set_flash_loan_provider(runner, &owner_account, platform.owner_badge, Some(source), dec!(0.0005));
+    receipt.expect_commit_success();
+
+    pool
+}
+
+#[test]
+fn test_valid_get_flash_loan_quote() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    let source = YMWeftV2FlashLoanSource::Weft(platform.component);
+    let receipt = cluster.set_flash_loan_provider(&mut runner, &owner_account, platform.owner_badge, Some(source), dec!(0.0005));
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = cluster.get_flash_loan_quote(&mut runner, &alice_account, dec!(1000));
+
+    //] Assert
+    let fee: Decimal = receipt.expect_commit_success().output(1);
+
+    assert_eq!(fee, dec!(0.5));
+}
+
+#[test]
+fn test_invalid_get_flash_loan_quote_without_provider() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    // Set then remove the provider
+    let source = YMWeftV2FlashLoanSource::Vault(platform.component);
+    let receipt = cluster.set_flash_loan_provider(&mut runner, &owner_account, platform.owner_badge, Some(source), dec!(0.0005));
+    receipt.expect_commit_success();
+
+    let receipt = cluster.set_flash_loan_provider(&mut runner, &owner_account, platform.owner_badge, None, dec!(0));
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = cluster.get_flash_loan_quote(&mut runner, &alice_account, dec!(1000));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_set_flash_loan_provider_with_fee_rate() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let owner_account = runner.owner_account;
+
+    //] Act
+    let source = YMWeftV2FlashLoanSource::Root(platform.component);
+    let receipt = cluster.set_flash_loan_provider(&mut runner, &owner_account, platform.owner_badge, Some(source), dec!(1));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_unauthorised_set_flash_loan_provider() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let source = YMWeftV2FlashLoanSource::Weft(platform.component);
+    let receipt = cluster.set_flash_loan_provider(&mut runner, &alice_account, platform.owner_badge, Some(source), dec!(0.0005));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_valid_get_flash_loan_quote_at_linked_rate() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    arrange_pool(&mut runner, &platform, &cluster);
+
+    //] Act
+    let receipt = cluster.get_flash_loan_quote(&mut runner, &alice_account, dec!(1));
+
+    //] Assert
+    let fee: Decimal = receipt.expect_commit_success().output(1);
+
+    assert_eq!(fee, dec!(0));
+}
+
+#[test]
+fn test_valid_execution_with_flash_loan() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+    let xwbtc = runner.faucet.xwbtc.address;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let pool = arrange_pool(&mut runner, &platform, &cluster);
+
+    //] Act
+    let receipt = cluster.execute_with_flash_loan(&mut runner, &alice_account, user_id, pool.terms, dec!(0.5), true);
+
+    //] Assert
+    receipt.expect_commit_success();
+
+    let receipt = pool.get_liquidity(&mut runner, &alice_account, xwbtc);
+    let liquidity: LiquidityInfo = receipt.expect_commit_success().output(1);
+
+    assert_eq!(liquidity.available, dec!(1));
+    assert_eq!(liquidity.outstanding, dec!(0));
+}
+
+#[test]
+fn test_invalid_execution_keeping_flash_loan() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    let pool = arrange_pool(&mut runner, &platform, &cluster);
+
+    //] Act
+    let receipt = cluster.execute_with_flash_loan(&mut runner, &alice_account, user_id, pool.terms, dec!(0.5), false);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
@@ -0,0 +1,89 @@
+use crate::helpers::clusters::yield_multiplier_weftv2::{
+    arrange_unwind, arrange_with_account, YMWeftV2JournalEntry, YMWeftV2JournalEvent, YMWeftV2LeverageMode,
+};
+use crate::helpers::prelude::*;
+use scrypto_test::prelude::*;
+
+/// Returns a page of Alice's account history.
+fn history(runner: &mut Runner, cluster: &YMWeftV2Cluster, offset: u64, limit: u64) -> Vec<YMWeftV2JournalEntry> {
+    let alice_account = runner.alice_account;
+
+    let receipt = cluster.get_account_history(runner, &alice_account, NonFungibleLocalId::Integer(0.into()), offset, limit);
+    receipt.expect_commit_success().output(1)
+}
+
+#[test]
+fn test_valid_history_of_account_lifecycle() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = cluster.execute(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+    receipt.expect_commit_success();
+
+    let receipt = cluster.close_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+    receipt.expect_commit_success();
+
+    //] Assert
+    let events: Vec<YMWeftV2JournalEvent> = history(&mut runner, &cluster, 0, 10).iter().map(|entry| entry.event).collect();
+
+    assert_eq!(
+        events,
+        vec![YMWeftV2JournalEvent::Open, YMWeftV2JournalEvent::Execution, YMWeftV2JournalEvent::Close]
+    );
+}
+
+#[test]
+fn test_valid_history_pagination() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    for _ in 0..3 {
+        let receipt = cluster.execute(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+        receipt.expect_commit_success();
+    }
+
+    //] Act
+    let first = history(&mut runner, &cluster, 0, 2);
+    let second = history(&mut runner, &cluster, 2, 2);
+    let past_end = history(&mut runner, &cluster, 4, 2);
+
+    //] Assert
+    assert_eq!(first.len(), 2);
+    assert_eq!(second.len(), 2);
+    assert!(past_end.is_empty());
+    assert_eq!(second[1].event, YMWeftV2JournalEvent::Execution);
+}
+
+#[test]
+fn test_valid_history_valuation() {
+    //] Arrange
+    // Alice's account opens with 1000 USDT of collateral and 500 USDC of debt, both priced at 1
+    let (mut runner, _platform, cluster, _prices) = arrange_unwind(YMWeftV2LeverageMode::Long);
+
+    //] Act
+    let entries = history(&mut runner, &cluster, 0, 1);
+
+    //] Assert
+    // The health is Weft's health LTV; the debt over the collateral value at the 0.8 LTV ratio
+    let valuation = entries[0].valuation.clone().expect("Open entry has no valuation");
+
+    assert_eq!(valuation.collateral_value, dec!(1000));
+    assert_eq!(valuation.debt_value, dec!(500));
+    assert_eq!(valuation.health, dec!(0.625));
+}
+
+#[test]
+fn test_invalid_history_page_size() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = cluster.get_account_history(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), 0, 101);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
@@ -0,0 +1,146 @@
+use crate::helpers::clusters::yield_multiplier_weftv2::{arrange_unwind, arrange_with_account, YMWeftV2Adjustment, YMWeftV2LeverageMode};
+use crate::helpers::prelude::*;
+use scrypto_test::prelude::*;
+
+#[test]
+fn test_execution_within_invariants() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = cluster.set_execution_invariants(
+        &mut runner,
+        &owner_account,
+        platform.owner_badge,
+        Some(dec!(0)),
+        Some(dec!(1)),
+        Some(dec!(0)),
+        true,
+    );
+    receipt.expect_commit_success();
+
+    //] Assert
+    // An execution which leaves the CDP unchanged satisfies every invariant
+    let receipt = cluster.execute(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+    receipt.expect_commit_success();
+}
+
+/* ----------------- Violations --------------- */
+// Alice's account opens with 1000 USDT of collateral and 500 USDC of debt, for a leverage of 0.5 debt per collateral
+
+#[test]
+fn test_invalid_execution_past_max_debt_increase() {
+    //] Arrange
+    let (mut runner, platform, cluster, prices) = arrange_unwind(YMWeftV2LeverageMode::Long);
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    // Fund the market with the debt asset to borrow
+    runner.faucet.mint(&mut runner.ledger, cluster.debt, owner_account, dec!(100));
+    prices.market.deposit_liquidity(&mut runner, &owner_account, cluster.debt, dec!(100));
+
+    let receipt = cluster.set_execution_invariants(&mut runner, &owner_account, platform.owner_badge, Some(dec!(50)), None, None, false);
+    receipt.expect_commit_success();
+
+    //] Act
+    let adjustment = YMWeftV2Adjustment::Borrow(cluster.debt, dec!(100));
+    let receipt = cluster.execute_adjusting(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), adjustment);
+
+    //] Assert
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Execution exceeds the maximum debt increase"));
+}
+
+#[test]
+fn test_invalid_execution_below_min_collateral_retained() {
+    //] Arrange
+    let (mut runner, platform, cluster, _prices) = arrange_unwind(YMWeftV2LeverageMode::Long);
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    let receipt = cluster.set_execution_invariants(&mut runner, &owner_account, platform.owner_badge, None, Some(dec!(0.9)), None, false);
+    receipt.expect_commit_success();
+
+    //] Act
+    // Withdraw 20% of the collateral
+    let adjustment = YMWeftV2Adjustment::RemoveCollateral(cluster.supply, dec!(200));
+    let receipt = cluster.execute_adjusting(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), adjustment);
+
+    //] Assert
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Execution does not retain the minimum collateral"));
+}
+
+#[test]
+fn test_invalid_execution_past_max_leverage_change() {
+    //] Arrange
+    let (mut runner, platform, cluster, _prices) = arrange_unwind(YMWeftV2LeverageMode::Long);
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    runner.faucet.mint(&mut runner.ledger, cluster.debt, alice_account, dec!(100));
+
+    let receipt = cluster.set_execution_invariants(&mut runner, &owner_account, platform.owner_badge, None, None, Some(dec!(0.1)), false);
+    receipt.expect_commit_success();
+
+    //] Act
+    // Repay a fifth of the debt, taking the leverage from 0.5 to 0.4
+    let adjustment = YMWeftV2Adjustment::Repay(cluster.debt, dec!(100));
+    let receipt = cluster.execute_adjusting(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), adjustment);
+
+    //] Assert
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Execution exceeds the maximum leverage change"));
+}
+
+#[test]
+fn test_invalid_execution_changing_frozen_config() {
+    //] Arrange
+    let (mut runner, platform, cluster, prices) = arrange_unwind(YMWeftV2LeverageMode::Long);
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    runner.faucet.mint(&mut runner.ledger, cluster.supply, alice_account, dec!(100));
+
+    let receipt = cluster.set_execution_invariants(&mut runner, &owner_account, platform.owner_badge, None, None, None, true);
+    receipt.expect_commit_success();
+
+    // Update the market's collateral configs, which the position takes on with its next deposit
+    prices.market.update_collateral_config(&mut runner);
+
+    //] Act
+    let adjustment = YMWeftV2Adjustment::AddCollateral(cluster.supply, dec!(100));
+    let receipt = cluster.execute_adjusting(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), adjustment);
+
+    //] Assert
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Execution changed the collateral config version"));
+}
+
+#[test]
+fn test_invalid_execution_invariants() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let owner_account = runner.owner_account;
+
+    //] Act
+    let negative_debt = cluster.set_execution_invariants(&mut runner, &owner_account, platform.owner_badge, Some(dec!(-1)), None, None, false);
+    let excess_collateral = cluster.set_execution_invariants(&mut runner, &owner_account, platform.owner_badge, None, Some(dec!(1.1)), None, false);
+    let negative_leverage = cluster.set_execution_invariants(&mut runner, &owner_account, platform.owner_badge, None, None, Some(dec!(-0.1)), false);
+
+    //] Assert
+    negative_debt.expect_commit_failure();
+    excess_collateral.expect_commit_failure();
+    negative_leverage.expect_commit_failure();
+}
+
+#[test]
+fn test_unauthorised_set_execution_invariants() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = cluster.set_execution_invariants(&mut runner, &alice_account, platform.owner_badge, Some(dec!(0)), None, None, false);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
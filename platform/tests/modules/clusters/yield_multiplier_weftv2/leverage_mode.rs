@@ -0,0 +1,93 @@
+use crate::helpers::prelude::*;
+use crate::helpers::{
+    clusters::yield_multiplier_weftv2::{YMWeftV2Cluster, YMWeftV2ClusterFactory, YMWeftV2LeverageMode},
+    integrations::weftv2::MockWeftV2,
+    platform::Platform,
+};
+use scrypto_test::prelude::*;
+
+/// Instantiates and links a short-mode cluster of USDT supply and xwBTC debt, and registers Alice.
+fn arrange() -> (Runner, Platform, MockWeftV2, YMWeftV2Cluster) {
+    // Create a test runner and platform
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    // Instantiate a short-mode YieldMultiplierWeftCluster
+    let weftv2 = MockWeftV2::new(&mut runner);
+    let ym_weftv2_cluster_factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let supply = runner.faucet.usdt.address;
+    let debt = runner.faucet.xwbtc.address;
+
+    let cluster = ym_weftv2_cluster_factory.instantiate_with_mode(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        supply,
+        debt,
+        YMWeftV2LeverageMode::Short,
+        weftv2.cdp,
+    );
+
+    // Link cluster to platform
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    // Get a user badge
+    platform.new_user(&mut runner, &alice_account);
+
+    (runner, platform, weftv2, cluster)
+}
+
+#[test]
+fn test_valid_short_mode_account_open() {
+    //] Arrange
+    let (mut runner, _platform, mut weftv2, cluster) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act & Assert
+    // The debt asset is held as collateral, against a loan of the supply asset
+    let collateral = indexmap! { cluster.debt => dec!(1) };
+    let loan = indexmap! { cluster.supply => dec!(1000) };
+    let cdp_id = weftv2.mint(&mut runner, alice_account, Some(collateral), Some(loan), false);
+
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_short_mode_account_open_with_long_cdp() {
+    //] Arrange
+    let (mut runner, _platform, mut weftv2, cluster) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act & Assert
+    let collateral = indexmap! { cluster.supply => dec!(1000) };
+    let loan = indexmap! { cluster.debt => dec!(1) };
+    let cdp_id = weftv2.mint(&mut runner, alice_account, Some(collateral), Some(loan), false);
+
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+}
+
+#[test]
+fn test_valid_get_cluster_info_short_mode() {
+    //] Arrange
+    let (mut runner, _platform, _weftv2, cluster) = arrange();
+    let owner_account = runner.owner_account;
+
+    //] Act & Assert
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(cluster.component, "get_cluster_info", manifest_args!());
+    let receipt = runner.exec_and_dump(
+        "get_cluster_info_short_mode",
+        manifest,
+        &owner_account,
+        Some("clusters/yield_multiplier_weftv2"),
+    );
+
+    receipt.expect_commit_success();
+}
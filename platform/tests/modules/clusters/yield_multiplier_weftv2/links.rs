@@ -206,7 +206,11 @@ fn test_invalid_link_when_platform_service_disabled() {
     let manifest = ManifestBuilder::new()
         .lock_fee_from_faucet()
         .create_proof_from_account_of_amount(owner_account.address, platform.owner_badge, dec!(1))
-        .call_method(platform.component, "update_service", manifest_args!(PlatformService::LinkCluster, false,));
+        .call_method(
+            platform.component,
+            "update_service",
+            manifest_args!(PlatformService::LinkCluster, false, None::<Instant>, None::<Instant>,),
+        );
     let receipt = runner.exec("update_platform_service", manifest, &owner_account, None);
 
     receipt.expect_commit_success();
@@ -253,7 +257,7 @@ fn test_invalid_unlink_when_platform_service_disabled() {
         .call_method(
             platform.component,
             "update_service",
-            manifest_args!(PlatformService::UnlinkCluster, false,),
+            manifest_args!(PlatformService::UnlinkCluster, false, None::<Instant>, None::<Instant>,),
         );
     let receipt = runner.exec("update_platform_service", manifest, &owner_account, None);
 
@@ -295,7 +299,11 @@ fn test_invalid_link_when_cluster_service_disabled() {
     let manifest = ManifestBuilder::new()
         .lock_fee_from_faucet()
         .create_proof_from_account_of_amount(owner_account.address, platform.owner_badge, dec!(1))
-        .call_method(cluster.component, "update_service", manifest_args!(YMWeftV2ClusterService::Link, false,));
+        .call_method(
+            cluster.component,
+            "update_service",
+            manifest_args!(YMWeftV2ClusterService::Link, false, None::<Instant>, None::<Instant>,),
+        );
     let receipt = runner.exec(
         "update_cluster_service",
         manifest,
@@ -348,7 +356,7 @@ fn test_invalid_link_when_cluster_service_disabled() {
 //         .call_method(
 //             cluster.component,
 //             "update_service",
-//             manifest_args!(YMWeftV2ClusterService::Unlink, false,),
+//             manifest_args!(YMWeftV2ClusterService::Unlink, false, None::<Instant>, None::<Instant>,),
 //         );
 //     let receipt = runner.exec(
 //         "update_cluster_service",
@@ -1,3 +1,16 @@
+pub mod access;
+pub mod account_state;
 pub mod accounts;
+pub mod basket;
+pub mod delegation;
+pub mod flash_loans;
+pub mod history;
 pub mod info;
+pub mod invariants;
+pub mod leverage_mode;
 pub mod links;
+pub mod nft_collaterals;
+pub mod oracle;
+pub mod refinance;
+pub mod risk_limits;
+pub mod triggers;
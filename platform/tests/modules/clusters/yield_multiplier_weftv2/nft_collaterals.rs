@@ -0,0 +1,107 @@
+use crate::helpers::prelude::*;
+use crate::helpers::{
+    clusters::yield_multiplier_weftv2::{YMWeftV2Cluster, YMWeftV2ClusterFactory},
+    integrations::weftv2::MockWeftV2,
+    platform::Platform,
+};
+use scrypto_test::prelude::*;
+
+/// Instantiates and links a cluster, and registers Alice.
+fn arrange() -> (Runner, Platform, MockWeftV2, YMWeftV2Cluster) {
+    // Create a test runner and platform
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    // Instantiate a YieldMultiplierWeftCluster
+    let weftv2 = MockWeftV2::new(&mut runner);
+    let ym_weftv2_cluster_factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let supply = runner.faucet.usdt.address;
+    let debt = runner.faucet.xwbtc.address;
+
+    let cluster = ym_weftv2_cluster_factory.instantiate(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        supply,
+        debt,
+        weftv2.cdp,
+    );
+
+    // Link cluster to platform
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    // Get a user badge
+    platform.new_user(&mut runner, &alice_account);
+
+    (runner, platform, weftv2, cluster)
+}
+
+#[test]
+fn test_valid_account_open_with_configured_nft_collateral() {
+    //] Arrange
+    let (mut runner, platform, mut weftv2, cluster) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    // Accept the mock NFT collateral
+    let receipt = cluster.set_nft_collaterals(&mut runner, &owner_account, platform.owner_badge, indexmap! { weftv2.cdp => dec!(1) });
+    receipt.expect_commit_success();
+
+    //] Act & Assert
+    let collateral = indexmap! { cluster.supply => dec!(1000) };
+    let cdp_id = weftv2.mint(&mut runner, alice_account, Some(collateral), None, true);
+
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_account_open_after_nft_collateral_removed() {
+    //] Arrange
+    let (mut runner, platform, mut weftv2, cluster) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    // Accept, then remove the mock NFT collateral
+    let receipt = cluster.set_nft_collaterals(&mut runner, &owner_account, platform.owner_badge, indexmap! { weftv2.cdp => dec!(1) });
+    receipt.expect_commit_success();
+
+    let receipt = cluster.set_nft_collaterals(&mut runner, &owner_account, platform.owner_badge, indexmap! {});
+    receipt.expect_commit_success();
+
+    //] Act & Assert
+    let cdp_id = weftv2.mint(&mut runner, alice_account, None, None, true);
+
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+}
+
+#[test]
+fn test_invalid_nft_collateral_weight_cap() {
+    //] Arrange
+    let (mut runner, platform, weftv2, cluster) = arrange();
+    let owner_account = runner.owner_account;
+
+    //] Act
+    let receipt = cluster.set_nft_collaterals(&mut runner, &owner_account, platform.owner_badge, indexmap! { weftv2.cdp => dec!(1.5) });
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_unauthorised_set_nft_collaterals() {
+    //] Arrange
+    let (mut runner, platform, weftv2, cluster) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = cluster.set_nft_collaterals(&mut runner, &alice_account, platform.owner_badge, indexmap! { weftv2.cdp => dec!(1) });
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
@@ -0,0 +1,159 @@
+use crate::helpers::prelude::*;
+use crate::helpers::{
+    clusters::yield_multiplier_weftv2::{arrange_unwind, arrange_with_account, YMWeftV2Cluster, YMWeftV2LeverageMode, YMWeftV2OracleSource},
+    integrations::mocks::{MockOracle, MocksFactory},
+};
+use scrypto_test::prelude::*;
+
+#[test]
+fn test_valid_set_oracle() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let owner_account = runner.owner_account;
+
+    let sources = vec![
+        YMWeftV2OracleSource::Root(platform.component),
+        YMWeftV2OracleSource::Root(cluster.weft_market),
+    ];
+
+    //] Act
+    let receipt = cluster.set_oracle(&mut runner, &owner_account, platform.owner_badge, sources, 600, dec!(0.02));
+    receipt.expect_commit_success();
+
+    // Removing every source removes the oracle
+    let receipt = cluster.set_oracle(&mut runner, &owner_account, platform.owner_badge, vec![], 0, dec!(0));
+
+    //] Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_invalid_set_oracle_with_negative_staleness() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let owner_account = runner.owner_account;
+
+    //] Act
+    let sources = vec![YMWeftV2OracleSource::Root(platform.component)];
+    let receipt = cluster.set_oracle(&mut runner, &owner_account, platform.owner_badge, sources, -1, dec!(0.02));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_unauthorised_set_oracle() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let sources = vec![YMWeftV2OracleSource::Root(platform.component)];
+    let receipt = cluster.set_oracle(&mut runner, &alice_account, platform.owner_badge, sources, 600, dec!(0.02));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_execute_trigger_with_unavailable_oracle() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange_with_account(dec!(0));
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+
+    let sources = vec![YMWeftV2OracleSource::Root(platform.component)];
+    let receipt = cluster.set_oracle(&mut runner, &owner_account, platform.owner_badge, sources, 600, dec!(0.02));
+    receipt.expect_commit_success();
+
+    let receipt = cluster.set_triggers(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), Some(dec!(0.8)), None);
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = cluster.execute_trigger(&mut runner, &bob_account, NonFungibleLocalId::Integer(0.into()), cluster.debt, dec!(0));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+/// Opens a long account for Alice with a stop-loss at 0.8, priced by a Root-style oracle guarded by a second one,
+/// and drops the supply asset's price to 0.8 on both.
+fn arrange_guarded() -> (Runner, YMWeftV2Cluster, MockOracle) {
+    let (mut runner, platform, cluster, prices) = arrange_unwind(YMWeftV2LeverageMode::Long);
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+    let usdt = runner.faucet.usdt.address;
+    let usdc = runner.faucet.usdc.address;
+
+    let mocks_factory = MocksFactory::new(&mut runner.ledger);
+    let guard = mocks_factory.instantiate_oracle(&mut runner);
+    guard.set_price(&mut runner, usdt, dec!(0.8), None);
+    guard.set_price(&mut runner, usdc, dec!(1), None);
+    prices.set_price(&mut runner, usdt, dec!(0.8));
+
+    let sources = vec![
+        YMWeftV2OracleSource::Root(prices.oracle.component),
+        YMWeftV2OracleSource::Root(guard.component),
+    ];
+    let receipt = cluster.set_oracle(&mut runner, &owner_account, platform.owner_badge, sources, 600, dec!(0.02));
+    receipt.expect_commit_success();
+
+    let receipt = cluster.set_triggers(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), Some(dec!(0.8)), None);
+    receipt.expect_commit_success();
+
+    runner.faucet.mint(&mut runner.ledger, usdc, bob_account, dec!(500));
+
+    (runner, cluster, prices.oracle)
+}
+
+#[test]
+fn test_valid_execute_trigger_with_guarded_oracle() {
+    //] Arrange
+    let (mut runner, cluster, _) = arrange_guarded();
+    let bob_account = runner.bob_account;
+    let usdc = runner.faucet.usdc.address;
+
+    //] Act
+    let receipt = cluster.execute_trigger(&mut runner, &bob_account, NonFungibleLocalId::Integer(0.into()), usdc, dec!(500));
+
+    //] Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_invalid_execute_trigger_with_stale_oracle() {
+    //] Arrange
+    let (mut runner, cluster, _) = arrange_guarded();
+    let bob_account = runner.bob_account;
+    let usdc = runner.faucet.usdc.address;
+
+    // The oracles' prices age past the maximum staleness
+    let now = runner.ledger.get_current_time(TimePrecisionV2::Second).seconds_since_unix_epoch;
+    runner.ledger.advance_to_round_at_timestamp(Round::of(1), (now + 3600) * 1_000);
+
+    //] Act
+    let receipt = cluster.execute_trigger(&mut runner, &bob_account, NonFungibleLocalId::Integer(0.into()), usdc, dec!(500));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_execute_trigger_with_deviating_oracle() {
+    //] Arrange
+    let (mut runner, cluster, oracle) = arrange_guarded();
+    let bob_account = runner.bob_account;
+    let usdt = runner.faucet.usdt.address;
+    let usdc = runner.faucet.usdc.address;
+
+    // The primary falls past the stop-loss, but deviates from the guard by over 14%
+    oracle.set_price(&mut runner, usdt, dec!(0.7), None);
+
+    //] Act
+    let receipt = cluster.execute_trigger(&mut runner, &bob_account, NonFungibleLocalId::Integer(0.into()), usdc, dec!(500));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
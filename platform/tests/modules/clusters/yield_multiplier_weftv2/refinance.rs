@@ -0,0 +1,254 @@
+use crate::helpers::prelude::*;
+use crate::helpers::{
+    clusters::{
+        yield_multiplier_root::{YMRootCluster, YMRootClusterFactory},
+        yield_multiplier_weftv2::{YMWeftV2Cluster, YMWeftV2ClusterFactory},
+    },
+    flash_loans::{FlashLoanPool, LiquidityInfo},
+    integrations::{mocks::MockWeftMarket, weftv2::MockWeftV2},
+    platform::Platform,
+};
+use scrypto_test::prelude::*;
+
+/// Instantiates two clusters over the same pair, linking only the first, and opens an account for Alice on it.
+fn arrange() -> (Runner, Platform, MockWeftV2, YMWeftV2Cluster, YMWeftV2Cluster) {
+    // Create a test runner and platform
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    // Instantiate two YieldMultiplierWeftClusters
+    let mut weftv2 = MockWeftV2::new(&mut runner);
+    let ym_weftv2_cluster_factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let supply = runner.faucet.usdt.address;
+    let debt = runner.faucet.xwbtc.address;
+
+    let from_cluster = ym_weftv2_cluster_factory.instantiate(
+        &mut runner,
+        owner_rule.clone(),
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        supply,
+        debt,
+        weftv2.cdp,
+    );
+    let to_cluster = ym_weftv2_cluster_factory.instantiate(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        supply,
+        debt,
+        weftv2.cdp,
+    );
+
+    // Link the first cluster to the platform
+    platform.link(&mut runner, &owner_account, from_cluster.component);
+
+    // Get a user badge and open an account
+    platform.new_user(&mut runner, &alice_account);
+
+    let cdp_id = weftv2.mint_empty(&mut runner, alice_account);
+    from_cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+
+    (runner, platform, weftv2, from_cluster, to_cluster)
+}
+
+#[test]
+fn test_unauthorised_import_account() {
+    //] Arrange
+    let (mut runner, _platform, mut weftv2, from_cluster, _to_cluster) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let cdp_id = weftv2.mint_empty(&mut runner, alice_account);
+    let receipt = from_cluster.import_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_refinance_to_unlinked_cluster() {
+    //] Arrange
+    let (mut runner, platform, mut weftv2, from_cluster, to_cluster) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let cdp_id = weftv2.mint_empty(&mut runner, alice_account);
+    let receipt = platform.refinance(
+        &mut runner,
+        &alice_account,
+        NonFungibleLocalId::Integer(0.into()),
+        from_cluster.component,
+        to_cluster.component,
+        weftv2.cdp,
+        cdp_id,
+    );
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_refinance_into_same_cluster() {
+    //] Arrange
+    let (mut runner, platform, mut weftv2, from_cluster, _to_cluster) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let cdp_id = weftv2.mint_empty(&mut runner, alice_account);
+    let receipt = platform.refinance(
+        &mut runner,
+        &alice_account,
+        NonFungibleLocalId::Integer(0.into()),
+        from_cluster.component,
+        from_cluster.component,
+        weftv2.cdp,
+        cdp_id,
+    );
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+/// Instantiates and links a Root cluster and a Weft cluster over USDT and USDC, both lending out 500 USDC, and opens an
+/// account for Alice on the Root cluster, supplying 1000 USDT and borrowing 500 USDC. Deploys the platform's pool, free
+/// for the platform, with `liquidity` USDC.
+fn arrange_from_root(liquidity: Decimal) -> (Runner, Platform, FlashLoanPool, MockWeftV2, YMRootCluster, YMWeftV2Cluster) {
+    // Create a test runner and platform
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    // Instantiate a YieldMultiplierRootCluster and a YieldMultiplierWeftCluster over the same pair
+    let weftv2 = MockWeftV2::new(&mut runner);
+    let ym_root_cluster_factory = YMRootClusterFactory::new(&mut runner.ledger);
+    let ym_weftv2_cluster_factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let supply = runner.faucet.usdt.address;
+    let debt = runner.faucet.usdc.address;
+
+    let (from_cluster, mut root_market) = ym_root_cluster_factory.instantiate(
+        &mut runner,
+        owner_rule.clone(),
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        supply,
+        debt,
+    );
+    let to_cluster = ym_weftv2_cluster_factory.instantiate(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        supply,
+        debt,
+        weftv2.cdp,
+    );
+
+    // Link both clusters to the platform
+    platform.link(&mut runner, &owner_account, from_cluster.component);
+    platform.link(&mut runner, &owner_account, to_cluster.component);
+
+    // Lend out the debt asset on both markets
+    root_market.deposit_liquidity(&mut runner, debt, dec!(500));
+
+    runner.faucet.mint(&mut runner.ledger, debt, owner_account, dec!(500));
+    MockWeftMarket { component: to_cluster.weft_market }.deposit_liquidity(&mut runner, &owner_account, debt, dec!(500));
+
+    // Deploy the platform's pool
+    platform.new_admin_badge(&mut runner, &owner_account);
+    let pool = FlashLoanPool::deploy(&mut runner, &platform, dec!(0.001));
+
+    let receipt = pool.add_resource(&mut runner, &owner_account, platform.admin_badge, debt);
+    receipt.expect_commit_success();
+
+    if liquidity > dec!(0) {
+        runner.faucet.mint(&mut runner.ledger, debt, owner_account, liquidity);
+        let receipt = pool.deposit(&mut runner, &owner_account, debt, liquidity);
+        receipt.expect_commit_success();
+    }
+
+    // Get a user badge and open an account on Root
+    platform.new_user(&mut runner, &alice_account);
+
+    let cdp_id = root_market.create_cdp(&mut runner, &alice_account, supply, dec!(1000), debt, dec!(500));
+    from_cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+
+    (runner, platform, pool, weftv2, from_cluster, to_cluster)
+}
+
+#[test]
+fn test_valid_refinance_from_root() {
+    //] Arrange
+    let (mut runner, platform, pool, mut weftv2, from_cluster, to_cluster) = arrange_from_root(dec!(500));
+    let alice_account = runner.alice_account;
+    let user_id = NonFungibleLocalId::Integer(0.into());
+
+    //] Act
+    // Refinance onto an empty Weft CDP, without a repayment of her own; the Root debt is flash borrowed from the pool
+    let cdp_id = weftv2.mint_empty(&mut runner, alice_account);
+    let receipt = platform.refinance(
+        &mut runner,
+        &alice_account,
+        user_id.clone(),
+        from_cluster.component,
+        to_cluster.component,
+        weftv2.cdp,
+        cdp_id,
+    );
+
+    //] Assert
+    receipt.expect_commit_success();
+
+    // The debt is re-borrowed on Weft, against the collateral withdrawn from Root
+    let info = to_cluster.get_account_info(&mut runner, &alice_account, user_id.clone());
+    assert_eq!(info.supply, dec!(1000));
+    assert_eq!(info.debt, dec!(500));
+
+    // Alice holds the emptied Root CDP, and only the USDC she borrowed on Root
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, from_cluster.cdp), dec!(1));
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, from_cluster.debt), dec!(500));
+
+    // The flash loan is repaid to the pool
+    let receipt = pool.get_liquidity(&mut runner, &alice_account, from_cluster.debt);
+    let liquidity: LiquidityInfo = receipt.expect_commit_success().output(1);
+    assert_eq!(liquidity.available, dec!(500));
+    assert_eq!(liquidity.outstanding, dec!(0));
+
+    // The account is moved from the Root cluster to the Weft cluster on the user's badge
+    let user = platform.get_user(&mut runner, user_id);
+    assert_eq!(user.accounts_in, indexset! { to_cluster.component });
+    assert_eq!(user.open, 1);
+}
+
+#[test]
+fn test_invalid_refinance_from_root_without_pool_liquidity() {
+    //] Arrange
+    // The pool holds only half of the Root debt
+    let (mut runner, platform, _, mut weftv2, from_cluster, to_cluster) = arrange_from_root(dec!(250));
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let cdp_id = weftv2.mint_empty(&mut runner, alice_account);
+    let receipt = platform.refinance(
+        &mut runner,
+        &alice_account,
+        NonFungibleLocalId::Integer(0.into()),
+        from_cluster.component,
+        to_cluster.component,
+        weftv2.cdp,
+        cdp_id,
+    );
+
+    //] Assert
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Insufficient available liquidity"));
+}
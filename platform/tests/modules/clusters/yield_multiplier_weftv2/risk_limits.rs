@@ -0,0 +1,305 @@
+use crate::helpers::prelude::*;
+use crate::helpers::{
+    clusters::yield_multiplier_weftv2::{YMWeftV2Cluster, YMWeftV2ClusterFactory},
+    integrations::weftv2::MockWeftV2,
+    platform::Platform,
+};
+use scrypto_test::prelude::*;
+
+/// Instantiates and links a cluster, and gives Alice a user badge.
+fn arrange() -> (Runner, Platform, YMWeftV2Cluster, MockWeftV2) {
+    // Create a test runner and platform
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    // Instantiate a YieldMultiplierWeftCluster
+    let weftv2 = MockWeftV2::new(&mut runner);
+    let ym_weftv2_cluster_factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let supply = runner.faucet.usdt.address;
+    let debt = runner.faucet.xwbtc.address;
+
+    let cluster = ym_weftv2_cluster_factory.instantiate(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        supply,
+        debt,
+        weftv2.cdp,
+    );
+
+    // Link cluster to platform
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    // Get a user badge
+    platform.new_user(&mut runner, &alice_account);
+
+    (runner, platform, cluster, weftv2)
+}
+
+#[test]
+fn test_account_open_within_risk_limits() {
+    //] Arrange
+    let (mut runner, platform, cluster, mut weftv2) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    let receipt = cluster.set_risk_limits(
+        &mut runner,
+        &owner_account,
+        platform.owner_badge,
+        Some(1),
+        Some(dec!(1000000)),
+        Some(dec!(100)),
+        Some(dec!(10000)),
+        Some(dec!(10)),
+        Some(dec!(1000)),
+    );
+    receipt.expect_commit_success();
+
+    //] Act & Assert
+    let collateral = indexmap! { runner.faucet.usdt.address => dec!(1000) };
+    let loan = indexmap! { runner.faucet.xwbtc.address => dec!(100) };
+    let cdp_id = weftv2.mint(&mut runner, alice_account, Some(collateral), Some(loan), false);
+
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+}
+
+#[test]
+fn test_execution_of_empty_account_within_risk_limits() {
+    //] Arrange
+    let (mut runner, platform, cluster, mut weftv2) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    let receipt = cluster.set_risk_limits(
+        &mut runner,
+        &owner_account,
+        platform.owner_badge,
+        None,
+        Some(dec!(0)),
+        Some(dec!(100)),
+        None,
+        Some(dec!(10)),
+        None,
+    );
+    receipt.expect_commit_success();
+
+    // An empty CDP is exempt from the minimums
+    let cdp_id = weftv2.mint_empty(&mut runner, alice_account);
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+
+    //] Act
+    let receipt = cluster.execute(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+
+    //] Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_execution_of_valued_account_within_max_total_collateral_value() {
+    //] Arrange
+    let (mut runner, platform, cluster, mut weftv2) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    let receipt = cluster.set_risk_limits(
+        &mut runner,
+        &owner_account,
+        platform.owner_badge,
+        None,
+        Some(dec!(1500)),
+        None,
+        None,
+        None,
+        None,
+    );
+    receipt.expect_commit_success();
+
+    // The CDP is valued and counted towards the cluster's total on open
+    let collateral = indexmap! { runner.faucet.usdt.address => dec!(1000) };
+    let cdp_id = weftv2.mint(&mut runner, alice_account, Some(collateral), None, false);
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+
+    //] Act
+    let receipt = cluster.execute(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+
+    //] Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_account_open_past_max_total_collateral_value() {
+    //] Arrange
+    let (mut runner, platform, cluster, mut weftv2) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+
+    let receipt = cluster.set_risk_limits(
+        &mut runner,
+        &owner_account,
+        platform.owner_badge,
+        None,
+        Some(dec!(1500)),
+        None,
+        None,
+        None,
+        None,
+    );
+    receipt.expect_commit_success();
+
+    let collateral = indexmap! { runner.faucet.usdt.address => dec!(1000) };
+    let cdp_id = weftv2.mint(&mut runner, alice_account, Some(collateral.clone()), None, false);
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+
+    //] Act & Assert
+    platform.new_user(&mut runner, &bob_account);
+
+    let cdp_id = weftv2.mint(&mut runner, bob_account, Some(collateral), None, false);
+    cluster.open_account(&mut runner, &bob_account, NonFungibleLocalId::Integer(1.into()), cdp_id);
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_account_open_past_max_accounts() {
+    //] Arrange
+    let (mut runner, platform, cluster, mut weftv2) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+
+    let receipt = cluster.set_risk_limits(&mut runner, &owner_account, platform.owner_badge, Some(1), None, None, None, None, None);
+    receipt.expect_commit_success();
+
+    let cdp_id = weftv2.mint_empty(&mut runner, alice_account);
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+
+    //] Act & Assert
+    platform.new_user(&mut runner, &bob_account);
+
+    let cdp_id = weftv2.mint_empty(&mut runner, bob_account);
+    cluster.open_account(&mut runner, &bob_account, NonFungibleLocalId::Integer(1.into()), cdp_id);
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_account_open_above_max_collateral() {
+    //] Arrange
+    let (mut runner, platform, cluster, mut weftv2) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    let receipt = cluster.set_risk_limits(
+        &mut runner,
+        &owner_account,
+        platform.owner_badge,
+        None,
+        None,
+        None,
+        Some(dec!(500)),
+        None,
+        None,
+    );
+    receipt.expect_commit_success();
+
+    //] Act & Assert
+    let collateral = indexmap! { runner.faucet.usdt.address => dec!(1000) };
+    let cdp_id = weftv2.mint(&mut runner, alice_account, Some(collateral), None, false);
+
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_account_open_below_min_debt() {
+    //] Arrange
+    let (mut runner, platform, cluster, mut weftv2) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    let receipt = cluster.set_risk_limits(
+        &mut runner,
+        &owner_account,
+        platform.owner_badge,
+        None,
+        None,
+        None,
+        None,
+        Some(dec!(500)),
+        None,
+    );
+    receipt.expect_commit_success();
+
+    //] Act & Assert
+    let collateral = indexmap! { runner.faucet.usdt.address => dec!(1000) };
+    let loan = indexmap! { runner.faucet.xwbtc.address => dec!(100) };
+    let cdp_id = weftv2.mint(&mut runner, alice_account, Some(collateral), Some(loan), false);
+
+    cluster.open_account(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), cdp_id);
+}
+
+#[test]
+fn test_invalid_risk_limits() {
+    //] Arrange
+    let (mut runner, platform, cluster, _) = arrange();
+    let owner_account = runner.owner_account;
+
+    //] Act
+    let negative = cluster.set_risk_limits(
+        &mut runner,
+        &owner_account,
+        platform.owner_badge,
+        None,
+        Some(dec!(-1)),
+        None,
+        None,
+        None,
+        None,
+    );
+    let collateral_range = cluster.set_risk_limits(
+        &mut runner,
+        &owner_account,
+        platform.owner_badge,
+        None,
+        None,
+        Some(dec!(100)),
+        Some(dec!(10)),
+        None,
+        None,
+    );
+    let debt_range = cluster.set_risk_limits(
+        &mut runner,
+        &owner_account,
+        platform.owner_badge,
+        None,
+        None,
+        None,
+        None,
+        Some(dec!(100)),
+        Some(dec!(10)),
+    );
+
+    //] Assert
+    negative.expect_commit_failure();
+    collateral_range.expect_commit_failure();
+    debt_range.expect_commit_failure();
+}
+
+#[test]
+fn test_unauthorised_set_risk_limits() {
+    //] Arrange
+    let (mut runner, platform, cluster, _) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = cluster.set_risk_limits(&mut runner, &alice_account, platform.owner_badge, Some(1), None, None, None, None, None);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
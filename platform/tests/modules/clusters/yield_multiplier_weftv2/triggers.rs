@@ -0,0 +1,263 @@
+use crate::helpers::clusters::yield_multiplier_weftv2::{arrange_unwind, arrange_with_account, YMWeftV2LeverageMode};
+use crate::helpers::prelude::*;
+use scrypto_test::prelude::*;
+
+#[test]
+fn test_valid_set_triggers() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = cluster.set_triggers(
+        &mut runner,
+        &alice_account,
+        NonFungibleLocalId::Integer(0.into()),
+        Some(dec!(0.8)),
+        Some(dec!(1.5)),
+    );
+
+    //] Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_invalid_set_triggers_with_stop_loss_above_take_profit() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = cluster.set_triggers(
+        &mut runner,
+        &alice_account,
+        NonFungibleLocalId::Integer(0.into()),
+        Some(dec!(1.5)),
+        Some(dec!(0.8)),
+    );
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_execute_trigger_without_oracle() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+
+    let receipt = cluster.set_triggers(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), Some(dec!(0.8)), None);
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = cluster.execute_trigger(&mut runner, &bob_account, NonFungibleLocalId::Integer(0.into()), cluster.debt, dec!(0));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_claim_without_unwound_account() {
+    //] Arrange
+    let (mut runner, _platform, cluster) = arrange_with_account(dec!(0));
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = cluster.claim(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_valid_execute_trigger_on_stop_loss() {
+    //] Arrange
+    let (mut runner, _platform, cluster, prices) = arrange_unwind(YMWeftV2LeverageMode::Long);
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+    let usdt = runner.faucet.usdt.address;
+    let usdc = runner.faucet.usdc.address;
+
+    let receipt = cluster.set_triggers(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), Some(dec!(0.8)), None);
+    receipt.expect_commit_success();
+
+    prices.set_price(&mut runner, usdt, dec!(0.8));
+    runner.faucet.mint(&mut runner.ledger, usdc, bob_account, dec!(600));
+    let xrd_before = runner.ledger.get_component_balance(bob_account.address, XRD);
+
+    //] Act
+    let receipt = cluster.execute_trigger(&mut runner, &bob_account, NonFungibleLocalId::Integer(0.into()), usdc, dec!(600));
+
+    //] Assert
+    receipt.expect_commit_success();
+
+    // The keeper is reimbursed 500 USDC of repaid debt in USDT at the oracle's price, keeps the excess repayment,
+    // and is paid the default bounty out of the open fee
+    assert_eq!(runner.ledger.get_component_balance(bob_account.address, usdt), dec!(625));
+    assert_eq!(runner.ledger.get_component_balance(bob_account.address, usdc), dec!(100));
+    assert_eq!(runner.ledger.get_component_balance(bob_account.address, XRD), xrd_before + dec!(10));
+}
+
+#[test]
+fn test_valid_execute_trigger_on_take_profit() {
+    //] Arrange
+    let (mut runner, _platform, cluster, prices) = arrange_unwind(YMWeftV2LeverageMode::Long);
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+    let usdt = runner.faucet.usdt.address;
+    let usdc = runner.faucet.usdc.address;
+
+    let receipt = cluster.set_triggers(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), None, Some(dec!(1.25)));
+    receipt.expect_commit_success();
+
+    prices.set_price(&mut runner, usdt, dec!(1.25));
+    runner.faucet.mint(&mut runner.ledger, usdc, bob_account, dec!(500));
+
+    //] Act
+    let receipt = cluster.execute_trigger(&mut runner, &bob_account, NonFungibleLocalId::Integer(0.into()), usdc, dec!(500));
+
+    //] Assert
+    receipt.expect_commit_success();
+
+    assert_eq!(runner.ledger.get_component_balance(bob_account.address, usdt), dec!(400));
+}
+
+#[test]
+fn test_invalid_execute_trigger_before_price_reached() {
+    //] Arrange
+    let (mut runner, _platform, cluster, _) = arrange_unwind(YMWeftV2LeverageMode::Long);
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+    let usdc = runner.faucet.usdc.address;
+
+    let receipt = cluster.set_triggers(
+        &mut runner,
+        &alice_account,
+        NonFungibleLocalId::Integer(0.into()),
+        Some(dec!(0.8)),
+        Some(dec!(1.25)),
+    );
+    receipt.expect_commit_success();
+
+    runner.faucet.mint(&mut runner.ledger, usdc, bob_account, dec!(500));
+
+    //] Act
+    let receipt = cluster.execute_trigger(&mut runner, &bob_account, NonFungibleLocalId::Integer(0.into()), usdc, dec!(500));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_valid_claim_after_execute_trigger() {
+    //] Arrange
+    let (mut runner, _platform, cluster, prices) = arrange_unwind(YMWeftV2LeverageMode::Long);
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+    let usdt = runner.faucet.usdt.address;
+    let usdc = runner.faucet.usdc.address;
+
+    let receipt = cluster.set_triggers(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), Some(dec!(0.8)), None);
+    receipt.expect_commit_success();
+
+    prices.set_price(&mut runner, usdt, dec!(0.8));
+    runner.faucet.mint(&mut runner.ledger, usdc, bob_account, dec!(500));
+
+    let receipt = cluster.execute_trigger(&mut runner, &bob_account, NonFungibleLocalId::Integer(0.into()), usdc, dec!(500));
+    receipt.expect_commit_success();
+
+    let usdt_before = runner.ledger.get_component_balance(alice_account.address, usdt);
+
+    //] Act
+    let receipt = cluster.claim(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()));
+
+    //] Assert
+    receipt.expect_commit_success();
+
+    // The collateral left after the keeper's reimbursement, and the emptied CDP
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, usdt), usdt_before + dec!(375));
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, cluster.cdp), dec!(1));
+}
+
+#[test]
+fn test_valid_set_triggers_in_short_mode() {
+    //] Arrange
+    let (mut runner, _platform, cluster, _) = arrange_unwind(YMWeftV2LeverageMode::Short);
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = cluster.set_triggers(
+        &mut runner,
+        &alice_account,
+        NonFungibleLocalId::Integer(0.into()),
+        Some(dec!(1.5)),
+        Some(dec!(0.8)),
+    );
+
+    //] Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_invalid_set_triggers_in_short_mode_with_stop_loss_below_take_profit() {
+    //] Arrange
+    let (mut runner, _platform, cluster, _) = arrange_unwind(YMWeftV2LeverageMode::Short);
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = cluster.set_triggers(
+        &mut runner,
+        &alice_account,
+        NonFungibleLocalId::Integer(0.into()),
+        Some(dec!(0.8)),
+        Some(dec!(1.5)),
+    );
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_valid_execute_trigger_in_short_mode_on_rising_price() {
+    //] Arrange
+    let (mut runner, _platform, cluster, prices) = arrange_unwind(YMWeftV2LeverageMode::Short);
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+    let usdt = runner.faucet.usdt.address;
+
+    let receipt = cluster.set_triggers(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), Some(dec!(1.25)), None);
+    receipt.expect_commit_success();
+
+    // The supply asset rises against the debt asset, past the short position's stop-loss
+    prices.set_price(&mut runner, usdt, dec!(1.25));
+    runner.faucet.mint(&mut runner.ledger, usdt, bob_account, dec!(500));
+
+    //] Act
+    let receipt = cluster.execute_trigger(&mut runner, &bob_account, NonFungibleLocalId::Integer(0.into()), usdt, dec!(500));
+
+    //] Assert
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_invalid_execute_trigger_in_short_mode_on_falling_price() {
+    //] Arrange
+    let (mut runner, _platform, cluster, prices) = arrange_unwind(YMWeftV2LeverageMode::Short);
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+    let usdt = runner.faucet.usdt.address;
+
+    let receipt = cluster.set_triggers(&mut runner, &alice_account, NonFungibleLocalId::Integer(0.into()), Some(dec!(1.25)), None);
+    receipt.expect_commit_success();
+
+    // The supply asset falls against the debt asset, in the short position's favour
+    prices.set_price(&mut runner, usdt, dec!(0.75));
+    runner.faucet.mint(&mut runner.ledger, usdt, bob_account, dec!(500));
+
+    //] Act
+    let receipt = cluster.execute_trigger(&mut runner, &bob_account, NonFungibleLocalId::Integer(0.into()), usdt, dec!(500));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
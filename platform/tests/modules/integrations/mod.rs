@@ -0,0 +1 @@
+pub mod swap_router;
@@ -0,0 +1,161 @@
+use crate::helpers::{
+    integrations::{
+        mocks::MocksFactory,
+        swap_router::{SwapPoolInterface, SwapRoute, SwapRouteLeg, SwapRouter, SwapRouterFactory},
+    },
+    platform::Platform,
+    prelude::*,
+};
+use scrypto_test::prelude::*;
+
+/// Instantiates a router, and two constant-product pools routing USDT through USDC to xwBTC; USDT/USDC at 1, and
+/// xwBTC/USDC at 100,000, deep enough that 1,000 USDT swaps for just under 0.01 xwBTC. Alice is funded with 1,000 USDT.
+fn arrange() -> (Runner, Platform, SwapRouter, Vec<SwapRouteLeg>) {
+    let (mut runner, platform) = Runner::new_base();
+    let alice_account = runner.alice_account;
+
+    let usdt = runner.faucet.usdt.address;
+    let usdc = runner.faucet.usdc.address;
+    let xwbtc = runner.faucet.xwbtc.address;
+
+    // Instantiate the router and its pools
+    let factory = SwapRouterFactory::new(&mut runner.ledger);
+    let router = factory.instantiate(&mut runner, rule!(require(platform.owner_badge)));
+
+    let mocks = MocksFactory::new(&mut runner.ledger);
+    let stable_pool = mocks.instantiate_dex(&mut runner, usdt, dec!(10000000), usdc, dec!(10000000)).component;
+    let btc_pool = mocks.instantiate_dex(&mut runner, xwbtc, dec!(100), usdc, dec!(10000000)).component;
+
+    let legs = vec![
+        SwapRouteLeg {
+            pool: stable_pool,
+            interface: SwapPoolInterface::Ociswap,
+            input: usdt,
+            output: usdc,
+        },
+        SwapRouteLeg {
+            pool: btc_pool,
+            interface: SwapPoolInterface::Ociswap,
+            input: usdc,
+            output: xwbtc,
+        },
+    ];
+
+    // Fund Alice
+    runner.faucet.mint(&mut runner.ledger, usdt, alice_account, dec!(1000));
+
+    (runner, platform, router, legs)
+}
+
+#[test]
+fn test_valid_swap_along_route() {
+    //] Arrange
+    let (mut runner, platform, router, legs) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let usdt = runner.faucet.usdt.address;
+    let xwbtc = runner.faucet.xwbtc.address;
+
+    let receipt = router.set_route(&mut runner, &owner_account, platform.owner_badge, legs);
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = router.swap(&mut runner, &alice_account, usdt, dec!(1000), xwbtc, dec!(0.0099));
+
+    //] Assert
+    receipt.expect_commit_success();
+
+    let output = runner.ledger.get_component_balance(alice_account.address, xwbtc);
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, usdt), dec!(0));
+    assert!(output >= dec!(0.0099) && output < dec!(0.01), "Unexpected swap output: {}", output);
+}
+
+#[test]
+fn test_valid_get_route() {
+    //] Arrange
+    let (mut runner, platform, router, legs) = arrange();
+    let owner_account = runner.owner_account;
+    let usdt = runner.faucet.usdt.address;
+    let xwbtc = runner.faucet.xwbtc.address;
+
+    let receipt = router.set_route(&mut runner, &owner_account, platform.owner_badge, legs.clone());
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = router.get_route(&mut runner, &owner_account, usdt, xwbtc);
+
+    //] Assert
+    let route: Option<SwapRoute> = receipt.expect_commit_success().output(1);
+
+    assert_eq!(route, Some(SwapRoute { legs }));
+}
+
+#[test]
+fn test_invalid_swap_below_min_output() {
+    //] Arrange
+    let (mut runner, platform, router, legs) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let usdt = runner.faucet.usdt.address;
+    let xwbtc = runner.faucet.xwbtc.address;
+
+    let receipt = router.set_route(&mut runner, &owner_account, platform.owner_badge, legs);
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = router.swap(&mut runner, &alice_account, usdt, dec!(1000), xwbtc, dec!(0.01));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_swap_without_route() {
+    //] Arrange
+    let (mut runner, platform, router, legs) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let usdt = runner.faucet.usdt.address;
+    let xwbtc = runner.faucet.xwbtc.address;
+
+    let receipt = router.set_route(&mut runner, &owner_account, platform.owner_badge, legs);
+    receipt.expect_commit_success();
+
+    let receipt = router.remove_route(&mut runner, &owner_account, platform.owner_badge, usdt, xwbtc);
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = router.swap(&mut runner, &alice_account, usdt, dec!(1000), xwbtc, dec!(0));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_set_route_with_gap() {
+    //] Arrange
+    let (mut runner, platform, router, mut legs) = arrange();
+    let owner_account = runner.owner_account;
+
+    // Point the first leg at another resource, so the legs no longer join up
+    legs[0].output = runner.faucet.hug.address;
+
+    //] Act
+    let receipt = router.set_route(&mut runner, &owner_account, platform.owner_badge, legs);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_unauthorised_set_route() {
+    //] Arrange
+    let (mut runner, platform, router, legs) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = router.set_route(&mut runner, &alice_account, platform.owner_badge, legs);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
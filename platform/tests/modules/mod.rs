@@ -1,2 +1,3 @@
 pub mod clusters;
+pub mod integrations;
 pub mod platform;
@@ -0,0 +1,84 @@
+use crate::helpers::{
+    clusters::yield_multiplier_weftv2::{YMWeftV2Cluster, YMWeftV2ClusterFactory},
+    integrations::weftv2::MockWeftV2,
+    platform::Platform,
+    prelude::*,
+};
+use scrypto_test::prelude::*;
+
+/// Instantiates and links a USDT/xwBTC WeftV2 cluster.
+fn arrange() -> (Runner, Platform, YMWeftV2Cluster) {
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+
+    let weftv2 = MockWeftV2::new(&mut runner);
+    let factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let cluster = factory.instantiate(
+        &mut runner,
+        rule!(require(platform.owner_badge)),
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        runner.faucet.usdt.address,
+        runner.faucet.xwbtc.address,
+        weftv2.cdp,
+    );
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    (runner, platform, cluster)
+}
+
+#[test]
+fn test_valid_cluster_inherits_dapp_definition() {
+    //] Arrange & Act
+    let (runner, _, cluster) = arrange();
+    let owner_account = runner.owner_account;
+
+    //] Assert
+    let dapp_definition = Some(MetadataValue::GlobalAddress(owner_account.address.into()));
+
+    assert_eq!(runner.ledger.get_metadata(cluster.component.into(), "dapp_definition"), dapp_definition);
+    assert_eq!(
+        runner.ledger.get_metadata(cluster.execution_terms.into(), "dapp_definition"),
+        dapp_definition
+    );
+}
+
+#[test]
+fn test_valid_get_claimed_entities() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = platform.get_claimed_entities(&mut runner, &alice_account);
+
+    //] Assert
+    let entities: Vec<GlobalAddress> = receipt.expect_commit_success().output(1);
+
+    assert!(entities.contains(&platform.component.into()));
+    assert!(entities.contains(&platform.user_badge.into()));
+    assert!(entities.contains(&platform.link_badge.into()));
+    assert!(entities.contains(&cluster.component.into()));
+    assert!(entities.contains(&cluster.execution_terms.into()));
+}
+
+#[test]
+fn test_valid_get_claimed_entities_excludes_unlinked_clusters() {
+    //] Arrange
+    let (mut runner, platform, cluster) = arrange();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    platform.unlink(&mut runner, &owner_account, cluster.component);
+
+    //] Act
+    let receipt = platform.get_claimed_entities(&mut runner, &alice_account);
+
+    //] Assert
+    let entities: Vec<GlobalAddress> = receipt.expect_commit_success().output(1);
+
+    assert!(entities.contains(&platform.component.into()));
+    assert!(!entities.contains(&cluster.component.into()));
+}
@@ -0,0 +1,218 @@
+use crate::helpers::{
+    clusters::yield_multiplier_weftv2::YMWeftV2ClusterFactory,
+    flash_loans::{FlashLoanPool, LiquidityInfo},
+    integrations::weftv2::MockWeftV2,
+    platform::Platform,
+    prelude::*,
+};
+use scrypto_test::prelude::*;
+
+/// Deploys the platform's pool charging 0.1% on flash loans, into which Alice deposits 1,000 USDT; the owner holds an
+/// admin badge.
+fn arrange() -> (Runner, Platform, FlashLoanPool, ResourceAddress) {
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let usdt = runner.faucet.usdt.address;
+
+    platform.new_admin_badge(&mut runner, &owner_account);
+    let pool = FlashLoanPool::deploy(&mut runner, &platform, dec!(0.001));
+
+    let receipt = pool.add_resource(&mut runner, &owner_account, platform.admin_badge, usdt);
+    let pool_units = receipt.expect_commit_success().new_resource_addresses()[0];
+
+    runner.faucet.mint(&mut runner.ledger, usdt, alice_account, dec!(1000));
+    let receipt = pool.deposit(&mut runner, &alice_account, usdt, dec!(1000));
+    receipt.expect_commit_success();
+
+    (runner, platform, pool, pool_units)
+}
+
+#[test]
+fn test_invalid_deploy_flash_loan_pool_twice() {
+    //] Arrange
+    let (mut runner, platform, _, _) = arrange();
+
+    //] Act
+    let receipt = FlashLoanPool::try_deploy(&mut runner, &platform, dec!(0.001));
+
+    //] Assert
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Flash loan pool already deployed"));
+}
+
+#[test]
+fn test_valid_set_fee_rates_with_admin_badge() {
+    //] Arrange
+    let (mut runner, platform, pool, _) = arrange();
+    let owner_account = runner.owner_account;
+    let bob_account = runner.bob_account;
+    let usdt = runner.faucet.usdt.address;
+
+    //] Act
+    let receipt = pool.set_fee_rates(&mut runner, &owner_account, platform.admin_badge, dec!(0.002), dec!(0));
+
+    //] Assert
+    receipt.expect_commit_success();
+
+    let receipt = pool.flash_loan(&mut runner, &bob_account, usdt, dec!(500), dec!(0.5));
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Insufficient flash loan repayment"));
+}
+
+#[test]
+fn test_valid_deposit_and_withdraw() {
+    //] Arrange
+    let (mut runner, _, pool, pool_units) = arrange();
+    let alice_account = runner.alice_account;
+    let usdt = runner.faucet.usdt.address;
+
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, pool_units), dec!(1000));
+
+    //] Act
+    let receipt = pool.withdraw(&mut runner, &alice_account, pool_units, dec!(1000));
+
+    //] Assert
+    receipt.expect_commit_success();
+
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, pool_units), dec!(0));
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, usdt), dec!(1000));
+}
+
+#[test]
+fn test_valid_flash_loan_fee_accrues_to_lps() {
+    //] Arrange
+    let (mut runner, _, pool, pool_units) = arrange();
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+    let usdt = runner.faucet.usdt.address;
+
+    //] Act
+    let receipt = pool.flash_loan(&mut runner, &bob_account, usdt, dec!(500), dec!(0.5));
+    receipt.expect_commit_success();
+
+    //] Assert
+    let receipt = pool.get_liquidity(&mut runner, &alice_account, usdt);
+    let liquidity: LiquidityInfo = receipt.expect_commit_success().output(1);
+
+    assert_eq!(liquidity.available, dec!(1000.5));
+    assert_eq!(liquidity.outstanding, dec!(0));
+
+    let receipt = pool.withdraw(&mut runner, &alice_account, pool_units, dec!(1000));
+    receipt.expect_commit_success();
+
+    assert_eq!(runner.ledger.get_component_balance(alice_account.address, usdt), dec!(1000.5));
+}
+
+#[test]
+fn test_invalid_flash_loan_underpaid() {
+    //] Arrange
+    let (mut runner, _, pool, _) = arrange();
+    let bob_account = runner.bob_account;
+    let usdt = runner.faucet.usdt.address;
+
+    //] Act
+    let receipt = pool.flash_loan(&mut runner, &bob_account, usdt, dec!(500), dec!(0.4));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_flash_loan_unrepaid() {
+    //] Arrange
+    let (mut runner, _, pool, _) = arrange();
+    let bob_account = runner.bob_account;
+    let usdt = runner.faucet.usdt.address;
+
+    //] Act
+    let receipt = pool.take_flash_loan(&mut runner, &bob_account, usdt, dec!(500));
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_linked_flash_loan_without_link_badge() {
+    //] Arrange
+    let (mut runner, platform, pool, _) = arrange();
+    let bob_account = runner.bob_account;
+    let usdt = runner.faucet.usdt.address;
+
+    platform.new_user(&mut runner, &bob_account);
+
+    //] Act
+    let receipt = pool.take_linked_flash_loan(
+        &mut runner,
+        &bob_account,
+        platform.user_badge,
+        NonFungibleLocalId::Integer(0.into()),
+        usdt,
+        dec!(500),
+    );
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_linked_flash_loan_with_unlinked_cluster_badge() {
+    //] Arrange
+    let (mut runner, platform, pool, _) = arrange();
+    let owner_account = runner.owner_account;
+    let usdt = runner.faucet.usdt.address;
+
+    // Link a cluster, recall its link badge, and unlink it
+    let weftv2 = MockWeftV2::new(&mut runner);
+    let factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let cluster = factory.instantiate(
+        &mut runner,
+        rule!(require(platform.owner_badge)),
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        usdt,
+        runner.faucet.xwbtc.address,
+        weftv2.cdp,
+    );
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    let vaults = runner.ledger.get_component_vaults(cluster.component, platform.link_badge);
+    let vault = InternalAddress::new_or_panic(vaults[0].into());
+
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account.address, platform.owner_badge, dec!(1))
+        .recall(vault, 1)
+        .deposit_entire_worktop(owner_account.address);
+    let receipt = runner.exec_and_dump("recall_link_badge", manifest, &owner_account, None);
+    receipt.expect_commit_success();
+
+    platform.unlink(&mut runner, &owner_account, cluster.component);
+
+    //] Act
+    let receipt = pool.take_linked_flash_loan(
+        &mut runner,
+        &owner_account,
+        platform.link_badge,
+        NonFungibleLocalId::Integer(0.into()),
+        usdt,
+        dec!(500),
+    );
+
+    //] Assert
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Cluster no longer linked"));
+}
+
+#[test]
+fn test_unauthorised_add_resource() {
+    //] Arrange
+    let (mut runner, platform, pool, _) = arrange();
+    let alice_account = runner.alice_account;
+    let usdc = runner.faucet.usdc.address;
+
+    //] Act
+    let receipt = pool.add_resource(&mut runner, &alice_account, platform.admin_badge, usdc);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
@@ -0,0 +1,284 @@
+use crate::helpers::{
+    clusters::{yield_multiplier_root::YMRootClusterFactory, yield_multiplier_weftv2::YMWeftV2ClusterFactory},
+    integrations::weftv2::MockWeftV2,
+    platform::{PlatformClusterService, PlatformService},
+    prelude::*,
+};
+use scrypto_test::prelude::*;
+
+#[test]
+fn test_valid_pause_and_unpause() {
+    //] Arrange
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    //] Act & Assert
+    platform.pause_all(&mut runner, &owner_account);
+    platform.unpause_all(&mut runner, &owner_account);
+
+    // Services are restored; a new user badge can be minted
+    platform.new_user(&mut runner, &alice_account);
+
+    let alice_resources = runner.ledger.get_component_resources(alice_account.address);
+
+    assert_eq!(alice_resources.len(), 1);
+}
+
+#[test]
+fn test_valid_pause_skips_unlinked_clusters() {
+    //] Arrange
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+
+    // Link two clusters, and unlink the first
+    let weftv2 = MockWeftV2::new(&mut runner);
+    let factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let supply = runner.faucet.usdt.address;
+    let debt = runner.faucet.xwbtc.address;
+
+    for _ in 0..2 {
+        let cluster = factory.instantiate(
+            &mut runner,
+            owner_rule.clone(),
+            platform.component,
+            platform.link_badge,
+            platform.user_badge,
+            supply,
+            debt,
+            weftv2.cdp,
+        );
+        platform.link(&mut runner, &owner_account, cluster.component);
+    }
+
+    let unlinked = platform.get_link(&mut runner, NonFungibleLocalId::Integer(0.into())).linked_cluster;
+    platform.unlink(&mut runner, &owner_account, unlinked);
+
+    //] Act
+    platform.pause_all(&mut runner, &owner_account);
+
+    //] Assert
+    let stale_link = platform.get_link(&mut runner, NonFungibleLocalId::Integer(0.into()));
+    let link = platform.get_link(&mut runner, NonFungibleLocalId::Integer(1.into()));
+
+    assert!(!stale_link.paused, "Link badge of the unlinked cluster was paused");
+    assert!(link.paused, "Link badge of the linked cluster was not paused");
+}
+
+#[test]
+fn test_valid_pause_halts_ym_weftv2_cluster() {
+    //] Arrange
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+
+    // Instantiate and link a YieldMultiplierWeftV2Cluster
+    let mut weftv2 = MockWeftV2::new(&mut runner);
+    let factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let supply = runner.faucet.usdt.address;
+    let debt = runner.faucet.xwbtc.address;
+
+    let cluster = factory.instantiate(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        supply,
+        debt,
+        weftv2.cdp,
+    );
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    // Alice opens an account on the cluster, and Bob gets a user badge and a CDP with which to open one
+    platform.new_user(&mut runner, &alice_account);
+    platform.new_user(&mut runner, &bob_account);
+
+    let alice_id = NonFungibleLocalId::Integer(0.into());
+    let bob_id = NonFungibleLocalId::Integer(1.into());
+
+    let alice_cdp = weftv2.mint(&mut runner, alice_account, Some(indexmap! { supply => dec!(1000) }), None, false);
+    let bob_cdp = weftv2.mint_empty(&mut runner, bob_account);
+    cluster.open_account(&mut runner, &alice_account, alice_id.clone(), alice_cdp);
+
+    //] Act & Assert
+    // Every account operation halts while the platform is paused
+    platform.pause_all(&mut runner, &owner_account);
+
+    let receipt = cluster.try_open_account(&mut runner, &bob_account, bob_id.clone(), bob_cdp.clone());
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Platform paused"));
+    let receipt = cluster.execute(&mut runner, &alice_account, alice_id.clone());
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Platform paused"));
+    let receipt = cluster.close_account(&mut runner, &alice_account, alice_id.clone());
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Platform paused"));
+
+    // And resumes once it is unpaused
+    platform.unpause_all(&mut runner, &owner_account);
+
+    cluster.open_account(&mut runner, &bob_account, bob_id, bob_cdp);
+    cluster.execute(&mut runner, &alice_account, alice_id.clone()).expect_commit_success();
+    cluster.close_account(&mut runner, &alice_account, alice_id).expect_commit_success();
+}
+
+#[test]
+fn test_valid_pause_halts_ym_root_cluster() {
+    //] Arrange
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+    let bob_account = runner.bob_account;
+
+    // Instantiate and link a YieldMultiplierRootCluster
+    let factory = YMRootClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let supply = runner.faucet.usdt.address;
+    let debt = runner.faucet.xwbtc.address;
+
+    let (cluster, mut root_market) = factory.instantiate(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        supply,
+        debt,
+    );
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    // Alice opens an account on the cluster, and Bob gets a user badge and a CDP with which to open one
+    platform.new_user(&mut runner, &alice_account);
+    platform.new_user(&mut runner, &bob_account);
+
+    let alice_id = NonFungibleLocalId::Integer(0.into());
+    let bob_id = NonFungibleLocalId::Integer(1.into());
+
+    let alice_cdp = root_market.create_cdp(&mut runner, &alice_account, supply, dec!(1000), debt, dec!(0));
+    let bob_cdp = root_market.create_cdp(&mut runner, &bob_account, supply, dec!(1000), debt, dec!(0));
+    cluster.open_account(&mut runner, &alice_account, alice_id.clone(), alice_cdp);
+
+    //] Act & Assert
+    // Every account operation halts while the platform is paused
+    platform.pause_all(&mut runner, &owner_account);
+
+    let receipt = cluster.try_open_account(&mut runner, &bob_account, bob_id.clone(), bob_cdp.clone());
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Platform paused"));
+    let receipt = cluster.execute(&mut runner, &alice_account, alice_id.clone());
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Platform paused"));
+    let receipt = cluster.close_account(&mut runner, &alice_account, alice_id.clone());
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Platform paused"));
+
+    // And resumes once it is unpaused
+    platform.unpause_all(&mut runner, &owner_account);
+
+    cluster.open_account(&mut runner, &bob_account, bob_id, bob_cdp);
+    cluster.execute(&mut runner, &alice_account, alice_id.clone()).expect_commit_success();
+    cluster.close_account(&mut runner, &alice_account, alice_id).expect_commit_success();
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_new_user_when_paused() {
+    //] Arrange
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    //] Act & Assert
+    platform.pause_all(&mut runner, &owner_account);
+
+    // Attempt to mint a new user badge
+    platform.new_user(&mut runner, &alice_account);
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_pause_without_guardian() {
+    //] Arrange
+    let (mut runner, platform) = Runner::new_base();
+    let alice_account = runner.alice_account;
+
+    //] Act & Assert
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(platform.component, "pause_all", manifest_args!());
+    let receipt = runner.exec_and_dump("pause_all", manifest, &alice_account, None);
+
+    receipt.expect_commit_success();
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_update_service_when_paused() {
+    //] Arrange
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+
+    //] Act & Assert
+    platform.pause_all(&mut runner, &owner_account);
+
+    // Attempt to re-enable the MintBadge service while paused
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account.address, platform.owner_badge, dec!(1))
+        .call_method(
+            platform.component,
+            "update_service",
+            manifest_args!(PlatformService::MintBadge, true, None::<Instant>, None::<Instant>,),
+        );
+    let receipt = runner.exec_and_dump("update_platform_service", manifest, &owner_account, None);
+
+    receipt.expect_commit_success();
+}
+
+#[test]
+fn test_invalid_update_cluster_service_when_paused() {
+    //] Arrange
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+
+    // Instantiate and link a YieldMultiplierWeftV2Cluster
+    let weftv2 = MockWeftV2::new(&mut runner);
+    let factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let owner_rule = rule!(require(platform.owner_badge));
+    let cluster = factory.instantiate(
+        &mut runner,
+        owner_rule,
+        platform.component,
+        platform.link_badge,
+        platform.user_badge,
+        runner.faucet.usdt.address,
+        runner.faucet.xwbtc.address,
+        weftv2.cdp,
+    );
+    platform.link(&mut runner, &owner_account, cluster.component);
+
+    platform.pause_all(&mut runner, &owner_account);
+
+    //] Act
+    // Attempt to disable the cluster's OpenAccount service while paused
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account.address, platform.owner_badge, dec!(1))
+        .call_method(
+            platform.component,
+            "update_cluster_service",
+            manifest_args!(
+                cluster.component,
+                PlatformClusterService::OpenAccount,
+                false,
+                None::<Instant>,
+                None::<Instant>,
+            ),
+        );
+    let receipt = runner.exec_and_dump("update_platform_specific_service", manifest, &owner_account, None);
+
+    //] Assert
+    receipt.expect_specific_failure(|e| format!("{:?}", e).contains("Platform paused"));
+}
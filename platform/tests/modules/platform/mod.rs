@@ -1 +1,6 @@
+pub mod dapp_definition;
+pub mod flash_loans;
+pub mod guardian;
+pub mod services;
+pub mod strategies;
 pub mod users;
@@ -0,0 +1,108 @@
+use crate::helpers::{platform::PlatformService, prelude::*};
+use scrypto_test::prelude::*;
+
+#[test]
+fn test_valid_get_services() {
+    //] Arrange
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+
+    //] Act & Assert
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .call_method(platform.component, "get_services", manifest_args!());
+    let receipt = runner.exec_and_dump("get_services", manifest, &owner_account, None);
+
+    receipt.expect_commit_success();
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_update_service_when_locked() {
+    //] Arrange
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+
+    //] Act & Assert
+    // Lock the MintBadge service
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account.address, platform.owner_badge, dec!(1))
+        .call_method(
+            platform.component,
+            "update_service_and_set_lock",
+            manifest_args!(PlatformService::MintBadge, true, true, None::<Instant>, None::<Instant>,),
+        );
+    let receipt = runner.exec_and_dump("update_platform_service_and_set_lock", manifest, &owner_account, None);
+
+    receipt.expect_commit_success();
+
+    // Attempt to update the locked service
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account.address, platform.owner_badge, dec!(1))
+        .call_method(
+            platform.component,
+            "update_service",
+            manifest_args!(PlatformService::MintBadge, false, None::<Instant>, None::<Instant>,),
+        );
+    let receipt = runner.exec_and_dump("update_platform_service", manifest, &owner_account, None);
+
+    receipt.expect_commit_success();
+}
+
+/* ------------ Scheduled Services ------------ */
+#[test]
+fn test_valid_new_user_after_scheduled_enable() {
+    //] Arrange
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    //] Act & Assert
+    // Disable the MintBadge service, with a scheduled enable which has already passed
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account.address, platform.owner_badge, dec!(1))
+        .call_method(
+            platform.component,
+            "update_service",
+            manifest_args!(PlatformService::MintBadge, false, Some(Instant::new(0)), None::<Instant>,),
+        );
+    let receipt = runner.exec_and_dump("update_platform_service", manifest, &owner_account, None);
+
+    receipt.expect_commit_success();
+
+    // Mint a new user badge
+    platform.new_user(&mut runner, &alice_account);
+
+    let alice_resources = runner.ledger.get_component_resources(alice_account.address);
+
+    assert_eq!(alice_resources.len(), 1);
+}
+
+#[test]
+#[should_panic]
+fn test_invalid_new_user_after_scheduled_disable() {
+    //] Arrange
+    let (mut runner, platform) = Runner::new_base();
+    let owner_account = runner.owner_account;
+    let alice_account = runner.alice_account;
+
+    //] Act & Assert
+    // Keep the MintBadge service enabled, with a scheduled disable which has already passed
+    let manifest = ManifestBuilder::new()
+        .lock_fee_from_faucet()
+        .create_proof_from_account_of_amount(owner_account.address, platform.owner_badge, dec!(1))
+        .call_method(
+            platform.component,
+            "update_service",
+            manifest_args!(PlatformService::MintBadge, true, None::<Instant>, Some(Instant::new(0)),),
+        );
+    let receipt = runner.exec_and_dump("update_platform_service", manifest, &owner_account, None);
+
+    receipt.expect_commit_success();
+
+    // Attempt to mint a new user badge
+    platform.new_user(&mut runner, &alice_account);
+}
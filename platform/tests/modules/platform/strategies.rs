@@ -0,0 +1,119 @@
+use crate::helpers::{
+    clusters::yield_multiplier_weftv2::{YMWeftV2ClusterFactory, YMWeftV2LeverageMode},
+    integrations::weftv2::MockWeftV2,
+    platform::Platform,
+    prelude::*,
+};
+use scrypto_test::prelude::*;
+
+const BLUEPRINT: &str = "YieldMultiplierWeftV2Cluster";
+
+/// Publishes the WeftV2 yield multiplier, returning its package and the parameters of a USDT/xwBTC cluster.
+fn arrange() -> (
+    Runner,
+    Platform,
+    PackageAddress,
+    (ResourceAddress, ResourceAddress, YMWeftV2LeverageMode, ComponentAddress, ResourceAddress),
+) {
+    let (mut runner, platform) = Runner::new_base();
+
+    let weftv2 = MockWeftV2::new(&mut runner);
+    let factory = YMWeftV2ClusterFactory::new(&mut runner.ledger);
+
+    let params = (
+        runner.faucet.usdt.address,
+        runner.faucet.xwbtc.address,
+        YMWeftV2LeverageMode::Long,
+        platform.component,
+        weftv2.cdp,
+    );
+
+    (runner, platform, factory.package_address, params)
+}
+
+#[test]
+fn test_valid_deploy_cluster() {
+    //] Arrange
+    let (mut runner, platform, package, params) = arrange();
+    let owner_account = runner.owner_account;
+
+    let receipt = platform.register_strategy(&mut runner, &owner_account, package, BLUEPRINT, "instantiate");
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = platform.deploy_cluster(&mut runner, &owner_account, package, BLUEPRINT, params);
+
+    //] Assert
+    let cluster: ComponentAddress = receipt.expect_commit_success().output(1);
+
+    // Linkable, and labelled with the platform's dApp definition
+    platform.link(&mut runner, &owner_account, cluster);
+
+    assert_eq!(runner.ledger.get_component_balance(cluster, platform.link_badge), dec!(1));
+    assert_eq!(
+        runner.ledger.get_metadata(cluster.into(), "dapp_definition"),
+        Some(MetadataValue::GlobalAddress(owner_account.address.into()))
+    );
+}
+
+#[test]
+fn test_invalid_deploy_unregistered_cluster() {
+    //] Arrange
+    let (mut runner, platform, package, params) = arrange();
+    let owner_account = runner.owner_account;
+
+    //] Act
+    let receipt = platform.deploy_cluster(&mut runner, &owner_account, package, BLUEPRINT, params);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_deploy_deregistered_cluster() {
+    //] Arrange
+    let (mut runner, platform, package, params) = arrange();
+    let owner_account = runner.owner_account;
+
+    let receipt = platform.register_strategy(&mut runner, &owner_account, package, BLUEPRINT, "instantiate");
+    receipt.expect_commit_success();
+
+    let receipt = platform.deregister_strategy(&mut runner, &owner_account, package, BLUEPRINT);
+    receipt.expect_commit_success();
+
+    //] Act
+    let receipt = platform.deploy_cluster(&mut runner, &owner_account, package, BLUEPRINT, params);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_invalid_deploy_cluster_with_params() {
+    //] Arrange
+    let (mut runner, platform, package, _) = arrange();
+    let owner_account = runner.owner_account;
+
+    let receipt = platform.register_strategy(&mut runner, &owner_account, package, BLUEPRINT, "instantiate");
+    receipt.expect_commit_success();
+
+    //] Act
+    let params = (runner.faucet.usdt.address,);
+    let receipt = platform.deploy_cluster(&mut runner, &owner_account, package, BLUEPRINT, params);
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
+
+#[test]
+fn test_unauthorised_register_strategy() {
+    //] Arrange
+    let (mut runner, platform, package, _) = arrange();
+    let alice_account = runner.alice_account;
+
+    //] Act
+    let receipt = platform.register_strategy(&mut runner, &alice_account, package, BLUEPRINT, "instantiate");
+
+    //] Assert
+    receipt.expect_commit_failure();
+}
@@ -36,7 +36,11 @@ fn test_invalid_new_user_when_service_disabled() {
     let manifest = ManifestBuilder::new()
         .lock_fee_from_faucet()
         .create_proof_from_account_of_amount(owner_account.address, platform.owner_badge, dec!(1))
-        .call_method(platform.component, "update_service", manifest_args!(PlatformService::MintBadge, false,));
+        .call_method(
+            platform.component,
+            "update_service",
+            manifest_args!(PlatformService::MintBadge, false, None::<Instant>, None::<Instant>,),
+        );
     let receipt = runner.exec_and_dump("update_platform_service", manifest, &owner_account, None);
 
     receipt.expect_commit_success();
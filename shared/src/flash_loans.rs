@@ -0,0 +1,115 @@
+// Libraries
+use scrypto::prelude::*;
+
+/* ------------------ Sources ----------------- */
+/// A provider from which a cluster may flash borrow its debt asset.
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlashLoanSource {
+    /// Weft V2's lending market; `take_flash_loan(loans) -> (buckets, terms)`.
+    Weft(ComponentAddress),
+    /// Root's lending market; `take_flash_loan(resource, amount) -> (bucket, terms)`.
+    Root(ComponentAddress),
+    /// The platform's flash loan pool, with the same interface as Root; linked clusters borrow at a discount.
+    Vault(ComponentAddress),
+}
+
+/// A flash loan source, with the fee it charges.
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlashLoanProvider {
+    pub source: FlashLoanSource,
+    /// Fee charged by Weft or Root on the borrowed amount; e.g. `0.0005` for 0.05%. Unused for the platform's pool,
+    /// whose linked rate is read from the pool.
+    pub fee_rate: Decimal,
+}
+
+impl FlashLoanProvider {
+    /// Creates a new flash loan provider.
+    ///
+    /// # Panics
+    /// - If the fee rate is negative, or not below 1.
+    pub fn new(source: FlashLoanSource, fee_rate: Decimal) -> Self {
+        assert!(fee_rate >= dec!(0) && fee_rate < dec!(1), "Invalid flash loan fee rate");
+
+        Self { source, fee_rate }
+    }
+
+    /// Returns the fee for flash borrowing `amount`. The platform's pool charges linked clusters its own linked
+    /// rate, which is read from the pool rather than the configured fee rate.
+    pub fn quote(&self, amount: Decimal) -> Decimal {
+        let fee_rate = match self.source {
+            FlashLoanSource::Vault(address) => {
+                let pool: Global<AnyComponent> = address.into();
+                let (_, linked_fee_rate) = pool.call_raw::<(Decimal, Decimal)>("get_fee_rates", scrypto_args!());
+
+                linked_fee_rate
+            }
+            FlashLoanSource::Weft(_) | FlashLoanSource::Root(_) => self.fee_rate,
+        };
+
+        amount * fee_rate
+    }
+
+    /// Flash borrows `amount` of `resource` as a cluster linked to the platform; the link badge is presented to the
+    /// platform's pool for its linked rate, and dropped for other sources.
+    ///
+    /// # Returns
+    /// - `FungibleBucket`: The borrowed funds.
+    /// - `NonFungibleBucket`: The transient terms of the loan, which must be returned to `repay` within the same transaction.
+    ///
+    /// # Panics
+    /// - If the source returns another resource, or less than the amount requested.
+    pub fn take(&self, link_badge: NonFungibleProof, resource: ResourceAddress, amount: Decimal) -> (FungibleBucket, NonFungibleBucket) {
+        let (funds, terms) = match self.source {
+            FlashLoanSource::Weft(address) => {
+                link_badge.drop();
+
+                let market: Global<AnyComponent> = address.into();
+                let (mut loans, terms) =
+                    market.call_raw::<(Vec<FungibleBucket>, NonFungibleBucket)>("take_flash_loan", scrypto_args!(indexmap! { resource => amount }));
+                assert_eq!(loans.len(), 1, "Unexpected flash loan from Weft");
+
+                (loans.pop().unwrap(), terms)
+            }
+            FlashLoanSource::Root(address) => {
+                link_badge.drop();
+
+                let market: Global<AnyComponent> = address.into();
+                market.call_raw::<(FungibleBucket, NonFungibleBucket)>("take_flash_loan", scrypto_args!(resource, amount))
+            }
+            FlashLoanSource::Vault(address) => {
+                let pool: Global<AnyComponent> = address.into();
+                pool.call_raw::<(FungibleBucket, NonFungibleBucket)>("take_linked_flash_loan", scrypto_args!(link_badge, resource, amount))
+            }
+        };
+
+        assert_eq!(funds.resource_address(), resource, "Flash loan returned an unexpected resource");
+        assert!(funds.amount() >= amount, "Flash loan returned less than requested");
+
+        (funds, terms)
+    }
+
+    /// Repays a flash loan taken from the source, returning its terms. The source charges the fee recorded in the
+    /// terms, and validates the repayment against them.
+    ///
+    /// # Returns
+    /// - `FungibleBucket`: The part of the repayment in excess of the amount due.
+    pub fn repay(&self, repayment: FungibleBucket, terms: NonFungibleBucket) -> FungibleBucket {
+        match self.source {
+            FlashLoanSource::Weft(address) => {
+                let mut excess = FungibleBucket::new(repayment.resource_address());
+
+                let market: Global<AnyComponent> = address.into();
+                let remainders = market.call_raw::<Vec<FungibleBucket>>("repay_flash_loan", scrypto_args!(vec![repayment], terms));
+                for remainder in remainders {
+                    excess.put(remainder);
+                }
+
+                excess
+            }
+            FlashLoanSource::Root(address) | FlashLoanSource::Vault(address) => {
+                let market: Global<AnyComponent> = address.into();
+                market.call_raw::<FungibleBucket>("repay_flash_loan", scrypto_args!(repayment, terms))
+            }
+        }
+    }
+}
@@ -1,4 +1,6 @@
+pub mod flash_loans;
 pub mod links;
+pub mod oracles;
 pub mod services;
 pub mod users;
 pub mod utils;
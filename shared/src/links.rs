@@ -5,10 +5,12 @@ use scrypto::prelude::*;
 pub struct Link {
     pub issuing_platform: ComponentAddress,
     pub linked_cluster: ComponentAddress,
+    #[mutable]
+    pub paused: bool,
 }
 
 impl Link {
     pub fn new(issuing_platform: ComponentAddress, linked_cluster: ComponentAddress) -> Self {
-        Self { issuing_platform, linked_cluster }
+        Self { issuing_platform, linked_cluster, paused: false }
     }
 }
@@ -0,0 +1,100 @@
+// Modules
+use crate::utils::now;
+// Libraries
+use scrypto::prelude::*;
+
+/* ---------------- Price Data ---------------- */
+/// A price and the time, in seconds since the unix epoch, at which it was last updated.
+/// Root's oracle reports its prices in this form.
+#[derive(ScryptoSbor, Debug, Clone, Copy)]
+pub struct PriceInfo {
+    pub timestamp: i64,
+    pub price: Decimal,
+}
+
+/* ------------------ Sources ----------------- */
+/// A price feed from which a `PriceOracle` reads.
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OracleSource {
+    /// Root's oracle, reporting a `PriceInfo` per asset in a common unit through its `get_price` method.
+    Root(ComponentAddress),
+}
+
+impl OracleSource {
+    /// Returns the price of `base` in units of `quote`, and the time at which it was last updated.
+    pub fn get_price(&self, base: ResourceAddress, quote: ResourceAddress) -> PriceInfo {
+        match *self {
+            OracleSource::Root(address) => {
+                let oracle: Global<AnyComponent> = address.into();
+                let base_price = oracle.call_raw::<PriceInfo>("get_price", scrypto_args!(base));
+                let quote_price = oracle.call_raw::<PriceInfo>("get_price", scrypto_args!(quote));
+
+                Self::__cross(&base_price, &quote_price)
+            }
+        }
+    }
+
+    /// Crosses two prices in a common unit into the price of the first in units of the second;
+    /// as old as the older of the two.
+    fn __cross(base: &PriceInfo, quote: &PriceInfo) -> PriceInfo {
+        assert!(quote.price > dec!(0), "Invalid price for the quote asset");
+
+        PriceInfo { timestamp: base.timestamp.min(quote.timestamp), price: base.price / quote.price }
+    }
+}
+
+/* ------------------ Oracle ------------------ */
+/// Price oracle reading from one or more sources. The first source is the primary, and the others
+/// guard it; a price is rejected if any source is stale, or deviates too far from the primary.
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct PriceOracle {
+    pub sources: Vec<OracleSource>,
+    /// Maximum age of a source's price, in seconds.
+    pub max_staleness: i64,
+    /// Maximum relative deviation of a source's price from the primary's; e.g. `0.02` for 2%.
+    pub max_deviation: Decimal,
+}
+
+impl PriceOracle {
+    /// Creates a new price oracle.
+    ///
+    /// # Panics
+    /// - If there are no sources.
+    /// - If the maximum staleness or deviation is negative.
+    pub fn new(sources: Vec<OracleSource>, max_staleness: i64, max_deviation: Decimal) -> Self {
+        assert!(!sources.is_empty(), "Oracle must have at least one source");
+        assert!(max_staleness >= 0, "Maximum staleness cannot be negative");
+        assert!(max_deviation >= dec!(0), "Maximum deviation cannot be negative");
+
+        Self { sources, max_staleness, max_deviation }
+    }
+
+    /// Returns the primary source's price of `base` in units of `quote`.
+    ///
+    /// # Panics
+    /// - If any source's price is stale or not positive.
+    /// - If any source's price deviates from the primary's by more than the maximum deviation.
+    pub fn get_price(&self, base: ResourceAddress, quote: ResourceAddress) -> Decimal {
+        let now = now();
+
+        let prices: Vec<Decimal> = self
+            .sources
+            .iter()
+            .map(|source| {
+                let info = source.get_price(base, quote);
+                assert!(now - info.timestamp <= self.max_staleness, "Stale price from {:?}", source);
+                assert!(info.price > dec!(0), "Invalid price from {:?}", source);
+
+                info.price
+            })
+            .collect();
+
+        let primary = prices[0];
+        for price in prices.iter().skip(1) {
+            let deviation = (*price / primary - dec!(1)).checked_abs().unwrap();
+            assert!(deviation <= self.max_deviation, "Oracle sources deviate beyond the maximum");
+        }
+
+        primary
+    }
+}
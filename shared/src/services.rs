@@ -1,32 +1,74 @@
+// Modules
+use crate::utils::now;
 // Libraries
 use scrypto::prelude::*;
+use std::hash::Hash;
 
+/* ------------------- Value ------------------ */
 #[derive(ScryptoSbor, Debug, Clone, Copy)]
 pub struct ServiceValue {
     pub value: bool,
     pub locked: bool,
+    pub enable_at: Option<Instant>,
+    pub disable_at: Option<Instant>,
 }
 
 impl ServiceValue {
     pub fn yes() -> Self {
-        Self { value: true, locked: false }
+        Self { value: true, locked: false, enable_at: None, disable_at: None }
     }
 
     pub fn no() -> Self {
-        Self { value: false, locked: false }
+        Self { value: false, locked: false, enable_at: None, disable_at: None }
     }
 
-    pub fn set(&mut self, value: bool, lock: SetLock) {
+    /// Sets the value of the service, replacing any scheduled transitions.
+    ///
+    /// # Parameters
+    /// - `value`: The value to set the service to.
+    /// - `enable_at`: Optional time at which the service is enabled.
+    /// - `disable_at`: Optional time at which the service is disabled.
+    /// - `lock`: Whether to keep or update the lock state of the service.
+    ///
+    /// # Panics
+    /// - If the lock state is kept, and the service is locked.
+    /// - If both transitions are scheduled for the same time.
+    pub fn set(&mut self, value: bool, enable_at: Option<Instant>, disable_at: Option<Instant>, lock: SetLock) {
+        if let (Some(enable_at), Some(disable_at)) = (enable_at, disable_at) {
+            assert!(enable_at != disable_at, "Cannot enable and disable a service at the same time");
+        }
+
         match lock {
             SetLock::None => {
                 assert!(!self.locked);
-                self.value = value;
             }
             SetLock::Update(lock) => {
-                self.value = value;
                 self.locked = lock;
             }
         }
+
+        self.value = value;
+        self.enable_at = enable_at;
+        self.disable_at = disable_at;
+    }
+
+    /// Returns the value of the service at the given time (in seconds since the unix epoch).
+    /// Scheduled transitions which have passed override the set value; if both have passed, the latest applies.
+    pub fn value_at(&self, time: i64) -> bool {
+        let enabled = self.enable_at.filter(|at| at.seconds_since_unix_epoch <= time);
+        let disabled = self.disable_at.filter(|at| at.seconds_since_unix_epoch <= time);
+
+        match (enabled, disabled) {
+            (None, None) => self.value,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (Some(enabled), Some(disabled)) => enabled.seconds_since_unix_epoch > disabled.seconds_since_unix_epoch,
+        }
+    }
+
+    /// Returns a copy of the service, with `value` resolved against the current time.
+    pub fn resolved(&self) -> Self {
+        Self { value: self.value_at(now()), ..*self }
     }
 }
 
@@ -35,3 +77,76 @@ pub enum SetLock {
     None,
     Update(bool),
 }
+
+/* ----------------- Registry ----------------- */
+/// Value held by services which have not been explicitly set in a `ServiceRegistry`.
+#[derive(ScryptoSbor, Debug, PartialEq, Clone, Copy)]
+pub enum ServicePolicy {
+    DefaultOn,
+    DefaultOff,
+}
+
+impl ServicePolicy {
+    pub fn value(&self) -> ServiceValue {
+        match self {
+            ServicePolicy::DefaultOn => ServiceValue::yes(),
+            ServicePolicy::DefaultOff => ServiceValue::no(),
+        }
+    }
+}
+
+/// Implemented by service enums, listing every service they define.
+pub trait ServiceKey: Copy + Eq + Hash {
+    fn all() -> Vec<Self>;
+}
+
+/// Map of services to their values. Services missing from the map fall back to the registry's policy,
+/// allowing new services to be introduced without migrating existing state.
+#[derive(ScryptoSbor, Debug, Clone)]
+#[sbor(categorize_types = "K")]
+pub struct ServiceRegistry<K: Eq + Hash> {
+    policy: ServicePolicy,
+    services: IndexMap<K, ServiceValue>,
+}
+
+impl<K: ServiceKey> ServiceRegistry<K> {
+    pub fn new(policy: ServicePolicy) -> Self {
+        Self { policy, services: IndexMap::new() }
+    }
+
+    /// Returns the service, with its value resolved against any scheduled transitions.
+    pub fn get(&self, service: K) -> ServiceValue {
+        self.__get_raw(service).resolved()
+    }
+
+    pub fn update(&mut self, service: K, value: bool, enable_at: Option<Instant>, disable_at: Option<Instant>, lock: SetLock) {
+        let mut set = self.__get_raw(service);
+        set.set(value, enable_at, disable_at, lock);
+
+        self.services.insert(service, set);
+    }
+
+    /// Disables every service and clears any scheduled transitions, leaving lock states untouched.
+    pub fn disable_all(&mut self) {
+        for service in K::all() {
+            let mut set = self.__get_raw(service);
+            set.value = false;
+            set.enable_at = None;
+            set.disable_at = None;
+
+            self.services.insert(service, set);
+        }
+    }
+
+    /// Returns every service alongside its value and lock state.
+    pub fn list(&self) -> IndexMap<K, ServiceValue> {
+        K::all().into_iter().map(|service| (service, self.get(service))).collect()
+    }
+
+    fn __get_raw(&self, service: K) -> ServiceValue {
+        match self.services.get(&service) {
+            Some(value) => *value,
+            None => self.policy.value(),
+        }
+    }
+}
@@ -19,6 +19,12 @@ pub struct User {
     pub open: u64,
 }
 
+impl Default for User {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl User {
     pub fn new() -> Self {
         Self { minted_at: Instant::new(now()), accounts_in: IndexSet::new(), open: 0 }
@@ -2,8 +2,28 @@
 use scrypto::prelude::*;
 
 /* ------------------- Time ------------------- */
-pub const SECONDS_PER_YEAR: i64 = 60 * 60 * 24 * 365;
+pub const SECONDS_PER_DAY: i64 = 60 * 60 * 24;
+pub const SECONDS_PER_YEAR: i64 = SECONDS_PER_DAY * 365;
 
 pub fn now() -> i64 {
     Clock::current_time(TimePrecisionV2::Second).seconds_since_unix_epoch
 }
+
+/* ------------------- Rates ------------------ */
+/// Annualises a per-second interest rate into a simple APR.
+pub fn annualise_rate(rate_per_second: Decimal) -> Decimal {
+    rate_per_second * Decimal::from(SECONDS_PER_YEAR)
+}
+
+/// Compounds an APR daily into an APY.
+pub fn apr_to_apy(apr: Decimal) -> Decimal {
+    let periods = SECONDS_PER_YEAR / SECONDS_PER_DAY;
+
+    (dec!(1) + apr / Decimal::from(periods)).checked_powi(periods).expect("APY overflow") - dec!(1)
+}
+
+/// Returns the APR on equity of a position levered `leverage` times; i.e. with collateral worth `leverage`
+/// times the equity, and the remainder borrowed.
+pub fn leveraged_apr(supply_apr: Decimal, borrow_apr: Decimal, leverage: Decimal) -> Decimal {
+    supply_apr * leverage - borrow_apr * (leverage - dec!(1))
+}
@@ -0,0 +1,15 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+
+/* ------------------- User ------------------- */
+#[derive(ScryptoSbor, Debug)]
+pub struct AccountData {
+    pub cdp_vault: NonFungibleVault,
+    pub lp_vault: FungibleVault,
+}
+
+impl AccountData {
+    pub fn new(cdp_vault: NonFungibleVault, lp_resource: ResourceAddress) -> Self {
+        Self { cdp_vault, lp_vault: FungibleVault::new(lp_resource) }
+    }
+}
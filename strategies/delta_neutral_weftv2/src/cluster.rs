@@ -0,0 +1,981 @@
+/* ------------------ Imports ----------------- */
+// Modules
+use crate::accounts::AccountData;
+use crate::dex::Dex;
+use crate::fees::FeeStructure;
+use crate::info::{AccountInfo, ClusterInfo, FeeInfo, HedgeConfig};
+use crate::services::{ClusterService, ClusterServiceManager};
+use crate::weft::*;
+// Shared Modules
+use shared::links::Link;
+use shared::oracles::{OracleSource, PriceOracle};
+use shared::services::{ServicePolicy, ServiceValue, SetLock};
+// Libraries
+use scrypto::prelude::*;
+use std::panic::catch_unwind;
+
+/* ----------------- Blueprint ---------------- */
+type Unit = ();
+
+#[blueprint]
+#[types(
+    // General
+    Unit,
+    ComponentAddress,
+    ResourceAddress,
+    NonFungibleResourceManager,
+    NonFungibleLocalId,
+    NonFungibleVault,
+    FungibleVault,
+    Decimal,
+    u64,
+    i64,
+    FeeStructure,
+    HedgeConfig,
+    PriceOracle,
+    OracleSource,
+    // Services
+    ClusterServiceManager,
+    ClusterService,
+    ServiceValue,
+    ServicePolicy,
+    SetLock,
+    // State Returns
+    AccountInfo,
+    ClusterInfo,
+    FeeInfo,
+    // WeftV2 Integration
+    CDPData,
+    CDPHealthChecker,
+    LoanPositionData,
+    LoanConfig,
+    LoanResourceConfig,
+    CollateralPositionData,
+    CollateralConfig,
+    CollateralResourceConfig,
+    RegisteredResourceType,
+    NFTCollateralPositionData,
+    NFTLiquidationValue,
+    RegisteredNFTResourceType,
+    EfficiencyMode,
+    CollateralConfigVersion,
+    CollateralInfo,
+    NFTCollateralInfo,
+    LoanInfo
+)]
+mod delta_neutral_weftv2_cluster {
+    //] --------------- Scrypto Setup -------------- */
+    enable_method_auth! {
+        roles {
+            can_manage_services => updatable_by: [OWNER];
+            can_lock_services   => updatable_by: [OWNER];
+            can_manage_fees     => updatable_by: [OWNER];
+            can_rebalance       => updatable_by: [OWNER];
+        },
+        methods {
+            // Links
+            handle_link => PUBLIC;
+            // Cluster
+            get_cluster_info     => PUBLIC;
+            get_claimed_entities => PUBLIC;
+            get_services         => PUBLIC;
+            update_service              => restrict_to: [can_manage_services, can_lock_services];
+            update_service_and_set_lock => restrict_to: [can_lock_services];
+            set_fee_structure           => restrict_to: [can_manage_fees];
+            collect_fees                => restrict_to: [can_manage_fees];
+            set_hedge_config            => restrict_to: [can_manage_services, OWNER];
+            set_oracle                  => restrict_to: [can_manage_services, OWNER];
+            // Accounts
+            open_account     => PUBLIC;
+            close_account    => PUBLIC;
+            get_account_info => PUBLIC;
+            // Hedging
+            enter             => PUBLIC;
+            exit              => PUBLIC;
+            rebalance         => PUBLIC;
+            rebalance_account => restrict_to: [can_rebalance, OWNER];
+        }
+    }
+
+    //] ------------- Cluster Blueprint ------------ */
+    struct DeltaNeutralWeftV2Cluster {
+        // Authorisation
+        component_address: ComponentAddress,
+        // Platform link
+        platform_address: ComponentAddress,
+        link: NonFungibleVault,
+        user_resource: ResourceAddress,
+        // Cluster
+        stable: ResourceAddress,
+        volatile: ResourceAddress,
+        hedge_config: HedgeConfig,
+        oracle: Option<PriceOracle>,
+        services: ClusterServiceManager,
+        // Accounts
+        accounts: KeyValueStore<NonFungibleLocalId, AccountData>,
+        account_count: u64,
+        // Fees
+        fee_structure: FeeStructure,
+        fee_vault: FungibleVault,
+        // Integration
+        weft_market_address: ComponentAddress,
+        cdp_manager: NonFungibleResourceManager,
+        dex_address: ComponentAddress,
+        lp_resource: ResourceAddress,
+    }
+
+    impl DeltaNeutralWeftV2Cluster {
+        /// Instantiates a new `DeltaNeutralWeftV2Cluster` component with the specified configuration.
+        ///
+        /// Users deposit the stable asset, part of which is supplied to Weft to borrow the volatile asset. The
+        /// borrowed volatile asset is paired with the rest of the stable in a DEX pool, so the volatile asset held
+        /// in the LP offsets the volatile debt, leaving the account's net exposure close to zero.
+        ///
+        /// # Parameters
+        /// - `owner_rule`: Access rule defining the owner of the cluster.
+        /// - `admin_rule`: Access rule defining the admins of the cluster.
+        /// - `platform_address`: The component address of the platform to which this cluster links.
+        /// - `stable`: Resource address of the stable asset, supplied as collateral.
+        /// - `volatile`: Resource address of the volatile asset, borrowed.
+        /// - `weft_market_address`: The component address of the WeftV2 lending market.
+        /// - `cdp_resource`: Resource address of the WeftV2 CDP NFT.
+        /// - `dex_address`: The component address of the DEX pool of the stable and volatile assets.
+        /// - `lp_resource`: Resource address of the DEX pool's LP token.
+        ///
+        /// # Returns
+        /// A globally accessible `DeltaNeutralWeftV2Cluster` component instance.
+        pub fn instantiate(
+            // Authorisation
+            owner_rule: AccessRule,
+            admin_rule: AccessRule,
+            // Link
+            platform_address: ComponentAddress,
+            // Cluster
+            stable: ResourceAddress,
+            volatile: ResourceAddress,
+            // Integration
+            weft_market_address: ComponentAddress,
+            cdp_resource: ResourceAddress,
+            dex_address: ComponentAddress,
+            lp_resource: ResourceAddress,
+        ) -> Global<DeltaNeutralWeftV2Cluster> {
+            assert_ne!(stable, volatile, "Stable and volatile assets must differ");
+
+            // Reserve component address
+            let (address_reservation, component_address) = Runtime::allocate_component_address(DeltaNeutralWeftV2Cluster::blueprint_id());
+
+            let platform: Global<AnyComponent> = platform_address.into();
+            let link_resource = platform.call_raw::<ResourceAddress>("get_link_badge_address", scrypto_args!());
+            let user_resource = platform.call_raw::<ResourceAddress>("get_user_badge_address", scrypto_args!());
+            let dapp_definition_address: GlobalAddress = platform
+                .get_metadata("dapp_definition")
+                .unwrap_or(None)
+                .expect("Platform has no dApp definition");
+
+            //] Authorisation
+            // Component owner
+            let owner_role: OwnerRole = OwnerRole::Fixed(owner_rule.clone());
+
+            //] Component Instantisation
+            // Metadata
+            let component_metadata = metadata! {
+                roles {
+                    metadata_setter         => OWNER;
+                    metadata_setter_updater => OWNER;
+                    metadata_locker         => OWNER;
+                    metadata_locker_updater => rule!(deny_all);
+                },
+                init {
+                    "name"            => "L3//Delta Neutral - WeftV2", locked;
+                    "description"     => "Lattic3 cluster component for the Delta Neutral strategy, built on top of the Weft V2 lending platform.", locked;
+                    "supply"          => stable, locked;
+                    "debt"            => volatile, locked;
+                    "dapp_definition" => dapp_definition_address, updatable;
+                }
+            };
+
+            // Roles
+            let component_roles = roles! {
+                can_manage_fees     => OWNER;
+                can_manage_services => admin_rule.clone();
+                can_lock_services   => OWNER;
+                can_rebalance       => admin_rule;
+            };
+
+            // Instantisation
+            let initial_state = Self {
+                component_address,
+                platform_address,
+                link: NonFungibleVault::new(link_resource),
+                user_resource,
+                stable,
+                volatile,
+                hedge_config: HedgeConfig::default(),
+                oracle: None,
+                accounts: KeyValueStore::new(),
+                account_count: 0,
+                services: ClusterServiceManager::new(ServicePolicy::DefaultOn),
+                fee_structure: FeeStructure::default(),
+                fee_vault: FungibleVault::new(XRD),
+                weft_market_address,
+                cdp_manager: cdp_resource.into(),
+                dex_address,
+                lp_resource,
+            };
+
+            let component: Global<DeltaNeutralWeftV2Cluster> = initial_state
+                .instantiate()
+                .prepare_to_globalize(owner_role)
+                .roles(component_roles)
+                .metadata(component_metadata)
+                .with_address(address_reservation)
+                .globalize();
+
+            component
+        }
+
+        //] ------------------- Links ------------------ */
+        /// Handles the reception of a link badge. Initiated by the platform's link_cluster() method.
+        ///
+        /// # Parameters
+        /// - `bucket`: The bucket containing the link badge.
+        ///
+        /// # Panics
+        /// - If the Link service is disabled
+        /// - If the link badge is invalid (amount != 1, incorrect resource address)
+        /// - If the cluster is already linked
+        pub fn handle_link(&mut self, bucket: NonFungibleBucket) {
+            // Check operating service
+            assert!(self.services.get(ClusterService::Link).value, "ClusterService::Link disabled");
+
+            // Sanity checks
+            assert_eq!(self.link.amount(), dec!(0), "Platform already linked");
+            assert_eq!(bucket.amount(), dec!(1), "Invalid bucket amount; must contain 1 link badge");
+            assert_eq!(
+                self.link.resource_address(),
+                bucket.resource_address(),
+                "Invalid link badge resource address"
+            );
+
+            // Link platform
+            self.link.put(bucket);
+        }
+
+        //] Private
+        /// Call a method on the platform; uses a closure with |platform, link_badge_proof|.
+        ///
+        /// # Parameters
+        /// - `func`: Closure with |platform, link_badge_proof|
+        ///
+        /// # Panics
+        /// - If the cluster is not linked
+        /// - If the CallLinked service is disabled
+        fn __with_link<F: FnOnce(Global<AnyComponent>, NonFungibleProof)>(&self, func: F) {
+            assert!(self.link.amount() > dec!(0), "Cluster not linked");
+            assert!(self.services.get(ClusterService::CallLinked).value, "ClusterService::CallLinked disabled");
+
+            // Arrange call
+            let link_local_id = self.link.non_fungible_local_id();
+            let link_badge = self.link.create_proof_of_non_fungibles(&indexset![link_local_id]);
+
+            let platform: Global<AnyComponent> = self.platform_address.into();
+
+            func(platform, link_badge);
+        }
+
+        /// Returns whether the linked platform has been paused; read from the `paused` flag of the link badge.
+        /// An unlinked cluster is never considered paused.
+        fn __platform_paused(&self) -> bool {
+            if self.link.amount() == dec!(0) {
+                return false;
+            }
+
+            let link: Link = self.link.non_fungible::<Link>().data();
+            link.paused
+        }
+
+        //] ------------------ Cluster ----------------- */
+        /// Returns the addresses of the cluster's entities; its component. They are labelled with the platform's
+        /// dApp definition, and are to be registered in its `claimed_entities`.
+        pub fn get_claimed_entities(&self) -> Vec<GlobalAddress> {
+            vec![self.component_address.into()]
+        }
+
+        /// Returns general information about the cluster.
+        ///
+        /// # Returns
+        /// A `ClusterInfo` struct containing the following information:
+        /// - `platform_address`: The component address of the platform to which this cluster links.
+        /// - `cluster_address`: The component address of the cluster.
+        /// - `linked`: A boolean indicating whether the cluster is linked.
+        /// - `stable_res`: The resource address of the stable asset.
+        /// - `volatile_res`: The resource address of the volatile asset.
+        /// - `dex_address`: The component address of the DEX pool.
+        /// - `lp_res`: The resource address of the DEX pool's LP token.
+        /// - `account_count`: The number of accounts open on the cluster.
+        /// - `hedge_config`: The parameters of the hedge.
+        /// - `oracle`: The price oracle from which the volatile asset is priced, if configured.
+        pub fn get_cluster_info(&self) -> ClusterInfo {
+            // Return the fee amounts
+            let fee_info = FeeInfo {
+                open: self.fee_structure.open,
+                close: self.fee_structure.close,
+                execute: self.fee_structure.execute,
+            };
+
+            ClusterInfo {
+                platform_address: self.platform_address,
+                cluster_address: self.component_address,
+                linked: self.link.amount() > dec!(0),
+                stable_res: self.stable,
+                volatile_res: self.volatile,
+                dex_address: self.dex_address,
+                lp_res: self.lp_resource,
+                account_count: self.account_count,
+                hedge_config: self.hedge_config,
+                oracle: self.oracle.clone(),
+                fee_info,
+            }
+        }
+
+        //] Services
+        /// Returns every cluster service, alongside its value and lock state.
+        pub fn get_services(&self) -> IndexMap<ClusterService, ServiceValue> {
+            self.services.list()
+        }
+
+        /// Updates a cluster service, assuming it is not locked.
+        ///
+        /// # Parameters
+        /// - `service`: The service to update.
+        /// - `value`: The value to set the service to.
+        /// - `enable_at`: Optional time at which the service is enabled.
+        /// - `disable_at`: Optional time at which the service is disabled.
+        ///
+        /// # Panics
+        /// - If the service is currently locked.
+        pub fn update_service(&mut self, service: ClusterService, value: bool, enable_at: Option<Instant>, disable_at: Option<Instant>) {
+            self.services.update(service, value, enable_at, disable_at, SetLock::None);
+        }
+
+        /// Updates a cluster service and sets the lock state.
+        ///
+        /// # Parameters
+        /// - `service`: The service to update.
+        /// - `value`: The value to set the service to.
+        /// - `locked`: The value to which the lock status of the service is set to.
+        /// - `enable_at`: Optional time at which the service is enabled.
+        /// - `disable_at`: Optional time at which the service is disabled.
+        pub fn update_service_and_set_lock(
+            &mut self,
+            service: ClusterService,
+            value: bool,
+            locked: bool,
+            enable_at: Option<Instant>,
+            disable_at: Option<Instant>,
+        ) {
+            self.services.update(service, value, enable_at, disable_at, SetLock::Update(locked));
+        }
+
+        //] Hedge Config
+        /// Sets the parameters of the hedge.
+        ///
+        /// # Parameters
+        /// - `target_ltv`: Value of the volatile debt per unit of stable collateral on entry, between 0 and 1.
+        /// - `delta_band`: Maximum net exposure, as a share of the account's equity, before a rebalance is allowed.
+        /// - `exit_slippage`: Maximum slippage from the oracle's price, and extra stable swapped on exit to cover a shortfall.
+        ///
+        /// # Panics
+        /// - If any of the parameters is out of range.
+        pub fn set_hedge_config(&mut self, target_ltv: Decimal, delta_band: Decimal, exit_slippage: Decimal) {
+            self.hedge_config = HedgeConfig::new(target_ltv, delta_band, exit_slippage);
+        }
+
+        //] Oracle
+        /// Sets the price oracle from which the volatile asset is priced; no sources removes the oracle, which halts
+        /// entries, exits and rebalances.
+        ///
+        /// # Parameters
+        /// - `sources`: The price feeds to read, the first being the primary; e.g. a Root oracle guarded by others.
+        /// - `max_staleness`: Maximum age of a source's price, in seconds.
+        /// - `max_deviation`: Maximum relative deviation of a source's price from the primary's; e.g. `0.02` for 2%.
+        ///
+        /// # Panics
+        /// - If the maximum staleness or deviation is negative.
+        pub fn set_oracle(&mut self, sources: Vec<OracleSource>, max_staleness: i64, max_deviation: Decimal) {
+            self.oracle = match sources.is_empty() {
+                true => None,
+                false => Some(PriceOracle::new(sources, max_staleness, max_deviation)),
+            };
+        }
+
+        //] Fees
+        /// Sets a new fee structure for the cluster.
+        /// All fees are set in XRD.
+        ///
+        /// # Parameters
+        /// - `open`: Fees for opening an account.
+        /// - `close`: Fees for closing an account.
+        /// - `execute`: Fees for entering or exiting a position.
+        pub fn set_fee_structure(&mut self, open: Option<Decimal>, close: Option<Decimal>, execute: Option<Decimal>) {
+            self.fee_structure.set(open, close, execute);
+        }
+
+        /// Collects fees from the fee vault.
+        ///
+        /// # Returns
+        /// - A `FungibleBucket` containing the collected fees.
+        pub fn collect_fees(&mut self) -> FungibleBucket {
+            self.fee_vault.take_all()
+        }
+
+        //] ----------------- Accounts ----------------- */
+        /// Opens an account for a user on the cluster. Deposits CDP into the `accounts` KV, in a corresponding vault.
+        ///
+        /// # Parameters
+        /// - `user_badge`: Proof of the user's badge from the platform.
+        /// - `cdp`: Weft CDP input.
+        /// - `fee_payment`: A `FungibleBucket` containing the payment for opening the account.
+        ///
+        /// # Panics
+        /// - If the cluster is not linked.
+        /// - If the ClusterService::OpenAccount is disabled.
+        /// - If the platform is paused.
+        /// - If the user already has an open account.
+        /// - If the CDP is invalid.
+        ///
+        /// # Returns
+        /// A `FungibleBucket` containing the remainder of the fee.
+        pub fn open_account(&mut self, user_badge: NonFungibleProof, cdp: NonFungibleBucket, mut fee_payment: FungibleBucket) -> FungibleBucket {
+            // Check operating service
+            assert!(
+                self.services.get(ClusterService::OpenAccount).value,
+                "ClusterService::OpenAccount disabled"
+            );
+            assert!(!self.__platform_paused(), "Platform paused");
+
+            // Validate own link badge
+            assert_eq!(self.link.amount(), dec!(1), "Cluster does not have a link badge");
+
+            // Validate the CDP
+            assert_eq!(cdp.amount(), dec!(1), "Invalid CDP amount; must contain 1 NFT");
+            assert_eq!(cdp.resource_address(), self.cdp_manager.address(), "Invalid CDP resource address");
+
+            let cdp_id = cdp.non_fungible_local_id();
+            let cdp_valid = self.__validate_cdp(cdp_id.clone());
+            assert!(cdp_valid, "Invalid CDP");
+
+            // Take fee payment
+            let fee = self.fee_structure.open;
+            self.fee_vault.put(fee_payment.take(fee));
+
+            // Update the user's badge
+            let valid_user = self.__validate_user(user_badge);
+            let user_id = valid_user.non_fungible_local_id();
+            self.__with_link(|platform, link_badge| platform.call_raw("open_account", scrypto_args!(link_badge, user_id.clone())));
+
+            if self.accounts.get(&user_id).is_some() {
+                let mut account = self.accounts.get_mut(&user_id).unwrap();
+                assert!(account.cdp_vault.amount() == dec!(0), "User already has an account");
+
+                account.cdp_vault.put(cdp);
+            } else {
+                let account = AccountData::new(NonFungibleVault::with_bucket(cdp), self.lp_resource);
+                self.accounts.insert(user_id, account);
+            }
+
+            // Update the account count
+            self.account_count += 1;
+            fee_payment
+        }
+
+        /// Closes an account for a user on the cluster, and withdraws CDP. The position must be exited first.
+        ///
+        /// # Parameters
+        /// - `user_badge`: Proof of the user's badge from the platform.
+        /// - `fee_payment`: A `FungibleBucket` containing the payment for closing the account.
+        ///
+        /// # Panics
+        /// - If the cluster is not linked.
+        /// - If the ClusterService::CloseAccount is disabled.
+        /// - If the platform is paused.
+        /// - If the user does not have an open account, or still holds LP tokens.
+        /// - If the fee payment is invalid (wrong type, insufficient amount).
+        ///
+        /// # Returns
+        /// - A `NonFungibleBucket` containing the CDP.
+        /// - A `FungibleBucket` containing the remainder of the fee.
+        pub fn close_account(&mut self, user_badge: NonFungibleProof, mut fee_payment: FungibleBucket) -> (NonFungibleBucket, FungibleBucket) {
+            // Check operating service
+            assert!(
+                self.services.get(ClusterService::CloseAccount).value,
+                "ClusterService::CloseAccount disabled"
+            );
+            assert!(!self.__platform_paused(), "Platform paused");
+            assert!(self.account_count > 0, "No accounts to close");
+
+            // Validate own link badge
+            assert_eq!(self.link.amount(), dec!(1), "Cluster does not have a link badge");
+
+            // Validate the user
+            let valid_user = self.__validate_user(user_badge);
+            let user_id = valid_user.non_fungible_local_id();
+
+            // Validate the fee
+            let fee = self.fee_structure.close;
+            self.fee_vault.put(fee_payment.take(fee));
+
+            // Extract the CDP and remove it from the cluster
+            let cdp_bucket = {
+                let mut account = self.accounts.get_mut(&user_id).expect("User has no open account");
+                assert!(account.lp_vault.is_empty(), "Position must be exited before closing");
+
+                account.cdp_vault.take_all()
+            };
+            assert_eq!(cdp_bucket.amount(), dec!(1), "User has no open account");
+
+            // Update the user's badge
+            self.__with_link(|platform, link_badge| platform.call_raw("close_account", scrypto_args!(link_badge, user_id)));
+
+            // Remove the account
+            self.account_count -= 1;
+            (cdp_bucket, fee_payment)
+        }
+
+        /// Returns general information about an account, including its net exposure to the volatile asset.
+        /// Queried from Weft using their `get_cdp` method, and from the DEX pool's reserves.
+        ///
+        /// # Parameters
+        /// - `local_id`: The local ID of the account to query.
+        ///
+        /// # Returns
+        /// - A `AccountInfo` struct with the account's information.
+        pub fn get_account_info(&self, local_id: NonFungibleLocalId) -> AccountInfo {
+            let account = self.accounts.get(&local_id).expect("User has no open account");
+
+            let cdp_id = account.cdp_vault.non_fungible_local_id();
+            let lp_amount = account.lp_vault.amount();
+            let exposure = self.__exposure(&cdp_id, lp_amount);
+
+            // Construct and emit the account info
+            AccountInfo {
+                cdp_id,
+                collateral: exposure.collateral,
+                debt: exposure.debt,
+                lp_amount,
+                lp_stable: exposure.lp_stable,
+                lp_volatile: exposure.lp_volatile,
+                delta: exposure.delta(),
+                delta_value: exposure.delta() * exposure.price,
+                equity: exposure.equity(),
+                health: exposure.health,
+            }
+        }
+
+        //] ------------------ Hedging ----------------- */
+        /// Enters (or increases) a hedged position. The stable deposit is split so that, at the cluster's target
+        /// LTV, the volatile asset borrowed against the collateral part matches the stable left to pair with it
+        /// in the DEX pool.
+        ///
+        /// # Parameters
+        /// - `user_badge`: Proof of the user's badge from the platform.
+        /// - `deposit`: A `FungibleBucket` containing the stable asset.
+        /// - `fee_payment`: A `FungibleBucket` containing the payment for the entry.
+        ///
+        /// # Panics
+        /// - If the ClusterService::Enter is disabled.
+        /// - If the platform is paused.
+        /// - If the user does not have an open account.
+        /// - If the deposit is invalid (wrong type, empty).
+        /// - If no oracle is configured, or the DEX pool's price deviates from the oracle's beyond the slippage.
+        ///
+        /// # Returns
+        /// - A `FungibleBucket` containing the remainder of the fee.
+        pub fn enter(&mut self, user_badge: NonFungibleProof, mut deposit: FungibleBucket, mut fee_payment: FungibleBucket) -> FungibleBucket {
+            // Check operating service
+            assert!(self.services.get(ClusterService::Enter).value, "ClusterService::Enter disabled");
+            assert!(!self.__platform_paused(), "Platform paused");
+
+            // Validate the input
+            assert_eq!(deposit.resource_address(), self.stable, "Invalid deposit resource address");
+            assert!(deposit.amount() > dec!(0), "No deposit provided");
+
+            let user_id = self.__validate_user(user_badge).non_fungible_local_id();
+
+            // Take fee payment
+            let fee = self.fee_structure.execute;
+            self.fee_vault.put(fee_payment.take(fee));
+
+            // Withdraw the CDP to prove ownership to Weft
+            let cdp = self.accounts.get_mut(&user_id).expect("User has no open account").cdp_vault.take_all();
+            assert_eq!(cdp.amount(), dec!(1), "User has no open account");
+
+            let weft_market = WeftMarket::new(self.weft_market_address);
+            let dex = Dex::new(self.dex_address);
+
+            // Split the deposit between collateral and the stable side of the LP
+            let ltv = self.hedge_config.target_ltv;
+            let paired = deposit.take(deposit.amount() * ltv / (dec!(1) + ltv));
+
+            let price = self.__price();
+            self.__assert_pool_price(&dex, price);
+            let borrow = paired.amount() / price;
+
+            // Supply, borrow and provide liquidity
+            weft_market.add_collateral(cdp.create_proof_of_all(), vec![deposit.into()]);
+
+            let mut loans = weft_market.borrow(cdp.create_proof_of_all(), vec![(self.volatile, borrow)]);
+            let volatile = loans.pop().expect("No volatile asset borrowed");
+
+            let (lp, remainder) = dex.add_liquidity(paired.into(), volatile);
+
+            // Return any unpaired input to the position
+            if let Some(remainder) = remainder {
+                self.__settle(&weft_market, &cdp, remainder, price);
+            }
+
+            // Return the LP tokens and CDP
+            let mut account = self.accounts.get_mut(&user_id).unwrap();
+            account.lp_vault.put(lp.as_fungible());
+            account.cdp_vault.put(cdp);
+
+            fee_payment
+        }
+
+        /// Exits a hedged position in full. Redeems the LP tokens, repays the volatile debt (swapping stable for any
+        /// shortfall), and withdraws the stable collateral.
+        ///
+        /// # Parameters
+        /// - `user_badge`: Proof of the user's badge from the platform.
+        /// - `fee_payment`: A `FungibleBucket` containing the payment for the exit.
+        ///
+        /// # Panics
+        /// - If the ClusterService::Exit is disabled.
+        /// - If the platform is paused.
+        /// - If the user does not have an open account.
+        /// - If the redeemed liquidity cannot cover the debt.
+        /// - If no oracle is configured, the DEX pool's price deviates from the oracle's, or a swap slips beyond the slippage.
+        ///
+        /// # Returns
+        /// - A `FungibleBucket` containing the stable asset.
+        /// - A `FungibleBucket` containing the remainder of the fee.
+        pub fn exit(&mut self, user_badge: NonFungibleProof, mut fee_payment: FungibleBucket) -> (FungibleBucket, FungibleBucket) {
+            // Check operating service
+            assert!(self.services.get(ClusterService::Exit).value, "ClusterService::Exit disabled");
+            assert!(!self.__platform_paused(), "Platform paused");
+
+            let user_id = self.__validate_user(user_badge).non_fungible_local_id();
+
+            // Take fee payment
+            let fee = self.fee_structure.execute;
+            self.fee_vault.put(fee_payment.take(fee));
+
+            // Withdraw the CDP and LP tokens
+            let (cdp, lp) = {
+                let mut account = self.accounts.get_mut(&user_id).expect("User has no open account");
+                (account.cdp_vault.take_all(), account.lp_vault.take_all())
+            };
+            assert_eq!(cdp.amount(), dec!(1), "User has no open account");
+
+            let weft_market = WeftMarket::new(self.weft_market_address);
+            let dex = Dex::new(self.dex_address);
+
+            // Redeem the liquidity
+            let price = self.__price();
+            let (mut stable, mut volatile) = match lp.amount() > dec!(0) {
+                true => {
+                    self.__assert_pool_price(&dex, price);
+                    self.__sort(dex.remove_liquidity(lp.into()))
+                }
+                false => (FungibleBucket::new(self.stable).into(), FungibleBucket::new(self.volatile).into()),
+            };
+
+            // Swap stable for any shortfall of the volatile asset
+            let debt = self.__debt(&weft_market, &cdp);
+            if volatile.amount() < debt {
+                let missing = debt - volatile.amount();
+                let shortfall = missing * price * (dec!(1) + self.hedge_config.exit_slippage);
+                assert!(stable.amount() >= shortfall, "Insufficient liquidity to repay the debt");
+
+                volatile.put(dex.swap(stable.take(shortfall), missing));
+            }
+
+            // Repay the debt, and swap back any excess
+            let excess = match debt > dec!(0) {
+                true => weft_market.repay(cdp.create_proof_of_all(), vec![volatile]),
+                false => vec![volatile],
+            };
+            for excess in excess.into_iter().filter(|excess| !excess.is_empty()) {
+                let min_output = self.__min_output(excess.amount() * price);
+                stable.put(dex.swap(excess, min_output));
+            }
+
+            // Withdraw the collateral
+            let collateral = self.__collateral(&weft_market, &cdp);
+            if collateral > dec!(0) {
+                for withdrawn in weft_market.remove_collateral(cdp.create_proof_of_all(), vec![(self.stable, collateral, false)]) {
+                    stable.put(withdrawn);
+                }
+            }
+
+            // Return the CDP
+            self.accounts.get_mut(&user_id).unwrap().cdp_vault.put(cdp);
+
+            (stable.as_fungible(), fee_payment)
+        }
+
+        /// Rebalances the user's account, whose net exposure to the volatile asset has drifted outside the delta band.
+        ///
+        /// # Parameters
+        /// - `user_badge`: Proof of the user's badge from the platform.
+        ///
+        /// # Panics
+        /// - If the ClusterService::Rebalance is disabled.
+        /// - If the platform is paused.
+        /// - If the user does not have an open account.
+        /// - If the account's net exposure is within the delta band.
+        /// - If no oracle is configured, or a swap slips beyond the slippage.
+        pub fn rebalance(&mut self, user_badge: NonFungibleProof) {
+            let user_id = self.__validate_user(user_badge).non_fungible_local_id();
+
+            self.__rebalance(user_id);
+        }
+
+        /// Rebalances an account on behalf of its user, e.g. by a keeper; see `rebalance`.
+        ///
+        /// # Parameters
+        /// - `user_id`: The local ID of the user's badge.
+        pub fn rebalance_account(&mut self, user_id: NonFungibleLocalId) {
+            self.__rebalance(user_id);
+        }
+
+        //] Private
+        /// Brings an account's net exposure back to zero. A long exposure is reduced by borrowing the excess volatile
+        /// asset and selling it for stable collateral; a short exposure by withdrawing stable collateral, buying the
+        /// missing volatile asset and repaying the debt with it.
+        fn __rebalance(&mut self, user_id: NonFungibleLocalId) {
+            // Check operating service
+            assert!(self.services.get(ClusterService::Rebalance).value, "ClusterService::Rebalance disabled");
+            assert!(!self.__platform_paused(), "Platform paused");
+
+            // Withdraw the CDP
+            let cdp = self.accounts.get_mut(&user_id).expect("User has no open account").cdp_vault.take_all();
+            assert_eq!(cdp.amount(), dec!(1), "User has no open account");
+
+            let lp_amount = self.accounts.get(&user_id).unwrap().lp_vault.amount();
+            let exposure = self.__exposure(&cdp.non_fungible_local_id(), lp_amount);
+
+            // Check the delta band
+            let delta = exposure.delta();
+            assert!(
+                (delta * exposure.price).checked_abs().unwrap() > exposure.equity() * self.hedge_config.delta_band,
+                "Delta within band"
+            );
+
+            let weft_market = WeftMarket::new(self.weft_market_address);
+            let dex = Dex::new(self.dex_address);
+
+            if delta > dec!(0) {
+                // Long: borrow the excess and hold it as stable collateral
+                let mut loans = weft_market.borrow(cdp.create_proof_of_all(), vec![(self.volatile, delta)]);
+                let volatile = loans.pop().expect("No volatile asset borrowed");
+
+                let stable = dex.swap(volatile, self.__min_output(delta * exposure.price));
+                weft_market.add_collateral(cdp.create_proof_of_all(), vec![stable]);
+            } else {
+                // Short: sell collateral for the missing volatile asset, and repay the debt with it
+                let stable = (-delta) * exposure.price;
+                let mut withdrawn = weft_market.remove_collateral(cdp.create_proof_of_all(), vec![(self.stable, stable, false)]);
+                let volatile = dex.swap(withdrawn.pop().expect("No collateral withdrawn"), self.__min_output(-delta));
+
+                for excess in weft_market.repay(cdp.create_proof_of_all(), vec![volatile]) {
+                    self.__settle(&weft_market, &cdp, excess, exposure.price);
+                }
+            }
+
+            // Return the CDP
+            self.accounts.get_mut(&user_id).unwrap().cdp_vault.put(cdp);
+        }
+
+        /// Validates the user's badge and returns the checked proof.
+        fn __validate_user(&self, user_badge: NonFungibleProof) -> CheckedNonFungibleProof {
+            let valid_user = user_badge.check_with_message(self.user_resource, "User badge not valid");
+            assert_eq!(valid_user.amount(), dec!(1), "Invalid user badge quantity");
+
+            valid_user
+        }
+
+        /// Price of the volatile asset in the stable asset, from the cluster's oracle.
+        fn __price(&self) -> Decimal {
+            self.oracle.as_ref().expect("No oracle configured").get_price(self.volatile, self.stable)
+        }
+
+        /// Asserts that the DEX pool's spot price is within the slippage of the oracle's, so liquidity is not
+        /// provided to or redeemed from a manipulated pool.
+        fn __assert_pool_price(&self, dex: &Dex, price: Decimal) {
+            let reserves = dex.reserves();
+            let volatile = *reserves.get(&self.volatile).expect("Pool does not hold the volatile asset");
+            let stable = *reserves.get(&self.stable).expect("Pool does not hold the stable asset");
+            assert!(volatile > dec!(0), "Pool has no liquidity");
+
+            let deviation = (stable / volatile / price - dec!(1)).checked_abs().unwrap();
+            assert!(deviation <= self.hedge_config.exit_slippage, "DEX pool price deviates from the oracle");
+        }
+
+        /// Minimum output of a swap expected to return `expected` at the oracle's price.
+        fn __min_output(&self, expected: Decimal) -> Decimal {
+            expected * (dec!(1) - self.hedge_config.exit_slippage)
+        }
+
+        /// Orders a pair of buckets from the DEX pool as (stable, volatile).
+        fn __sort(&self, (a, b): (Bucket, Bucket)) -> (Bucket, Bucket) {
+            match a.resource_address() == self.stable {
+                true => (a, b),
+                false => (b, a),
+            }
+        }
+
+        /// Puts a leftover bucket back to work: stable is added as collateral, the volatile asset repays the debt.
+        fn __settle(&self, weft_market: &WeftMarket, cdp: &NonFungibleBucket, bucket: Bucket, price: Decimal) {
+            if bucket.is_empty() {
+                return;
+            }
+
+            if bucket.resource_address() == self.stable {
+                weft_market.add_collateral(cdp.create_proof_of_all(), vec![bucket]);
+            } else {
+                let excess = weft_market.repay(cdp.create_proof_of_all(), vec![bucket]);
+                let dex = Dex::new(self.dex_address);
+
+                for excess in excess.into_iter().filter(|excess| !excess.is_empty()) {
+                    let min_output = self.__min_output(excess.amount() * price);
+                    weft_market.add_collateral(cdp.create_proof_of_all(), vec![dex.swap(excess, min_output)]);
+                }
+            }
+        }
+
+        /// Returns the stable collateral of the CDP.
+        fn __collateral(&self, weft_market: &WeftMarket, cdp: &NonFungibleBucket) -> Decimal {
+            let cdp_health = weft_market.get_cdp(&cdp.non_fungible_local_id());
+
+            match cdp_health.collateral_positions.get(&self.stable) {
+                Some(collateral) => collateral.amount,
+                None => dec!(0),
+            }
+        }
+
+        /// Returns the volatile debt of the CDP, including accrued interest.
+        fn __debt(&self, weft_market: &WeftMarket, cdp: &NonFungibleBucket) -> Decimal {
+            let cdp_health = weft_market.get_cdp(&cdp.non_fungible_local_id());
+
+            match cdp_health.loan_positions.get(&self.volatile) {
+                Some(loan) => loan.amount,
+                None => dec!(0),
+            }
+        }
+
+        /// Returns the positions of an account across Weft and the DEX pool.
+        fn __exposure(&self, cdp_id: &NonFungibleLocalId, lp_amount: Decimal) -> Exposure {
+            let cdp_health = WeftMarket::new(self.weft_market_address).get_cdp(cdp_id);
+            let dex = Dex::new(self.dex_address);
+
+            let collateral = match cdp_health.collateral_positions.get(&self.stable) {
+                Some(collateral) => collateral.amount,
+                None => dec!(0),
+            };
+            let debt = match cdp_health.loan_positions.get(&self.volatile) {
+                Some(loan) => loan.amount,
+                None => dec!(0),
+            };
+
+            // Share of the pool's reserves held through the LP tokens
+            let lp_supply = ResourceManager::from(self.lp_resource).total_supply().unwrap_or(dec!(0));
+            let reserves = dex.reserves();
+
+            let (lp_stable, lp_volatile) = match lp_supply > dec!(0) {
+                true => (
+                    reserves.get(&self.stable).copied().unwrap_or(dec!(0)) * lp_amount / lp_supply,
+                    reserves.get(&self.volatile).copied().unwrap_or(dec!(0)) * lp_amount / lp_supply,
+                ),
+                false => (dec!(0), dec!(0)),
+            };
+
+            Exposure {
+                collateral,
+                debt,
+                lp_stable,
+                lp_volatile,
+                price: self.__price(),
+                health: cdp_health.liquidation_ltv,
+            }
+        }
+
+        //] ------------------- Weft ------------------- */
+        /// Validates the given CDP by checking its contents.
+        ///
+        /// # Parameters
+        /// - `local_id`: The local ID of the CDP to validate.
+        ///
+        /// # Returns
+        /// - `true` if the CDP is valid; otherwise, `false`.
+        ///
+        /// # Validation Criteria
+        /// - The CDP must have a valid ResourceAddress.
+        /// - The CDP must not have any NFT collaterals.
+        /// - The only collateral asset in the CDP must be the stable asset.
+        /// - The only debt asset in the CDP must be the volatile asset.
+        fn __validate_cdp(&self, local_id: NonFungibleLocalId) -> bool {
+            // Parse CDP data or return false if fetching the data panics
+            // Panic occurs if the cdp_manager cannot find an NFT with a matching local_id
+            let cdp: CDPData = match catch_unwind(|| self.cdp_manager.get_non_fungible_data::<CDPData>(&local_id)) {
+                Ok(cdp) => cdp,
+                Err(_) => {
+                    info!("Error parsing CDP with local_id {:?}", local_id);
+                    return false;
+                }
+            };
+
+            // Validate that there are no NFT collaterals
+            if !cdp.nft_collaterals.is_empty() {
+                info!("CDP with local_id {:?} has NFT collateral(s)", local_id);
+                return false;
+            }
+
+            // Validate that all supply and debt assets are valid
+            if cdp.collaterals.keys().any(|&resource| resource != self.stable) {
+                info!("CDP with local_id {:?} has an invalid collateral asset", local_id);
+                return false;
+            }
+
+            if cdp.loans.keys().any(|&resource| resource != self.volatile) {
+                info!("CDP with local_id {:?} has an invalid debt asset", local_id);
+                return false;
+            }
+
+            true
+        }
+    }
+}
+
+/* ----------------- Exposure ----------------- */
+/// Positions of an account across Weft and the DEX pool, with the volatile asset priced in the stable asset.
+struct Exposure {
+    collateral: Decimal,
+    debt: Decimal,
+    lp_stable: Decimal,
+    lp_volatile: Decimal,
+    price: Decimal,
+    health: Decimal,
+}
+
+impl Exposure {
+    /// Net exposure to the volatile asset.
+    fn delta(&self) -> Decimal {
+        self.lp_volatile - self.debt
+    }
+
+    /// Net value of the account, in the stable asset.
+    fn equity(&self) -> Decimal {
+        self.collateral + self.lp_stable + self.delta() * self.price
+    }
+}
@@ -0,0 +1,43 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+
+/* -------------------- Dex ------------------- */
+/// Thin wrapper around a two-resource DEX pool (e.g. an Ociswap basic pool), backed by a native `TwoResourcePool`.
+pub struct Dex {
+    component: Global<AnyComponent>,
+}
+
+impl Dex {
+    pub fn new(address: ComponentAddress) -> Self {
+        Self { component: address.into() }
+    }
+
+    /// Swaps the input for the other resource of the pool.
+    ///
+    /// # Panics
+    /// - If the output is below `min_output`.
+    pub fn swap(&self, input: Bucket, min_output: Decimal) -> Bucket {
+        let output: Bucket = self.component.call_raw("swap", scrypto_args!(input));
+        assert!(output.amount() >= min_output, "Swap output below the minimum");
+
+        output
+    }
+
+    /// Provides liquidity to the pool, returning the LP tokens and any unused input.
+    pub fn add_liquidity(&self, a: Bucket, b: Bucket) -> (Bucket, Option<Bucket>) {
+        self.component.call_raw("add_liquidity", scrypto_args!(a, b))
+    }
+
+    /// Redeems LP tokens for the underlying resources.
+    pub fn remove_liquidity(&self, lp: Bucket) -> (Bucket, Bucket) {
+        self.component.call_raw("remove_liquidity", scrypto_args!(lp))
+    }
+
+    /// Returns the reserves of the pool, per resource.
+    pub fn reserves(&self) -> IndexMap<ResourceAddress, Decimal> {
+        let pool_address = self.component.call_raw::<ComponentAddress>("liquidity_pool", scrypto_args!());
+        let pool: Global<TwoResourcePool> = pool_address.into();
+
+        pool.get_vault_amounts()
+    }
+}
@@ -0,0 +1,83 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+use shared::oracles::PriceOracle;
+
+/* ------------------ Cluster ----------------- */
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct ClusterInfo {
+    pub platform_address: ComponentAddress,
+    pub cluster_address: ComponentAddress,
+    pub linked: bool,
+    pub stable_res: ResourceAddress,
+    pub volatile_res: ResourceAddress,
+    pub dex_address: ComponentAddress,
+    pub lp_res: ResourceAddress,
+    pub account_count: u64,
+    pub hedge_config: HedgeConfig,
+    pub oracle: Option<PriceOracle>,
+    pub fee_info: FeeInfo,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct FeeInfo {
+    pub open: Decimal,
+    pub close: Decimal,
+    pub execute: Decimal,
+}
+
+/* --------------- Hedge Config --------------- */
+/// Parameters of the hedge.
+#[derive(ScryptoSbor, Debug, Clone, Copy)]
+pub struct HedgeConfig {
+    /// Value of the volatile debt per unit of stable collateral on entry; e.g. `0.5` for 50%.
+    pub target_ltv: Decimal,
+    /// Maximum net exposure to the volatile asset, as a share of the account's equity, before a rebalance is allowed.
+    pub delta_band: Decimal,
+    /// Maximum slippage of a swap from the oracle's price, and of the DEX pool's price before providing or redeeming
+    /// liquidity; also the extra stable swapped on exit to cover a shortfall of the volatile asset.
+    pub exit_slippage: Decimal,
+}
+
+impl Default for HedgeConfig {
+    fn default() -> Self {
+        Self { target_ltv: dec!(0.5), delta_band: dec!(0.05), exit_slippage: dec!(0.02) }
+    }
+}
+
+impl HedgeConfig {
+    /// Creates a new hedge config.
+    ///
+    /// # Panics
+    /// - If the target LTV is not within (0, 1).
+    /// - If the delta band or exit slippage is not within [0, 1).
+    pub fn new(target_ltv: Decimal, delta_band: Decimal, exit_slippage: Decimal) -> Self {
+        assert!(target_ltv > dec!(0) && target_ltv < dec!(1), "Target LTV must be between 0 and 1");
+        assert!(delta_band >= dec!(0) && delta_band < dec!(1), "Delta band must be between 0 and 1");
+        assert!(
+            exit_slippage >= dec!(0) && exit_slippage < dec!(1),
+            "Exit slippage must be between 0 and 1"
+        );
+
+        Self { target_ltv, delta_band, exit_slippage }
+    }
+}
+
+/* ------------------ Account ----------------- */
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct AccountInfo {
+    pub cdp_id: NonFungibleLocalId,
+    /// Stable supplied as collateral.
+    pub collateral: Decimal,
+    /// Volatile asset borrowed, including accrued interest.
+    pub debt: Decimal,
+    pub lp_amount: Decimal,
+    pub lp_stable: Decimal,
+    pub lp_volatile: Decimal,
+    /// Net exposure to the volatile asset; the volatile asset in the LP less the debt.
+    pub delta: Decimal,
+    /// Net exposure to the volatile asset, valued in the stable.
+    pub delta_value: Decimal,
+    /// Net value of the account, valued in the stable.
+    pub equity: Decimal,
+    pub health: Decimal,
+}
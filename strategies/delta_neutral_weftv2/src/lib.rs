@@ -0,0 +1,10 @@
+// Scrypto's generated test bindings take an extra `env` argument beyond each function's own
+#![allow(clippy::too_many_arguments)]
+
+pub mod accounts;
+pub mod cluster;
+pub mod dex;
+pub mod fees;
+pub mod info;
+pub mod services;
+pub mod weft;
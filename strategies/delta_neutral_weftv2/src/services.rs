@@ -0,0 +1,33 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+use shared::services::{ServiceKey, ServiceRegistry};
+
+/* ------------ Operating Services ------------ */
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ClusterService {
+    OpenAccount,
+    CloseAccount,
+    Enter,
+    Exit,
+    Rebalance,
+    Link,
+    // Unlink,
+    CallLinked,
+}
+
+impl ServiceKey for ClusterService {
+    fn all() -> Vec<Self> {
+        vec![
+            ClusterService::OpenAccount,
+            ClusterService::CloseAccount,
+            ClusterService::Enter,
+            ClusterService::Exit,
+            ClusterService::Rebalance,
+            ClusterService::Link,
+            // ClusterService::Unlink,
+            ClusterService::CallLinked,
+        ]
+    }
+}
+
+pub type ClusterServiceManager = ServiceRegistry<ClusterService>;
@@ -0,0 +1,7 @@
+/* ------------------ Imports ----------------- */
+use delta_neutral_weftv2::cluster::delta_neutral_weftv2_cluster_test::*;
+use scrypto_test::prelude::*;
+
+/* ------------------- Tests ------------------ */
+#[test]
+fn test() {}
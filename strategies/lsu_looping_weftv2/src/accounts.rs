@@ -0,0 +1,63 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+use shared::utils::now;
+
+/* ------------------- User ------------------- */
+#[derive(ScryptoSbor, Debug)]
+pub struct AccountData {
+    pub cdp_vault: NonFungibleVault,
+    pub position: Option<LoopPosition>,
+}
+
+impl AccountData {
+    pub fn new(cdp_vault: NonFungibleVault) -> Self {
+        Self { cdp_vault, position: None }
+    }
+}
+
+/* ----------------- Position ----------------- */
+/// Record of a user's looped position, used to report the staking yield against the borrow cost.
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct LoopPosition {
+    /// LSU deposited by the user, excluding the LSU minted from borrowed XRD.
+    pub deposited: Decimal,
+    /// XRD borrowed, excluding accrued interest.
+    pub principal: Decimal,
+    /// Redemption value of one LSU, in XRD, when the position was entered; averaged over entries.
+    pub entry_rate: Decimal,
+    /// Time at which the position was entered, in seconds since the unix epoch; averaged over entries.
+    pub entered_at: Decimal,
+}
+
+impl LoopPosition {
+    pub fn new(entry_rate: Decimal) -> Self {
+        Self {
+            deposited: dec!(0),
+            principal: dec!(0),
+            entry_rate,
+            entered_at: Decimal::from(now()),
+        }
+    }
+
+    /// Adds an entry to the position. The entry rate and time are reset to their averages weighted by the LSU
+    /// deposited, so the yields reported since entry cover every entry rather than only the first.
+    pub fn add(&mut self, deposited: Decimal, borrowed: Decimal, rate: Decimal) {
+        let total = self.deposited + deposited;
+
+        if total > dec!(0) {
+            let weight = deposited / total;
+
+            self.entry_rate += (rate - self.entry_rate) * weight;
+            self.entered_at += (Decimal::from(now()) - self.entered_at) * weight;
+        }
+
+        self.deposited = total;
+        self.principal += borrowed;
+    }
+
+    /// Reduces the position by the given share, between 0 and 1, as it is exited.
+    pub fn reduce(&mut self, share: Decimal) {
+        self.deposited *= dec!(1) - share;
+        self.principal *= dec!(1) - share;
+    }
+}
@@ -0,0 +1,816 @@
+/* ------------------ Imports ----------------- */
+// Modules
+use crate::accounts::{AccountData, LoopPosition};
+use crate::fees::FeeStructure;
+use crate::info::{AccountInfo, ClusterInfo, FeeInfo, LoopConfig};
+use crate::services::{ClusterService, ClusterServiceManager};
+use crate::weft::*;
+// Shared Modules
+use shared::links::Link;
+use shared::services::{ServicePolicy, ServiceValue, SetLock};
+use shared::utils::{now, SECONDS_PER_YEAR};
+// Libraries
+use scrypto::prelude::*;
+use std::panic::catch_unwind;
+
+/* ----------------- Blueprint ---------------- */
+type Unit = ();
+
+#[blueprint]
+#[types(
+    // General
+    Unit,
+    ComponentAddress,
+    ResourceAddress,
+    NonFungibleResourceManager,
+    NonFungibleLocalId,
+    NonFungibleVault,
+    FungibleVault,
+    Decimal,
+    u8,
+    u64,
+    i64,
+    FeeStructure,
+    LoopConfig,
+    LoopPosition,
+    // Services
+    ClusterServiceManager,
+    ClusterService,
+    ServiceValue,
+    ServicePolicy,
+    SetLock,
+    // State Returns
+    AccountInfo,
+    ClusterInfo,
+    FeeInfo,
+    // WeftV2 Integration
+    CDPData,
+    CDPHealthChecker,
+    LoanPositionData,
+    LoanConfig,
+    LoanResourceConfig,
+    CollateralPositionData,
+    CollateralConfig,
+    CollateralResourceConfig,
+    RegisteredResourceType,
+    NFTCollateralPositionData,
+    NFTLiquidationValue,
+    RegisteredNFTResourceType,
+    EfficiencyMode,
+    CollateralConfigVersion,
+    CollateralInfo,
+    NFTCollateralInfo,
+    LoanInfo
+)]
+mod lsu_looping_weftv2_cluster {
+    //] --------------- Scrypto Setup -------------- */
+    enable_method_auth! {
+        roles {
+            can_manage_services => updatable_by: [OWNER];
+            can_lock_services   => updatable_by: [OWNER];
+            can_manage_fees     => updatable_by: [OWNER];
+        },
+        methods {
+            // Links
+            handle_link => PUBLIC;
+            // Cluster
+            get_cluster_info     => PUBLIC;
+            get_claimed_entities => PUBLIC;
+            get_services         => PUBLIC;
+            update_service              => restrict_to: [can_manage_services, can_lock_services];
+            update_service_and_set_lock => restrict_to: [can_lock_services];
+            set_fee_structure           => restrict_to: [can_manage_fees];
+            collect_fees                => restrict_to: [can_manage_fees];
+            set_loop_config             => restrict_to: [can_manage_services, OWNER];
+            // Accounts
+            open_account     => PUBLIC;
+            close_account    => PUBLIC;
+            get_account_info => PUBLIC;
+            // Looping
+            enter => PUBLIC;
+            exit  => PUBLIC;
+        }
+    }
+
+    //] ------------- Cluster Blueprint ------------ */
+    struct LsuLoopingWeftV2Cluster {
+        // Authorisation
+        component_address: ComponentAddress,
+        // Platform link
+        platform_address: ComponentAddress,
+        link: NonFungibleVault,
+        user_resource: ResourceAddress,
+        // Cluster
+        validator: Global<Validator>,
+        lsu: ResourceAddress,
+        loop_config: LoopConfig,
+        services: ClusterServiceManager,
+        // Accounts
+        accounts: KeyValueStore<NonFungibleLocalId, AccountData>,
+        account_count: u64,
+        // Fees
+        fee_structure: FeeStructure,
+        fee_vault: FungibleVault,
+        // Integration
+        weft_market_address: ComponentAddress,
+        cdp_manager: NonFungibleResourceManager,
+    }
+
+    impl LsuLoopingWeftV2Cluster {
+        /// Instantiates a new `LsuLoopingWeftV2Cluster` component, looping the LSU of a single validator against XRD.
+        ///
+        /// # Parameters
+        /// - `owner_rule`: Access rule defining the owner of the cluster.
+        /// - `admin_rule`: Access rule defining the admins of the cluster.
+        /// - `platform_address`: The component address of the platform to which this cluster links.
+        /// - `validator`: The validator to which borrowed XRD is staked.
+        /// - `lsu`: Resource address of the validator's Liquid Stake Unit.
+        /// - `weft_market_address`: The component address of the WeftV2 lending market.
+        /// - `cdp_resource`: Resource address of the WeftV2 CDP NFT.
+        ///
+        /// # Panics
+        /// - If the LSU does not belong to the validator.
+        ///
+        /// # Returns
+        /// A globally accessible `LsuLoopingWeftV2Cluster` component instance.
+        pub fn instantiate(
+            // Authorisation
+            owner_rule: AccessRule,
+            admin_rule: AccessRule,
+            // Link
+            platform_address: ComponentAddress,
+            // Cluster
+            validator: ComponentAddress,
+            lsu: ResourceAddress,
+            // Integration
+            weft_market_address: ComponentAddress,
+            cdp_resource: ResourceAddress,
+        ) -> Global<LsuLoopingWeftV2Cluster> {
+            // Reserve component address
+            let (address_reservation, component_address) = Runtime::allocate_component_address(LsuLoopingWeftV2Cluster::blueprint_id());
+
+            let platform: Global<AnyComponent> = platform_address.into();
+            let link_resource = platform.call_raw::<ResourceAddress>("get_link_badge_address", scrypto_args!());
+            let user_resource = platform.call_raw::<ResourceAddress>("get_user_badge_address", scrypto_args!());
+            let dapp_definition_address: GlobalAddress = platform
+                .get_metadata("dapp_definition")
+                .unwrap_or(None)
+                .expect("Platform has no dApp definition");
+
+            // Validate the LSU against the validator
+            let lsu_validator: Option<GlobalAddress> = ResourceManager::from(lsu).get_metadata("validator").unwrap_or(None);
+            assert_eq!(
+                lsu_validator,
+                Some(GlobalAddress::from(validator)),
+                "LSU does not belong to the validator"
+            );
+
+            //] Authorisation
+            // Component owner
+            let owner_role: OwnerRole = OwnerRole::Fixed(owner_rule.clone());
+
+            //] Component Instantisation
+            // Metadata
+            let component_metadata = metadata! {
+                roles {
+                    metadata_setter         => OWNER;
+                    metadata_setter_updater => OWNER;
+                    metadata_locker         => OWNER;
+                    metadata_locker_updater => rule!(deny_all);
+                },
+                init {
+                    "name"            => "L3//LSU Looping - WeftV2", locked;
+                    "description"     => "Lattic3 cluster component for the LSU Looping strategy, built on top of the Weft V2 lending platform.", locked;
+                    "validator"       => GlobalAddress::from(validator), locked;
+                    "supply"          => lsu, locked;
+                    "debt"            => XRD, locked;
+                    "dapp_definition" => dapp_definition_address, updatable;
+                }
+            };
+
+            // Roles
+            let component_roles = roles! {
+                can_manage_fees     => OWNER;
+                can_manage_services => admin_rule;
+                can_lock_services   => OWNER;
+            };
+
+            // Instantisation
+            let initial_state = Self {
+                component_address,
+                platform_address,
+                link: NonFungibleVault::new(link_resource),
+                user_resource,
+                validator: validator.into(),
+                lsu,
+                loop_config: LoopConfig::default(),
+                accounts: KeyValueStore::new(),
+                account_count: 0,
+                services: ClusterServiceManager::new(ServicePolicy::DefaultOn),
+                fee_structure: FeeStructure::default(),
+                fee_vault: FungibleVault::new(XRD),
+                weft_market_address,
+                cdp_manager: cdp_resource.into(),
+            };
+
+            let component: Global<LsuLoopingWeftV2Cluster> = initial_state
+                .instantiate()
+                .prepare_to_globalize(owner_role)
+                .roles(component_roles)
+                .metadata(component_metadata)
+                .with_address(address_reservation)
+                .globalize();
+
+            component
+        }
+
+        //] ------------------- Links ------------------ */
+        /// Handles the reception of a link badge. Initiated by the platform's link_cluster() method.
+        ///
+        /// # Parameters
+        /// - `bucket`: The bucket containing the link badge.
+        ///
+        /// # Panics
+        /// - If the Link service is disabled
+        /// - If the link badge is invalid (amount != 1, incorrect resource address)
+        /// - If the cluster is already linked
+        pub fn handle_link(&mut self, bucket: NonFungibleBucket) {
+            // Check operating service
+            assert!(self.services.get(ClusterService::Link).value, "ClusterService::Link disabled");
+
+            // Sanity checks
+            assert_eq!(self.link.amount(), dec!(0), "Platform already linked");
+            assert_eq!(bucket.amount(), dec!(1), "Invalid bucket amount; must contain 1 link badge");
+            assert_eq!(
+                self.link.resource_address(),
+                bucket.resource_address(),
+                "Invalid link badge resource address"
+            );
+
+            // Link platform
+            self.link.put(bucket);
+        }
+
+        //] Private
+        /// Call a method on the platform; uses a closure with |platform, link_badge_proof|.
+        ///
+        /// # Parameters
+        /// - `func`: Closure with |platform, link_badge_proof|
+        ///
+        /// # Panics
+        /// - If the cluster is not linked
+        /// - If the CallLinked service is disabled
+        fn __with_link<F: FnOnce(Global<AnyComponent>, NonFungibleProof)>(&self, func: F) {
+            assert!(self.link.amount() > dec!(0), "Cluster not linked");
+            assert!(self.services.get(ClusterService::CallLinked).value, "ClusterService::CallLinked disabled");
+
+            // Arrange call
+            let link_local_id = self.link.non_fungible_local_id();
+            let link_badge = self.link.create_proof_of_non_fungibles(&indexset![link_local_id]);
+
+            let platform: Global<AnyComponent> = self.platform_address.into();
+
+            func(platform, link_badge);
+        }
+
+        /// Returns whether the linked platform has been paused; read from the `paused` flag of the link badge.
+        /// An unlinked cluster is never considered paused.
+        fn __platform_paused(&self) -> bool {
+            if self.link.amount() == dec!(0) {
+                return false;
+            }
+
+            let link: Link = self.link.non_fungible::<Link>().data();
+            link.paused
+        }
+
+        //] ------------------ Cluster ----------------- */
+        /// Returns the addresses of the cluster's entities; its component. They are labelled with the platform's
+        /// dApp definition, and are to be registered in its `claimed_entities`.
+        pub fn get_claimed_entities(&self) -> Vec<GlobalAddress> {
+            vec![self.component_address.into()]
+        }
+
+        /// Returns general information about the cluster.
+        ///
+        /// # Returns
+        /// A `ClusterInfo` struct containing the following information:
+        /// - `platform_address`: The component address of the platform to which this cluster links.
+        /// - `cluster_address`: The component address of the cluster.
+        /// - `linked`: A boolean indicating whether the cluster is linked.
+        /// - `validator`: The validator to which borrowed XRD is staked.
+        /// - `lsu_res`: The resource address of the validator's LSU.
+        /// - `account_count`: The number of accounts open on the cluster.
+        /// - `loop_config`: The parameters of the entry loop.
+        pub fn get_cluster_info(&self) -> ClusterInfo {
+            // Return the fee amounts
+            let fee_info = FeeInfo {
+                open: self.fee_structure.open,
+                close: self.fee_structure.close,
+                execute: self.fee_structure.execute,
+            };
+
+            ClusterInfo {
+                platform_address: self.platform_address,
+                cluster_address: self.component_address,
+                linked: self.link.amount() > dec!(0),
+                validator: self.validator.address(),
+                lsu_res: self.lsu,
+                account_count: self.account_count,
+                loop_config: self.loop_config,
+                fee_info,
+            }
+        }
+
+        //] Services
+        /// Returns every cluster service, alongside its value and lock state.
+        pub fn get_services(&self) -> IndexMap<ClusterService, ServiceValue> {
+            self.services.list()
+        }
+
+        /// Updates a cluster service, assuming it is not locked.
+        ///
+        /// # Parameters
+        /// - `service`: The service to update.
+        /// - `value`: The value to set the service to.
+        /// - `enable_at`: Optional time at which the service is enabled.
+        /// - `disable_at`: Optional time at which the service is disabled.
+        ///
+        /// # Panics
+        /// - If the service is currently locked.
+        pub fn update_service(&mut self, service: ClusterService, value: bool, enable_at: Option<Instant>, disable_at: Option<Instant>) {
+            self.services.update(service, value, enable_at, disable_at, SetLock::None);
+        }
+
+        /// Updates a cluster service and sets the lock state.
+        ///
+        /// # Parameters
+        /// - `service`: The service to update.
+        /// - `value`: The value to set the service to.
+        /// - `locked`: The value to which the lock status of the service is set to.
+        /// - `enable_at`: Optional time at which the service is enabled.
+        /// - `disable_at`: Optional time at which the service is disabled.
+        pub fn update_service_and_set_lock(
+            &mut self,
+            service: ClusterService,
+            value: bool,
+            locked: bool,
+            enable_at: Option<Instant>,
+            disable_at: Option<Instant>,
+        ) {
+            self.services.update(service, value, enable_at, disable_at, SetLock::Update(locked));
+        }
+
+        //] Loop Config
+        /// Sets the parameters of the entry loop.
+        ///
+        /// # Parameters
+        /// - `loop_ltv`: Share of the value of each new LSU deposit borrowed in the next loop, between 0 and 1.
+        /// - `max_loops`: Maximum number of borrow-and-stake loops in a single entry.
+        /// - `max_leverage`: Maximum target leverage accepted on entry.
+        ///
+        /// # Panics
+        /// - If any of the parameters is out of range.
+        pub fn set_loop_config(&mut self, loop_ltv: Decimal, max_loops: u8, max_leverage: Decimal) {
+            self.loop_config = LoopConfig::new(loop_ltv, max_loops, max_leverage);
+        }
+
+        //] Fees
+        /// Sets a new fee structure for the cluster.
+        /// All fees are set in XRD.
+        ///
+        /// # Parameters
+        /// - `open`: Fees for opening an account.
+        /// - `close`: Fees for closing an account.
+        /// - `execute`: Fees for entering or exiting a position.
+        pub fn set_fee_structure(&mut self, open: Option<Decimal>, close: Option<Decimal>, execute: Option<Decimal>) {
+            self.fee_structure.set(open, close, execute);
+        }
+
+        /// Collects fees from the fee vault.
+        ///
+        /// # Returns
+        /// - A `FungibleBucket` containing the collected fees.
+        pub fn collect_fees(&mut self) -> FungibleBucket {
+            self.fee_vault.take_all()
+        }
+
+        //] ----------------- Accounts ----------------- */
+        /// Opens an account for a user on the cluster. Deposits CDP into the `accounts` KV, in a corresponding vault.
+        ///
+        /// # Parameters
+        /// - `user_badge`: Proof of the user's badge from the platform.
+        /// - `cdp`: Weft CDP input.
+        /// - `fee_payment`: A `FungibleBucket` containing the payment for opening the account.
+        ///
+        /// # Panics
+        /// - If the cluster is not linked.
+        /// - If the ClusterService::OpenAccount is disabled.
+        /// - If the platform is paused.
+        /// - If the user already has an open account.
+        /// - If the CDP is invalid.
+        ///
+        /// # Returns
+        /// A `FungibleBucket` containing the remainder of the fee.
+        pub fn open_account(&mut self, user_badge: NonFungibleProof, cdp: NonFungibleBucket, mut fee_payment: FungibleBucket) -> FungibleBucket {
+            // Check operating service
+            assert!(
+                self.services.get(ClusterService::OpenAccount).value,
+                "ClusterService::OpenAccount disabled"
+            );
+            assert!(!self.__platform_paused(), "Platform paused");
+
+            // Validate own link badge
+            assert_eq!(self.link.amount(), dec!(1), "Cluster does not have a link badge");
+
+            // Validate the CDP
+            assert_eq!(cdp.amount(), dec!(1), "Invalid CDP amount; must contain 1 NFT");
+            assert_eq!(cdp.resource_address(), self.cdp_manager.address(), "Invalid CDP resource address");
+
+            let cdp_id = cdp.non_fungible_local_id();
+            let cdp_valid = self.__validate_cdp(cdp_id.clone());
+            assert!(cdp_valid, "Invalid CDP");
+
+            // Take fee payment
+            let fee = self.fee_structure.open;
+            self.fee_vault.put(fee_payment.take(fee));
+
+            // Update the user's badge
+            let valid_user = self.__validate_user(user_badge);
+            let user_id = valid_user.non_fungible_local_id();
+            self.__with_link(|platform, link_badge| platform.call_raw("open_account", scrypto_args!(link_badge, user_id.clone())));
+
+            if self.accounts.get(&user_id).is_some() {
+                let mut account = self.accounts.get_mut(&user_id).unwrap();
+                assert!(account.cdp_vault.amount() == dec!(0), "User already has an account");
+
+                account.cdp_vault.put(cdp);
+                account.position = None;
+            } else {
+                let account = AccountData::new(NonFungibleVault::with_bucket(cdp));
+                self.accounts.insert(user_id, account);
+            }
+
+            // Update the account count
+            self.account_count += 1;
+            fee_payment
+        }
+
+        /// Closes an account for a user on the cluster, and withdraws CDP.
+        ///
+        /// # Parameters
+        /// - `user_badge`: Proof of the user's badge from the platform.
+        /// - `fee_payment`: A `FungibleBucket` containing the payment for closing the account.
+        ///
+        /// # Panics
+        /// - If the cluster is not linked.
+        /// - If the ClusterService::CloseAccount is disabled.
+        /// - If the platform is paused.
+        /// - If the user does not have an open account.
+        /// - If the fee payment is invalid (wrong type, insufficient amount).
+        ///
+        /// # Returns
+        /// - A `NonFungibleBucket` containing the CDP.
+        /// - A `FungibleBucket` containing the remainder of the fee.
+        pub fn close_account(&mut self, user_badge: NonFungibleProof, mut fee_payment: FungibleBucket) -> (NonFungibleBucket, FungibleBucket) {
+            // Check operating service
+            assert!(
+                self.services.get(ClusterService::CloseAccount).value,
+                "ClusterService::CloseAccount disabled"
+            );
+            assert!(!self.__platform_paused(), "Platform paused");
+            assert!(self.account_count > 0, "No accounts to close");
+
+            // Validate own link badge
+            assert_eq!(self.link.amount(), dec!(1), "Cluster does not have a link badge");
+
+            // Validate the user
+            let valid_user = self.__validate_user(user_badge);
+            let user_id = valid_user.non_fungible_local_id();
+
+            // Validate the fee
+            let fee = self.fee_structure.close;
+            self.fee_vault.put(fee_payment.take(fee));
+
+            // Extract the CDP and remove it from the cluster
+            let cdp_bucket = self.accounts.get_mut(&user_id).expect("User has no open account").cdp_vault.take_all();
+            assert_eq!(cdp_bucket.amount(), dec!(1), "User has no open account");
+
+            // Update the user's badge
+            self.__with_link(|platform, link_badge| platform.call_raw("close_account", scrypto_args!(link_badge, user_id)));
+
+            // Remove the account
+            self.account_count -= 1;
+            (cdp_bucket, fee_payment)
+        }
+
+        /// Returns general information about an account, including the staking yield of its LSU against the cost
+        /// of its XRD debt. Queried from Weft using their `get_cdp` method.
+        ///
+        /// # Parameters
+        /// - `local_id`: The local ID of the account to query.
+        ///
+        /// # Returns
+        /// - A `AccountInfo` struct with the account's information.
+        pub fn get_account_info(&self, local_id: NonFungibleLocalId) -> AccountInfo {
+            let account = self.accounts.get(&local_id).expect("User has no open account");
+
+            // Fetch and parse the CDP
+            let cdp_id = account.cdp_vault.non_fungible_local_id();
+            let cdp_health = WeftMarket::new(self.weft_market_address).get_cdp(&cdp_id);
+
+            let (collateral, collateral_value) = match cdp_health.collateral_positions.get(&self.lsu) {
+                Some(collateral) => (collateral.amount, collateral.value),
+                None => (dec!(0), dec!(0)),
+            };
+            let (debt, debt_value) = match cdp_health.loan_positions.get(&XRD) {
+                Some(loan) => (loan.amount, loan.value),
+                None => (dec!(0), dec!(0)),
+            };
+
+            // Leverage on the user's equity
+            let equity = collateral_value - debt_value;
+            let leverage = if equity > dec!(0) { collateral_value / equity } else { dec!(0) };
+
+            // Annualise the growth of the LSU's redemption value, and of the debt, since entry
+            let (staking_yield, borrow_cost) = match &account.position {
+                Some(position) => {
+                    let elapsed = Decimal::from(now()) - position.entered_at;
+                    let rate = self.validator.get_redemption_value(dec!(1));
+
+                    let staking_yield = Self::__annualise(rate, position.entry_rate, elapsed);
+                    let borrow_cost = Self::__annualise(debt, position.principal, elapsed);
+
+                    (staking_yield, borrow_cost)
+                }
+                None => (dec!(0), dec!(0)),
+            };
+            let net_yield = staking_yield * leverage - borrow_cost * (leverage - dec!(1)).max(dec!(0));
+
+            // Construct and emit the account info
+            AccountInfo {
+                cdp_id,
+                collateral,
+                collateral_value,
+                debt,
+                debt_value,
+                health: cdp_health.liquidation_ltv,
+                leverage,
+                staking_yield,
+                borrow_cost,
+                net_yield,
+                position: account.position.clone(),
+            }
+        }
+
+        //] ------------------ Looping ----------------- */
+        /// Enters (or increases) a looped position: deposits the LSU as collateral, then repeatedly borrows XRD,
+        /// stakes it to the cluster's validator and deposits the received LSU, until the target leverage or the
+        /// maximum number of loops is reached.
+        ///
+        /// # Parameters
+        /// - `user_badge`: Proof of the user's badge from the platform.
+        /// - `lsu`: A `FungibleBucket` containing the LSU to deposit.
+        /// - `target_leverage`: Target ratio of the value of the collateral to the deposited value.
+        /// - `fee_payment`: A `FungibleBucket` containing the payment for the entry.
+        ///
+        /// # Panics
+        /// - If the ClusterService::Enter is disabled.
+        /// - If the platform is paused.
+        /// - If the user does not have an open account.
+        /// - If the LSU bucket is invalid (wrong type, empty).
+        /// - If the target leverage is below 1, or above the cluster's maximum leverage.
+        ///
+        /// # Returns
+        /// - A `FungibleBucket` containing the remainder of the fee.
+        pub fn enter(
+            &mut self,
+            user_badge: NonFungibleProof,
+            lsu: FungibleBucket,
+            target_leverage: Decimal,
+            mut fee_payment: FungibleBucket,
+        ) -> FungibleBucket {
+            // Check operating service
+            assert!(self.services.get(ClusterService::Enter).value, "ClusterService::Enter disabled");
+            assert!(!self.__platform_paused(), "Platform paused");
+
+            // Validate the input
+            assert_eq!(lsu.resource_address(), self.lsu, "Invalid LSU resource address");
+            assert!(lsu.amount() > dec!(0), "No LSU provided");
+            assert!(
+                target_leverage >= dec!(1) && target_leverage <= self.loop_config.max_leverage,
+                "Target leverage out of range"
+            );
+
+            let user_id = self.__validate_user(user_badge).non_fungible_local_id();
+
+            // Take fee payment
+            let fee = self.fee_structure.execute;
+            self.fee_vault.put(fee_payment.take(fee));
+
+            // Withdraw the CDP to prove ownership to Weft
+            let cdp = self.accounts.get_mut(&user_id).expect("User has no open account").cdp_vault.take_all();
+            assert_eq!(cdp.amount(), dec!(1), "User has no open account");
+
+            // Loop until the target debt is borrowed, in XRD
+            let weft_market = WeftMarket::new(self.weft_market_address);
+            let rate = self.validator.get_redemption_value(dec!(1));
+
+            let deposited = lsu.amount();
+            let target_debt = deposited * rate * (target_leverage - dec!(1));
+
+            let mut collateral: Bucket = lsu.into();
+            let mut borrowed = dec!(0);
+            let mut loops = 0u8;
+
+            loop {
+                let collateral_value = collateral.amount() * rate;
+                weft_market.add_collateral(cdp.create_proof_of_all(), vec![collateral]);
+
+                let remaining = target_debt - borrowed;
+                if remaining <= dec!(0) || loops == self.loop_config.max_loops {
+                    break;
+                }
+
+                let amount = remaining.min(collateral_value * self.loop_config.loop_ltv);
+                let mut loans = weft_market.borrow(cdp.create_proof_of_all(), vec![(XRD, amount)]);
+                let xrd = loans.pop().expect("No XRD borrowed");
+
+                borrowed += amount;
+                loops += 1;
+                collateral = self.validator.stake(xrd.as_fungible()).into();
+            }
+
+            // Validate the resulting CDP
+            let cdp_id = cdp.non_fungible_local_id();
+            assert!(self.__validate_cdp(cdp_id), "Invalid CDP");
+
+            // Record the position, and return the CDP
+            let mut account = self.accounts.get_mut(&user_id).unwrap();
+
+            let mut position = account.position.take().unwrap_or_else(|| LoopPosition::new(rate));
+            position.add(deposited, borrowed, rate);
+
+            account.position = Some(position);
+            account.cdp_vault.put(cdp);
+
+            fee_payment
+        }
+
+        /// Exits a looped position, in full or in part. The XRD repayment is provided by the caller, e.g. from
+        /// swapping LSU or a flash loan within the same transaction, as unstaking LSU is subject to an unbonding
+        /// delay. A share of the LSU collateral matching the share of the debt repaid is withdrawn.
+        ///
+        /// # Parameters
+        /// - `user_badge`: Proof of the user's badge from the platform.
+        /// - `repayment`: A `FungibleBucket` containing the XRD with which to repay the debt.
+        /// - `fee_payment`: A `FungibleBucket` containing the payment for the exit.
+        ///
+        /// # Panics
+        /// - If the ClusterService::Exit is disabled.
+        /// - If the platform is paused.
+        /// - If the user does not have an open account.
+        /// - If the repayment is not XRD.
+        ///
+        /// # Returns
+        /// - A `FungibleBucket` containing the withdrawn LSU.
+        /// - A `FungibleBucket` containing the remainder of the repayment.
+        /// - A `FungibleBucket` containing the remainder of the fee.
+        pub fn exit(
+            &mut self,
+            user_badge: NonFungibleProof,
+            mut repayment: FungibleBucket,
+            mut fee_payment: FungibleBucket,
+        ) -> (FungibleBucket, FungibleBucket, FungibleBucket) {
+            // Check operating service
+            assert!(self.services.get(ClusterService::Exit).value, "ClusterService::Exit disabled");
+            assert!(!self.__platform_paused(), "Platform paused");
+
+            // Validate the input
+            assert_eq!(repayment.resource_address(), XRD, "Repayment must be in XRD");
+
+            let user_id = self.__validate_user(user_badge).non_fungible_local_id();
+
+            // Take fee payment
+            let fee = self.fee_structure.execute;
+            self.fee_vault.put(fee_payment.take(fee));
+
+            // Withdraw the CDP to prove ownership to Weft
+            let cdp = self.accounts.get_mut(&user_id).expect("User has no open account").cdp_vault.take_all();
+            assert_eq!(cdp.amount(), dec!(1), "User has no open account");
+
+            let weft_market = WeftMarket::new(self.weft_market_address);
+            let cdp_health = weft_market.get_cdp(&cdp.non_fungible_local_id());
+
+            let collateral = match cdp_health.collateral_positions.get(&self.lsu) {
+                Some(collateral) => collateral.amount,
+                None => dec!(0),
+            };
+            let debt = match cdp_health.loan_positions.get(&XRD) {
+                Some(loan) => loan.amount,
+                None => dec!(0),
+            };
+
+            // Repay the debt, and determine the share of the position exited
+            let share = if debt > dec!(0) {
+                let payment = repayment.take(repayment.amount().min(debt));
+                let share = payment.amount() / debt;
+
+                for excess in weft_market.repay(cdp.create_proof_of_all(), vec![payment.into()]) {
+                    repayment.put(excess.as_fungible());
+                }
+
+                share
+            } else {
+                dec!(1)
+            };
+
+            // Withdraw the matching share of the collateral
+            let withdrawal = if share == dec!(1) { collateral } else { collateral * share };
+            let lsu = match withdrawal > dec!(0) {
+                true => {
+                    let mut withdrawn = weft_market.remove_collateral(cdp.create_proof_of_all(), vec![(self.lsu, withdrawal, false)]);
+                    withdrawn.pop().expect("No LSU withdrawn").as_fungible()
+                }
+                false => FungibleBucket::new(self.lsu),
+            };
+
+            // Update the position, and return the CDP
+            let mut account = self.accounts.get_mut(&user_id).unwrap();
+
+            account.position = match (account.position.take(), share == dec!(1)) {
+                (Some(mut position), false) => {
+                    position.reduce(share);
+                    Some(position)
+                }
+                _ => None,
+            };
+            account.cdp_vault.put(cdp);
+
+            (lsu, repayment, fee_payment)
+        }
+
+        //] Private
+        /// Validates the user's badge and returns the checked proof.
+        fn __validate_user(&self, user_badge: NonFungibleProof) -> CheckedNonFungibleProof {
+            let valid_user = user_badge.check_with_message(self.user_resource, "User badge not valid");
+            assert_eq!(valid_user.amount(), dec!(1), "Invalid user badge quantity");
+
+            valid_user
+        }
+
+        /// Annualises the relative growth from `start` to `end` over the given number of seconds.
+        fn __annualise(end: Decimal, start: Decimal, elapsed: Decimal) -> Decimal {
+            if start <= dec!(0) || elapsed <= dec!(0) {
+                return dec!(0);
+            }
+
+            (end / start - dec!(1)) * Decimal::from(SECONDS_PER_YEAR) / elapsed
+        }
+
+        //] ------------------- Weft ------------------- */
+        /// Validates the given CDP by checking its contents.
+        ///
+        /// # Parameters
+        /// - `local_id`: The local ID of the CDP to validate.
+        ///
+        /// # Returns
+        /// - `true` if the CDP is valid; otherwise, `false`.
+        ///
+        /// # Validation Criteria
+        /// - The CDP must have a valid ResourceAddress.
+        /// - The CDP must not have any NFT collaterals.
+        /// - The only collateral asset in the CDP must be the cluster's LSU.
+        /// - The only debt asset in the CDP must be XRD.
+        fn __validate_cdp(&self, local_id: NonFungibleLocalId) -> bool {
+            // Parse CDP data or return false if fetching the data panics
+            // Panic occurs if the cdp_manager cannot find an NFT with a matching local_id
+            let cdp: CDPData = match catch_unwind(|| self.cdp_manager.get_non_fungible_data::<CDPData>(&local_id)) {
+                Ok(cdp) => cdp,
+                Err(_) => {
+                    info!("Error parsing CDP with local_id {:?}", local_id);
+                    return false;
+                }
+            };
+
+            // Validate that there are no NFT collaterals
+            if !cdp.nft_collaterals.is_empty() {
+                info!("CDP with local_id {:?} has NFT collateral(s)", local_id);
+                return false;
+            }
+
+            // Validate that all supply and debt assets are valid
+            if cdp.collaterals.keys().any(|&resource| resource != self.lsu) {
+                info!("CDP with local_id {:?} has an invalid collateral asset", local_id);
+                return false;
+            }
+
+            if cdp.loans.keys().any(|&resource| resource != XRD) {
+                info!("CDP with local_id {:?} has an invalid debt asset", local_id);
+                return false;
+            }
+
+            true
+        }
+    }
+}
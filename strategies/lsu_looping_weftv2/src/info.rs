@@ -0,0 +1,77 @@
+/* ------------------ Imports ----------------- */
+use crate::accounts::LoopPosition;
+use scrypto::prelude::*;
+
+/* ------------------ Cluster ----------------- */
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct ClusterInfo {
+    pub platform_address: ComponentAddress,
+    pub cluster_address: ComponentAddress,
+    pub linked: bool,
+    pub validator: ComponentAddress,
+    pub lsu_res: ResourceAddress,
+    pub account_count: u64,
+    pub loop_config: LoopConfig,
+    pub fee_info: FeeInfo,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct FeeInfo {
+    pub open: Decimal,
+    pub close: Decimal,
+    pub execute: Decimal,
+}
+
+/* ---------------- Loop Config --------------- */
+/// Parameters of the entry loop.
+#[derive(ScryptoSbor, Debug, Clone, Copy)]
+pub struct LoopConfig {
+    /// Share of the value of each new LSU deposit borrowed in the next loop; e.g. `0.7` for 70%.
+    pub loop_ltv: Decimal,
+    /// Maximum number of borrow-and-stake loops in a single entry.
+    pub max_loops: u8,
+    /// Maximum target leverage accepted on entry.
+    pub max_leverage: Decimal,
+}
+
+impl Default for LoopConfig {
+    fn default() -> Self {
+        Self { loop_ltv: dec!(0.7), max_loops: 8, max_leverage: dec!(3) }
+    }
+}
+
+impl LoopConfig {
+    /// Creates a new loop config.
+    ///
+    /// # Panics
+    /// - If the loop LTV is not within (0, 1).
+    /// - If the maximum number of loops is zero.
+    /// - If the maximum leverage is below 1.
+    pub fn new(loop_ltv: Decimal, max_loops: u8, max_leverage: Decimal) -> Self {
+        assert!(loop_ltv > dec!(0) && loop_ltv < dec!(1), "Loop LTV must be between 0 and 1");
+        assert!(max_loops > 0, "Maximum loops must be positive");
+        assert!(max_leverage >= dec!(1), "Maximum leverage must be at least 1");
+
+        Self { loop_ltv, max_loops, max_leverage }
+    }
+}
+
+/* ------------------ Account ----------------- */
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct AccountInfo {
+    pub cdp_id: NonFungibleLocalId,
+    pub collateral: Decimal,
+    pub collateral_value: Decimal,
+    pub debt: Decimal,
+    pub debt_value: Decimal,
+    pub health: Decimal,
+    pub leverage: Decimal,
+    /// Annualised growth of the LSU redemption value since entry.
+    pub staking_yield: Decimal,
+    /// Annualised growth of the debt over the borrowed principal since entry.
+    pub borrow_cost: Decimal,
+    /// Annualised yield on the user's equity; the staking yield on the collateral less the borrow cost on the debt.
+    pub net_yield: Decimal,
+    /// Record of the looped position from which the yields are reported; `None` before entering.
+    pub position: Option<LoopPosition>,
+}
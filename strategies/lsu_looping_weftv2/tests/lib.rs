@@ -0,0 +1,7 @@
+/* ------------------ Imports ----------------- */
+use lsu_looping_weftv2::cluster::lsu_looping_weftv2_cluster_test::*;
+use scrypto_test::prelude::*;
+
+/* ------------------- Tests ------------------ */
+#[test]
+fn test() {}
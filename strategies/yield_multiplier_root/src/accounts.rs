@@ -1,14 +1,88 @@
 /* ------------------ Imports ----------------- */
 use scrypto::prelude::*;
+use shared::utils::now;
 
 /* ------------------- User ------------------- */
 #[derive(ScryptoSbor, Debug)]
 pub struct AccountData {
     pub cdp_vault: NonFungibleVault,
+    pub delegation: Option<Delegation>,
+    pub state: AccountState,
 }
 
 impl AccountData {
     pub fn new(cdp_vault: NonFungibleVault) -> Self {
-        Self { cdp_vault }
+        Self { cdp_vault, delegation: None, state: AccountState::Idle }
+    }
+
+    /// Moves the account to the given state.
+    ///
+    /// # Panics
+    /// - If the transition from the current state is not allowed.
+    pub fn transition(&mut self, to: AccountState) {
+        assert!(
+            self.state.can_transition(to),
+            "Invalid account state transition: {:?} -> {:?}",
+            self.state,
+            to
+        );
+
+        self.state = to;
+    }
+}
+
+/* ------------------- State ------------------ */
+/// Lifecycle of an account. The CDP is held by the cluster only while the account is `Idle`.
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountState {
+    /// The CDP is held by the cluster.
+    Idle,
+    /// The CDP is out on an `ExecutionTerms` badge, and must be returned through `end_execution`.
+    Executing,
+    /// The CDP is being withdrawn from the cluster.
+    Closing,
+    /// The CDP has been withdrawn; the account can be reopened with a new CDP.
+    Closed,
+}
+
+impl AccountState {
+    pub fn can_transition(&self, to: AccountState) -> bool {
+        matches!(
+            (self, to),
+            (AccountState::Idle, AccountState::Executing)
+                | (AccountState::Executing, AccountState::Idle)
+                | (AccountState::Idle, AccountState::Closing)
+                | (AccountState::Closing, AccountState::Closed)
+                | (AccountState::Closed, AccountState::Idle)
+        )
+    }
+}
+
+/* ---------------- Delegation ---------------- */
+/// Rights granted by a user to a delegate (e.g. a bot or keeper), allowing it to run executions on the user's account.
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct Delegation {
+    /// Access rule the delegate's proof must satisfy; e.g. a badge.
+    pub rule: AccessRule,
+    /// Maximum increase of the debt, in loan units, over a single delegated execution.
+    pub max_debt_increase: Option<Decimal>,
+    /// Time at which the delegation expires.
+    pub expires_at: Option<Instant>,
+}
+
+impl Delegation {
+    pub fn new(rule: AccessRule, max_debt_increase: Option<Decimal>, expires_at: Option<Instant>) -> Self {
+        if let Some(max_debt_increase) = max_debt_increase {
+            assert!(max_debt_increase >= dec!(0), "Maximum debt increase cannot be negative");
+        }
+
+        Self { rule, max_debt_increase, expires_at }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        match self.expires_at {
+            Some(expires_at) => now() >= expires_at.seconds_since_unix_epoch,
+            None => false,
+        }
     }
 }
@@ -1,12 +1,14 @@
 /* ------------------ Imports ----------------- */
 // Modules
-use crate::accounts::AccountData;
+use crate::accounts::{AccountData, AccountState, Delegation};
 use crate::fees::FeeStructure;
-use crate::info::{AccountInfo, ClusterInfo, FeeInfo};
-use crate::root::{CDPType, CollaterizedDebtPositionData, PriceInfo};
+use crate::info::{AccountInfo, ClusterInfo, FeeInfo, MarketRates, RateInfo};
+use crate::root::{CDPType, CollaterizedDebtPositionData, PriceInfo, RootMarket};
 use crate::services::{ClusterService, ClusterServiceManager};
 // Shared Modules
-use shared::services::{ServiceValue, SetLock};
+use shared::links::Link;
+use shared::services::{ServicePolicy, ServiceValue, SetLock};
+use shared::utils::{apr_to_apy, leveraged_apr};
 // Libraries
 use scrypto::prelude::*;
 use std::panic::catch_unwind;
@@ -17,6 +19,8 @@ type Unit = ();
 #[derive(NonFungibleData, ScryptoSbor)]
 pub struct ExecutionTerms {
     pub user_id: NonFungibleLocalId,
+    pub cdp_id: NonFungibleLocalId,
+    pub max_debt: Option<Decimal>,
 }
 
 #[blueprint]
@@ -34,10 +38,13 @@ pub struct ExecutionTerms {
     i64,
     FeeStructure,
     ExecutionTerms,
+    Delegation,
+    AccountState,
     // Services
     ClusterServiceManager,
     ClusterService,
     ServiceValue,
+    ServicePolicy,
     SetLock,
     // State Returns
     AccountInfo,
@@ -60,7 +67,10 @@ mod yield_multiplier_root_cluster {
             // Links
             handle_link => PUBLIC;
             // Cluster
-            get_cluster_info => PUBLIC;
+            get_cluster_info     => PUBLIC;
+            get_claimed_entities => PUBLIC;
+            get_market_rates     => PUBLIC;
+            get_services         => PUBLIC;
             update_service              => restrict_to: [can_manage_services, can_lock_services];
             update_service_and_set_lock => restrict_to: [can_lock_services];
             set_fee_structure           => restrict_to: [can_manage_fees];
@@ -71,6 +81,12 @@ mod yield_multiplier_root_cluster {
             get_account_info => PUBLIC;
             start_execution  => PUBLIC;
             end_execution    => PUBLIC;
+            // Delegation
+            set_delegate    => PUBLIC;
+            revoke_delegate => PUBLIC;
+            // Refinancing
+            get_account_debt => PUBLIC;
+            export_account   => PUBLIC;
         }
     }
 
@@ -94,6 +110,7 @@ mod yield_multiplier_root_cluster {
         fee_structure: FeeStructure,
         fee_vault: FungibleVault,
         // Integration
+        root_market_address: ComponentAddress,
         cdp_manager: NonFungibleResourceManager,
     }
 
@@ -108,6 +125,7 @@ mod yield_multiplier_root_cluster {
         /// - `user_resource`: Resource address for the user badge required for user operations.
         /// - `supply`: Resource address for the supply asset of the cluster.
         /// - `debt`: Resource address for the debt asset of the cluster.
+        /// - `root_market_address`: The component address of Root's lending market.
         /// - `cdp_resource`:Resource address of the Root CDP NFT.
         ///
         /// # Returns
@@ -122,6 +140,7 @@ mod yield_multiplier_root_cluster {
             supply: ResourceAddress,
             debt: ResourceAddress,
             // Integration
+            root_market_address: ComponentAddress,
             cdp_resource: ResourceAddress,
         ) -> Global<YieldMultiplierRootCluster> {
             // Reserve component address
@@ -130,6 +149,10 @@ mod yield_multiplier_root_cluster {
             let platform: Global<AnyComponent> = platform_address.into();
             let link_resource = platform.call_raw::<ResourceAddress>("get_link_badge_address", scrypto_args!());
             let user_resource = platform.call_raw::<ResourceAddress>("get_user_badge_address", scrypto_args!());
+            let dapp_definition_address: GlobalAddress = platform
+                .get_metadata("dapp_definition")
+                .unwrap_or(None)
+                .expect("Platform has no dApp definition");
 
             //] Authorisation
             // Component
@@ -139,6 +162,9 @@ mod yield_multiplier_root_cluster {
             let owner_role: OwnerRole = OwnerRole::Fixed(owner_rule.clone());
 
             let execution_term_manager = ResourceBuilder::new_ruid_non_fungible::<ExecutionTerms>(owner_role.clone())
+                .metadata(metadata! {init {
+                    "dapp_definition" => dapp_definition_address, updatable;
+                }})
                 .mint_roles(mint_roles! {
                     minter         => component_access_rule.clone();
                     minter_updater => rule!(deny_all);
@@ -167,7 +193,7 @@ mod yield_multiplier_root_cluster {
                     "description"     => "Lattic3 cluster component for the Yield Multiplier strategy, built on top of Root Finance's lending market.", locked;
                     "supply"          => supply, locked;
                     "debt"            => debt, locked;
-                    // "dapp_definition" => dapp_definition_address, updatable;
+                    "dapp_definition" => dapp_definition_address, updatable;
                 }
             };
 
@@ -189,9 +215,10 @@ mod yield_multiplier_root_cluster {
                 accounts: KeyValueStore::new(),
                 account_count: 0,
                 execution_term_manager,
-                services: ClusterServiceManager::new(),
+                services: ClusterServiceManager::new(ServicePolicy::DefaultOn),
                 fee_structure: FeeStructure::default(),
                 fee_vault: FungibleVault::new(XRD),
+                root_market_address,
                 cdp_manager: cdp_resource.into(),
             };
 
@@ -218,7 +245,7 @@ mod yield_multiplier_root_cluster {
         /// - If the cluster is already linked
         pub fn handle_link(&mut self, bucket: NonFungibleBucket) {
             // Check operating service
-            assert!(self.services.get(ClusterService::Link), "ClusterService::Link disabled");
+            assert!(self.services.get(ClusterService::Link).value, "ClusterService::Link disabled");
 
             // Sanity checks
             assert_eq!(self.link.amount(), dec!(0), "Platform already linked");
@@ -245,7 +272,7 @@ mod yield_multiplier_root_cluster {
         /// - If the CallLinked service is disabled
         fn __with_link<F: FnOnce(Global<AnyComponent>, NonFungibleProof)>(&self, func: F) {
             assert!(self.link.amount() > dec!(0), "Cluster not linked");
-            assert!(self.services.get(ClusterService::CallLinked), "ClusterService::CallLinked disabled");
+            assert!(self.services.get(ClusterService::CallLinked).value, "ClusterService::CallLinked disabled");
 
             // Arrange call
             let link_local_id = self.link.non_fungible_local_id();
@@ -256,7 +283,24 @@ mod yield_multiplier_root_cluster {
             func(platform, link_badge);
         }
 
+        /// Returns whether the linked platform has been paused; read from the `paused` flag of the link badge.
+        /// An unlinked cluster is never considered paused.
+        fn __platform_paused(&self) -> bool {
+            if self.link.amount() == dec!(0) {
+                return false;
+            }
+
+            let link: Link = self.link.non_fungible::<Link>().data();
+            link.paused
+        }
+
         //] ------------------ Cluster ----------------- */
+        /// Returns the addresses of the cluster's entities; the component and its resources. They are labelled with
+        /// the platform's dApp definition, and are to be registered in its `claimed_entities`.
+        pub fn get_claimed_entities(&self) -> Vec<GlobalAddress> {
+            vec![self.component_address.into(), self.execution_term_manager.address().into()]
+        }
+
         /// Returns general information about the cluster.
         ///
         /// # Returns
@@ -275,7 +319,7 @@ mod yield_multiplier_root_cluster {
                 execute: self.fee_structure.execute,
             };
 
-            let info = ClusterInfo {
+            ClusterInfo {
                 platform_address: self.platform_address,
                 cluster_address: self.component_address,
                 linked: self.link.amount() > dec!(0),
@@ -284,22 +328,54 @@ mod yield_multiplier_root_cluster {
                 account_count: self.account_count,
                 execution_term_manager: self.execution_term_manager,
                 fee_info,
-            };
+            }
+        }
+
+        //] Rates
+        /// Returns the current lending rates of the collateral and loan assets on Root, and the resulting net rates on the
+        /// equity of a position levered `leverage` times.
+        ///
+        /// # Parameters
+        /// - `leverage`: The ratio of the position's collateral value to its equity; `1` for an unlevered position.
+        ///
+        /// # Panics
+        /// - If the leverage is below 1.
+        ///
+        /// # Returns
+        /// - `MarketRates`: The supply APR, borrow APR and utilisation of each asset, and the net APR and APY at the given leverage.
+        pub fn get_market_rates(&self, leverage: Decimal) -> MarketRates {
+            assert!(leverage >= dec!(1), "Leverage must be at least 1");
 
-            info
+            let (collateral, loan) = (self.supply, self.debt);
+            let market = RootMarket::new(self.root_market_address);
+            let states = market.get_market_state(indexset![collateral, loan]);
+
+            let supply = RateInfo::new(collateral, states.get(&collateral).expect("No market state for the collateral asset"));
+            let debt = RateInfo::new(loan, states.get(&loan).expect("No market state for the loan asset"));
+
+            let net_apr = leveraged_apr(supply.supply_apr, debt.borrow_apr, leverage);
+
+            MarketRates { supply, debt, leverage, net_apr, net_apy: apr_to_apy(net_apr) }
         }
 
         //] Services
+        /// Returns every cluster service, alongside its value and lock state.
+        pub fn get_services(&self) -> IndexMap<ClusterService, ServiceValue> {
+            self.services.list()
+        }
+
         /// Updates a cluster service, assuming it is not locked.
         ///
         /// # Parameters
         /// - `service`: The service to update.
         /// - `value`: The value to set the service to.
+        /// - `enable_at`: Optional time at which the service is enabled.
+        /// - `disable_at`: Optional time at which the service is disabled.
         ///
         /// # Panics
         /// - If the service is currently locked.
-        pub fn update_service(&mut self, service: ClusterService, value: bool) {
-            self.services.update(service, value, SetLock::None);
+        pub fn update_service(&mut self, service: ClusterService, value: bool, enable_at: Option<Instant>, disable_at: Option<Instant>) {
+            self.services.update(service, value, enable_at, disable_at, SetLock::None);
         }
 
         /// Updates a cluster service and sets the lock state.
@@ -308,8 +384,17 @@ mod yield_multiplier_root_cluster {
         /// - `service`: The service to update.
         /// - `value`: The value to set the service to.
         /// - `locked`: The value to which the lock status of the service is set to.
-        pub fn update_service_and_set_lock(&mut self, service: ClusterService, value: bool, locked: bool) {
-            self.services.update(service, value, SetLock::Update(locked));
+        /// - `enable_at`: Optional time at which the service is enabled.
+        /// - `disable_at`: Optional time at which the service is disabled.
+        pub fn update_service_and_set_lock(
+            &mut self,
+            service: ClusterService,
+            value: bool,
+            locked: bool,
+            enable_at: Option<Instant>,
+            disable_at: Option<Instant>,
+        ) {
+            self.services.update(service, value, enable_at, disable_at, SetLock::Update(locked));
         }
 
         //] Fees
@@ -342,14 +427,19 @@ mod yield_multiplier_root_cluster {
         /// # Panics
         /// - If the cluster is not linked.
         /// - If the ClusterService::OpenAccount is disabled.
-        /// - If the user already has an account.
+        /// - If the platform is paused.
+        /// - If the user already has an open account.
         /// - If the CDP is invalid.
         ///
         /// # Returns
         /// A `FungibleBucket` containing the remainder of the fee.
         pub fn open_account(&mut self, user_badge: NonFungibleProof, cdp: NonFungibleBucket, mut fee_payment: FungibleBucket) -> FungibleBucket {
             // Check operating service
-            assert!(self.services.get(ClusterService::OpenAccount), "ClusterService::OpenAccount disabled");
+            assert!(
+                self.services.get(ClusterService::OpenAccount).value,
+                "ClusterService::OpenAccount disabled"
+            );
+            assert!(!self.__platform_paused(), "Platform paused");
 
             // Validate own link badge
             assert_eq!(self.link.amount(), dec!(1), "Cluster does not have a link badge");
@@ -369,13 +459,20 @@ mod yield_multiplier_root_cluster {
             // Update the user's badge
             let valid_user = self.__validate_user(user_badge);
             let user_id = valid_user.non_fungible_local_id();
+
+            // Only a closed account may be reopened
+            if let Some(account) = self.accounts.get(&user_id) {
+                assert_eq!(account.state, AccountState::Closed, "User already has an open account");
+            }
+
             self.__with_link(|platform, link_badge| platform.call_raw("open_account", scrypto_args!(link_badge, user_id.clone())));
 
             if self.accounts.get(&user_id).is_some() {
                 let mut account = self.accounts.get_mut(&user_id).unwrap();
-                assert!(account.cdp_vault.amount() == dec!(0), "User already has an account");
+                account.transition(AccountState::Idle);
 
                 account.cdp_vault.put(cdp);
+                account.delegation = None;
             } else {
                 let account = AccountData::new(NonFungibleVault::with_bucket(cdp));
                 self.accounts.insert(user_id, account);
@@ -395,7 +492,8 @@ mod yield_multiplier_root_cluster {
         /// # Panics
         /// - If the cluster is not linked.
         /// - If the ClusterService::CloseAccount is disabled.
-        /// - If the user does not have an account.
+        /// - If the platform is paused.
+        /// - If the user does not have an open account, or it is in an execution.
         /// - If the fee payment is invalid (wrong type, insufficient amount).
         ///
         /// # Returns
@@ -403,7 +501,11 @@ mod yield_multiplier_root_cluster {
         /// - A `FungibleBucket` containing the remainder of the fee.
         pub fn close_account(&mut self, user_badge: NonFungibleProof, mut fee_payment: FungibleBucket) -> (NonFungibleBucket, FungibleBucket) {
             // Check operating service
-            assert!(self.services.get(ClusterService::CloseAccount), "ClusterService::CloseAccount disabled");
+            assert!(
+                self.services.get(ClusterService::CloseAccount).value,
+                "ClusterService::CloseAccount disabled"
+            );
+            assert!(!self.__platform_paused(), "Platform paused");
             assert!(self.account_count > 0, "No accounts to close");
 
             // Validate own link badge
@@ -418,10 +520,16 @@ mod yield_multiplier_root_cluster {
             self.fee_vault.put(fee_payment.take(fee));
 
             // Extract the CDP and remove it from the cluster
-            let cdp_bucket = self.accounts.get_mut(&user_id).expect("User has no open account").cdp_vault.take_all();
+            let cdp_bucket = {
+                let mut account = self.accounts.get_mut(&user_id).expect("User has no open account");
+                account.transition(AccountState::Closing);
+
+                account.cdp_vault.take_all()
+            };
 
             // Update the user's badge
-            self.__with_link(|platform, link_badge| platform.call_raw("close_account", scrypto_args!(link_badge, user_id)));
+            self.__with_link(|platform, link_badge| platform.call_raw("close_account", scrypto_args!(link_badge, user_id.clone())));
+            self.accounts.get_mut(&user_id).unwrap().transition(AccountState::Closed);
 
             // Remove the account
             self.account_count -= 1;
@@ -455,9 +563,7 @@ mod yield_multiplier_root_cluster {
             };
 
             // Construct and emit the account info
-            let info = AccountInfo { cdp_id, supply_units, debt_units };
-
-            info
+            AccountInfo { cdp_id, supply_units, debt_units }
         }
 
         /// Starts an execution on the cluster, allowing the user to perform arbitrary
@@ -465,31 +571,55 @@ mod yield_multiplier_root_cluster {
         /// one transaction, and the CPD must be returned to the user at the end by
         /// calling the `end_execution` method.
         ///
+        /// A delegate of the user starts the execution with a proof satisfying the delegation's access rule instead of
+        /// the user's badge, and is held to the delegation's debt limit by `end_execution`.
+        ///
         /// # Parameters
-        /// - `user_badge`: A `NonFungibleProof` of the user's badge.
+        /// - `user_badge`: A `NonFungibleProof` of the user's badge; `None` for a delegated execution.
+        /// - `delegate`: The local ID of the user's badge, and a proof satisfying the access rule of their delegate;
+        ///   `None` for the user's own execution.
         ///
         /// # Panics
         /// - If the cluster is not linked to the platform.
         /// - If the ClusterService::Execute is disabled.
-        /// - If the user does not have an open account.
+        /// - If the platform is paused.
+        /// - If neither or both of the user's badge and a delegate proof are given.
+        /// - If the user does not have an open account, or it is already in an execution.
+        /// - If the user has no delegate, the delegation has expired, or the proof does not satisfy its access rule.
         ///
         /// # Returns
         /// - A `NonFungibleBucket` containing the user's CDP.
         /// - A `NonFungibleBucket` containing the execution terms transient badge.
-        pub fn start_execution(&mut self, user_badge: NonFungibleProof) -> (NonFungibleBucket, NonFungibleBucket) {
+        pub fn start_execution(
+            &mut self,
+            user_badge: Option<NonFungibleProof>,
+            delegate: Option<(NonFungibleLocalId, Proof)>,
+        ) -> (NonFungibleBucket, NonFungibleBucket) {
             // Check ClusterService::Execute enabled
-            assert!(self.services.get(ClusterService::Execute), "ClusterService::Execute disabled");
+            assert!(self.services.get(ClusterService::Execute).value, "ClusterService::Execute disabled");
+            assert!(!self.__platform_paused(), "Platform paused");
 
-            // Validate the user
-            let user_id = self.__validate_user(user_badge).non_fungible_local_id();
+            match (user_badge, delegate) {
+                (Some(user_badge), None) => {
+                    // Validate the user
+                    let user_id = self.__validate_user(user_badge).non_fungible_local_id();
 
-            // Return CDP and execution terms
-            let cdp_bucket = self.accounts.get_mut(&user_id).expect("User has no open account").cdp_vault.take_all();
+                    self.__start_execution(user_id, None)
+                }
+                (None, Some((user_id, delegate_proof))) => {
+                    // Validate the delegate
+                    let delegation = self.__validate_delegate(&user_id, delegate_proof);
 
-            let terms = ExecutionTerms { user_id };
-            let execution_terms = self.execution_term_manager.mint_ruid_non_fungible(terms);
+                    // Limit the debt of the CDP at the end of the execution
+                    let max_debt = delegation.max_debt_increase.map(|max_debt_increase| {
+                        let cdp_id = self.accounts.get(&user_id).unwrap().cdp_vault.non_fungible_local_id();
+                        self.__debt_units(&cdp_id) + max_debt_increase
+                    });
 
-            (cdp_bucket, execution_terms)
+                    self.__start_execution(user_id, max_debt)
+                }
+                _ => panic!("Execution must be started by either the user or their delegate"),
+            }
         }
 
         /// Counterpart to `start_execution`, returns the user's CDP to the cluster
@@ -501,7 +631,8 @@ mod yield_multiplier_root_cluster {
         ///
         /// # Panics
         /// - If the user does not have an open account.
-        /// - If the CDP is invalid (wrong type, insufficient amount).
+        /// - If the CDP is invalid (wrong type, insufficient amount), or does not match the execution terms.
+        /// - If a delegated execution increased the debt past its limit.
         ///
         /// # Returns
         /// - A `FungibleBucket` containing the remainder of the fee.
@@ -517,19 +648,30 @@ mod yield_multiplier_root_cluster {
 
             // Validate the CDP
             let cdp_id = cdp_bucket.non_fungible_local_id();
+            assert_eq!(cdp_id, terms.cdp_id, "CDP does not match the execution terms");
+
             let cdp_valid = self.__validate_cdp(cdp_id.clone());
             assert!(cdp_valid, "Invalid CDP");
 
+            // Enforce the debt limit of a delegated execution
+            if let Some(max_debt) = terms.max_debt {
+                assert!(
+                    self.__debt_units(&cdp_id) <= max_debt,
+                    "Delegated execution exceeds the maximum debt increase"
+                );
+            }
+
             // Calculate the fee
             let fee = self.fee_structure.execute;
             self.fee_vault.put(fee_payment.take(fee));
 
             // Return the CDP
-            self.accounts
-                .get_mut(&terms.user_id)
-                .expect("User has no open account")
-                .cdp_vault
-                .put(cdp_bucket);
+            {
+                let mut account = self.accounts.get_mut(&terms.user_id).expect("User has no open account");
+                account.transition(AccountState::Idle);
+
+                account.cdp_vault.put(cdp_bucket);
+            }
 
             // Burn the execution terms
             self.execution_term_manager.burn(terms_bucket);
@@ -537,7 +679,171 @@ mod yield_multiplier_root_cluster {
             fee_payment
         }
 
+        //] Delegation
+        /// Grants a delegate the right to run executions on the user's account, replacing any existing delegate.
+        /// The delegate starts executions through `start_execution`, with a proof satisfying the access rule.
+        ///
+        /// # Parameters
+        /// - `user_badge`: Proof of the user's badge from the platform.
+        /// - `rule`: Access rule the delegate's proof must satisfy; e.g. a badge.
+        /// - `max_debt_increase`: Optional maximum increase of the debt, in loan units, over a single execution.
+        /// - `expires_at`: Optional time at which the delegation expires.
+        ///
+        /// # Panics
+        /// - If the user's account is not idle.
+        /// - If the maximum debt increase is negative.
+        pub fn set_delegate(
+            &mut self,
+            user_badge: NonFungibleProof,
+            rule: AccessRule,
+            max_debt_increase: Option<Decimal>,
+            expires_at: Option<Instant>,
+        ) {
+            let user_id = self.__validate_user(user_badge).non_fungible_local_id();
+
+            let mut account = self.accounts.get_mut(&user_id).expect("User has no open account");
+            assert_eq!(account.state, AccountState::Idle, "Account is not idle");
+
+            account.delegation = Some(Delegation::new(rule, max_debt_increase, expires_at));
+        }
+
+        /// Revokes the delegate of the user's account.
+        ///
+        /// # Parameters
+        /// - `user_badge`: Proof of the user's badge from the platform.
+        ///
+        /// # Panics
+        /// - If the user does not have an account.
+        pub fn revoke_delegate(&mut self, user_badge: NonFungibleProof) {
+            let user_id = self.__validate_user(user_badge).non_fungible_local_id();
+
+            self.accounts.get_mut(&user_id).expect("User has no open account").delegation = None;
+        }
+
+        //] Refinancing
+        /// Returns the debt asset owed by an account, rounded up; the repayment with which the platform exports it.
+        ///
+        /// # Parameters
+        /// - `user_id`: The local ID of the user's badge.
+        pub fn get_account_debt(&self, user_id: NonFungibleLocalId) -> Decimal {
+            let account = self.accounts.get(&user_id).expect("User has no open account");
+            let cdp = account.cdp_vault.non_fungible::<CollaterizedDebtPositionData>().data();
+
+            match cdp.loans.get(&self.debt) {
+                Some(loan) => loan.checked_truncate(RoundingMode::ToPositiveInfinity).unwrap(),
+                None => dec!(0),
+            }
+        }
+
+        /// Unwinds a user's account for a refinance to another cluster; repays the CDP's debt, withdraws its collateral,
+        /// and closes the account. Only callable by the platform, as part of its `refinance` flow, which moves
+        /// the account on the user's badge.
+        ///
+        /// # Parameters
+        /// - `user_id`: The local ID of the user's badge.
+        /// - `repayment`: A `FungibleBucket` of the debt asset with which to repay the CDP; e.g. from a flash loan.
+        ///
+        /// # Panics
+        /// - If the ClusterService::Refinance is disabled.
+        /// - If the platform is paused.
+        /// - If the caller is not the linked platform.
+        /// - If the user's account is not idle.
+        /// - If the repayment is not the debt asset.
+        /// - If the repayment does not cover the CDP's debt.
+        ///
+        /// # Returns
+        /// - A `NonFungibleBucket` containing the emptied CDP.
+        /// - A `FungibleBucket` containing the withdrawn collateral.
+        /// - A `FungibleBucket` containing the remainder of the repayment.
+        pub fn export_account(
+            &mut self,
+            user_id: NonFungibleLocalId,
+            mut repayment: FungibleBucket,
+        ) -> (NonFungibleBucket, FungibleBucket, FungibleBucket) {
+            // Check operating service
+            assert!(self.services.get(ClusterService::Refinance).value, "ClusterService::Refinance disabled");
+            assert!(!self.__platform_paused(), "Platform paused");
+
+            // Validate the caller
+            assert_eq!(self.link.amount(), dec!(1), "Cluster does not have a link badge");
+            Runtime::assert_access_rule(rule!(require(global_caller(self.platform_address))));
+
+            assert_eq!(repayment.resource_address(), self.debt, "Repayment must be in the debt asset");
+
+            // Extract the CDP
+            let cdp_bucket = {
+                let mut account = self.accounts.get_mut(&user_id).expect("User has no open account");
+                account.transition(AccountState::Closing);
+
+                account.cdp_vault.take_all()
+            };
+
+            let cdp_id = cdp_bucket.non_fungible_local_id();
+            let cdp = self.cdp_manager.get_non_fungible_data::<CollaterizedDebtPositionData>(&cdp_id);
+            let root_market = RootMarket::new(self.root_market_address);
+
+            // Repay the debt
+            if cdp.loans.contains_key(&self.debt) {
+                let payment = repayment.take(repayment.amount());
+
+                for excess in root_market.repay(cdp_bucket.create_proof_of_all(), vec![payment.into()]) {
+                    repayment.put(excess.as_fungible());
+                }
+            }
+            assert_eq!(self.__debt_units(&cdp_id), dec!(0), "Repayment does not cover the CDP's debt");
+
+            // Withdraw the collateral
+            let collateral = match cdp.collaterals.get(&self.supply) {
+                Some(units) => {
+                    let amount = units.checked_truncate(RoundingMode::ToZero).unwrap();
+                    let mut withdrawn = root_market.remove_collateral(cdp_bucket.create_proof_of_all(), vec![(self.supply, amount, false)]);
+
+                    withdrawn.pop().expect("No collateral withdrawn").as_fungible()
+                }
+                None => FungibleBucket::new(self.supply),
+            };
+
+            // Close the account; the platform moves it on the user's badge
+            self.accounts.get_mut(&user_id).unwrap().transition(AccountState::Closed);
+            self.account_count -= 1;
+
+            (cdp_bucket, collateral, repayment)
+        }
+
         //] Private
+        /// Withdraws the user's CDP, and mints the execution terms transient badge which must accompany its return.
+        fn __start_execution(&mut self, user_id: NonFungibleLocalId, max_debt: Option<Decimal>) -> (NonFungibleBucket, NonFungibleBucket) {
+            let cdp_bucket = {
+                let mut account = self.accounts.get_mut(&user_id).expect("User has no open account");
+                account.transition(AccountState::Executing);
+
+                account.cdp_vault.take_all()
+            };
+
+            let cdp_id = cdp_bucket.non_fungible_local_id();
+            let terms = ExecutionTerms { user_id, cdp_id, max_debt };
+            let execution_terms = self.execution_term_manager.mint_ruid_non_fungible(terms);
+
+            (cdp_bucket, execution_terms)
+        }
+
+        /// Validates the delegate of the user's idle account against the given proof, returning the delegation.
+        fn __validate_delegate(&self, user_id: &NonFungibleLocalId, delegate_proof: Proof) -> Delegation {
+            let delegation = {
+                let account = self.accounts.get(user_id).expect("User has no open account");
+                assert_eq!(account.state, AccountState::Idle, "Account is not idle");
+
+                account.delegation.clone().expect("User has no delegate")
+            };
+            assert!(!delegation.is_expired(), "Delegation expired");
+
+            // Check the delegation's access rule against the delegate's proof
+            LocalAuthZone::push(delegate_proof);
+            Runtime::assert_access_rule(delegation.rule.clone());
+
+            delegation
+        }
+
         /// Validates the user's badge and returns the checked proof.
         fn __validate_user(&self, user_badge: NonFungibleProof) -> CheckedNonFungibleProof {
             // assert_eq!(user_badge.resource_address(), self.user_resource, "Invalid user badge resource address");
@@ -586,21 +892,27 @@ mod yield_multiplier_root_cluster {
                 return false;
             }
 
-            if cdp.collaterals.len() == 1 {
-                if !cdp.collaterals.contains_key(&self.supply) {
-                    info!("CDP with local_id {:?} has an invalid collateral asset", local_id);
-                    return false;
-                }
+            if cdp.collaterals.len() == 1 && !cdp.collaterals.contains_key(&self.supply) {
+                info!("CDP with local_id {:?} has an invalid collateral asset", local_id);
+                return false;
             }
 
-            if cdp.loans.len() == 1 {
-                if !cdp.loans.contains_key(&self.debt) {
-                    info!("CDP with local_id {:?} has an invalid debt asset", local_id);
-                    return false;
-                }
+            if cdp.loans.len() == 1 && !cdp.loans.contains_key(&self.debt) {
+                info!("CDP with local_id {:?} has an invalid debt asset", local_id);
+                return false;
             }
 
             true
         }
+
+        /// Returns the units of the debt asset borrowed by the given CDP.
+        fn __debt_units(&self, local_id: &NonFungibleLocalId) -> Decimal {
+            let cdp = self.cdp_manager.get_non_fungible_data::<CollaterizedDebtPositionData>(local_id);
+
+            match cdp.loans.get(&self.debt) {
+                Some(loan) => loan.checked_truncate(RoundingMode::ToZero).unwrap(),
+                None => dec!(0),
+            }
+        }
     }
 }
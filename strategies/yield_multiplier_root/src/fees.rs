@@ -10,11 +10,13 @@ pub struct FeeStructure {
     pub execute: Decimal,
 }
 
-impl FeeStructure {
-    pub fn default() -> Self {
+impl Default for FeeStructure {
+    fn default() -> Self {
         Self { open: dec!(16), close: dec!(4), execute: dec!(4) }
     }
+}
 
+impl FeeStructure {
     pub fn set(&mut self, open: Option<Decimal>, close: Option<Decimal>, execute: Option<Decimal>) {
         self.open = open.unwrap_or(self.open);
         self.close = close.unwrap_or(self.close);
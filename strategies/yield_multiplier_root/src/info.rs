@@ -1,5 +1,7 @@
 /* ------------------ Imports ----------------- */
+use crate::root::MarketState;
 use scrypto::prelude::*;
+use shared::utils::annualise_rate;
 
 /* ------------------ Cluster ----------------- */
 #[derive(ScryptoSbor, Debug, Clone)]
@@ -28,3 +30,37 @@ pub struct AccountInfo {
     pub supply_units: Decimal,
     pub debt_units: Decimal,
 }
+
+/* ------------------- Rates ------------------ */
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct MarketRates {
+    pub supply: RateInfo,
+    pub debt: RateInfo,
+    pub leverage: Decimal,
+    pub net_apr: Decimal,
+    pub net_apy: Decimal,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct RateInfo {
+    pub resource: ResourceAddress,
+    pub supply_apr: Decimal,
+    pub borrow_apr: Decimal,
+    pub utilisation: Decimal,
+}
+
+impl RateInfo {
+    pub fn new(resource: ResourceAddress, state: &MarketState) -> Self {
+        let utilisation = match state.total_deposit > dec!(0) {
+            true => state.total_loan / state.total_deposit,
+            false => dec!(0),
+        };
+
+        Self {
+            resource,
+            supply_apr: annualise_rate(state.supply_rate),
+            borrow_apr: annualise_rate(state.borrow_rate),
+            utilisation,
+        }
+    }
+}
@@ -1,3 +1,6 @@
+// Scrypto's generated test bindings take an extra `env` argument beyond each function's own
+#![allow(clippy::too_many_arguments)]
+
 pub mod accounts;
 pub mod cluster;
 pub mod fees;
@@ -1,11 +1,7 @@
 use scrypto::prelude::*;
 
 /* ------------- Price Oracle Data ------------ */
-#[derive(ScryptoSbor, Clone)]
-pub struct PriceInfo {
-    pub timestamp: i64,
-    pub price: Decimal,
-}
+pub use shared::oracles::PriceInfo;
 /* --------------- CDP Breakdown -------------- */
 #[derive(ScryptoSbor, NonFungibleData, Clone, Debug)]
 pub struct CollaterizedDebtPositionData {
@@ -54,3 +50,41 @@ pub enum CDPType {
     /// A CDP where an user is directly responsible of his borrows and collaterals, subject to liquidation
     Standard,
 }
+
+/* ------------------ Market ------------------ */
+/// Thin wrapper around Root's lending market, for the calls made by the cluster on its CDPs.
+pub struct RootMarket {
+    component: Global<AnyComponent>,
+}
+
+impl RootMarket {
+    pub fn new(address: ComponentAddress) -> Self {
+        Self { component: address.into() }
+    }
+
+    /// Repays the CDP's loans, returning any excess payment.
+    pub fn repay(&self, cdp_proof: NonFungibleProof, payments: Vec<Bucket>) -> Vec<Bucket> {
+        self.component.call_raw("repay", scrypto_args!(cdp_proof, payments))
+    }
+
+    /// Withdraws collateral from the CDP; each withdrawal is a resource, an amount, and whether to keep the deposit units.
+    pub fn remove_collateral(&self, cdp_proof: NonFungibleProof, withdrawals: Vec<(ResourceAddress, Decimal, bool)>) -> Vec<Bucket> {
+        self.component.call_raw("remove_collateral", scrypto_args!(cdp_proof, withdrawals))
+    }
+
+    /// Returns the lending state of the given resources, through the market's `get_market_state` method, which maps each
+    /// resource to its totals and per-second rates; the mock Root market in `mocks` serves the same interface.
+    pub fn get_market_state(&self, resources: IndexSet<ResourceAddress>) -> IndexMap<ResourceAddress, MarketState> {
+        self.component.call_raw("get_market_state", scrypto_args!(resources))
+    }
+}
+
+/* --------------- Market State --------------- */
+/// Lending state of a resource on Root's market; interest rates are per second.
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct MarketState {
+    pub total_deposit: Decimal,
+    pub total_loan: Decimal,
+    pub supply_rate: Decimal,
+    pub borrow_rate: Decimal,
+}
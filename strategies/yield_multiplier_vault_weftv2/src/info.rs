@@ -0,0 +1,37 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+
+/* ------------------- Vault ------------------ */
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct VaultInfo {
+    pub platform_address: ComponentAddress,
+    pub vault_address: ComponentAddress,
+    pub linked: bool,
+    pub supply_res: ResourceAddress,
+    pub debt_res: ResourceAddress,
+    pub share_res: ResourceAddress,
+    pub ticket_res: ResourceAddress,
+    pub cdp_id: NonFungibleLocalId,
+    /// Net value of the vault in the supply asset; idle funds plus the CDP's collateral less its debt.
+    pub net_value: Decimal,
+    pub share_supply: Decimal,
+    /// Value of one share in the supply asset.
+    pub share_price: Decimal,
+    pub idle: Decimal,
+    pub collateral: Decimal,
+    pub debt: Decimal,
+    pub health: Decimal,
+    pub deleveraging: bool,
+    pub queue: QueueInfo,
+    pub max_value_loss: Decimal,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct QueueInfo {
+    /// Local ID of the next ticket to be processed.
+    pub head: u64,
+    /// Local ID of the next ticket to be issued.
+    pub tail: u64,
+    pub queued_deposits: Decimal,
+    pub queued_shares: Decimal,
+}
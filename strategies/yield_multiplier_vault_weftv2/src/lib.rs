@@ -0,0 +1,8 @@
+// Scrypto's generated test bindings take an extra `env` argument beyond each function's own
+#![allow(clippy::too_many_arguments)]
+
+pub mod info;
+pub mod queue;
+pub mod services;
+pub mod vault;
+pub mod weft;
@@ -0,0 +1,36 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+
+/* ------------------- Queue ------------------ */
+/// Direction of a queued request.
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueueKind {
+    /// Supply asset waiting to be exchanged for shares.
+    Deposit,
+    /// Shares waiting to be redeemed for the supply asset.
+    Withdraw,
+}
+
+/// Ticket for a queued deposit or withdrawal, redeemed through `claim` once processed.
+#[derive(ScryptoSbor, NonFungibleData, Debug, Clone)]
+pub struct QueueTicket {
+    pub kind: QueueKind,
+    /// Amount of the supply asset deposited, or of shares withdrawn.
+    pub amount: Decimal,
+    pub queued_at: Instant,
+}
+
+/// State of a queued request, keyed by the local ID of its ticket.
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct QueueEntry {
+    pub kind: QueueKind,
+    pub amount: Decimal,
+    /// Shares minted for a deposit, or supply asset paid for a withdrawal; set once processed.
+    pub payout: Option<Decimal>,
+}
+
+impl QueueEntry {
+    pub fn new(kind: QueueKind, amount: Decimal) -> Self {
+        Self { kind, amount, payout: None }
+    }
+}
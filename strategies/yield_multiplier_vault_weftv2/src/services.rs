@@ -0,0 +1,31 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+use shared::services::{ServiceKey, ServiceRegistry};
+
+/* ------------ Operating Services ------------ */
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VaultService {
+    Deposit,
+    Withdraw,
+    ProcessQueue,
+    Execute,
+    Link,
+    // Unlink,
+    CallLinked,
+}
+
+impl ServiceKey for VaultService {
+    fn all() -> Vec<Self> {
+        vec![
+            VaultService::Deposit,
+            VaultService::Withdraw,
+            VaultService::ProcessQueue,
+            VaultService::Execute,
+            VaultService::Link,
+            // VaultService::Unlink,
+            VaultService::CallLinked,
+        ]
+    }
+}
+
+pub type VaultServiceManager = ServiceRegistry<VaultService>;
@@ -0,0 +1,836 @@
+/* ------------------ Imports ----------------- */
+// Modules
+use crate::info::{QueueInfo, VaultInfo};
+use crate::queue::{QueueEntry, QueueKind, QueueTicket};
+use crate::services::{VaultService, VaultServiceManager};
+use crate::weft::*;
+// Shared Modules
+use shared::links::Link;
+use shared::services::{ServicePolicy, ServiceValue, SetLock};
+use shared::utils::now;
+// Libraries
+use scrypto::prelude::*;
+
+/* ----------------- Blueprint ---------------- */
+type Unit = ();
+
+#[derive(NonFungibleData, ScryptoSbor)]
+pub struct ExecutionTerms {
+    /// Local ID of the vault's CDP, withdrawn for the execution.
+    pub cdp_id: NonFungibleLocalId,
+    /// Net value of the vault, in the supply asset, before the execution.
+    pub net_value: Decimal,
+}
+
+#[blueprint]
+#[types(
+    // General
+    Unit,
+    ComponentAddress,
+    ResourceAddress,
+    FungibleResourceManager,
+    NonFungibleResourceManager,
+    NonFungibleLocalId,
+    NonFungibleVault,
+    FungibleVault,
+    Decimal,
+    u64,
+    ExecutionTerms,
+    // Queue
+    QueueKind,
+    QueueTicket,
+    QueueEntry,
+    // Services
+    VaultServiceManager,
+    VaultService,
+    ServiceValue,
+    ServicePolicy,
+    SetLock,
+    // State Returns
+    VaultInfo,
+    QueueInfo,
+    // WeftV2 Integration
+    CDPData,
+    CDPHealthChecker,
+    LoanPositionData,
+    LoanConfig,
+    LoanResourceConfig,
+    CollateralPositionData,
+    CollateralConfig,
+    CollateralResourceConfig,
+    RegisteredResourceType,
+    NFTCollateralPositionData,
+    NFTLiquidationValue,
+    RegisteredNFTResourceType,
+    EfficiencyMode,
+    CollateralConfigVersion,
+    CollateralInfo,
+    NFTCollateralInfo,
+    LoanInfo
+)]
+mod yield_multiplier_weftv2_vault {
+    //] --------------- Scrypto Setup -------------- */
+    enable_method_auth! {
+        roles {
+            can_manage_services => updatable_by: [OWNER];
+            can_lock_services   => updatable_by: [OWNER];
+        },
+        methods {
+            // Links
+            handle_link => PUBLIC;
+            // Vault
+            get_vault_info       => PUBLIC;
+            get_claimed_entities => PUBLIC;
+            get_services         => PUBLIC;
+            update_service              => restrict_to: [can_manage_services, can_lock_services];
+            update_service_and_set_lock => restrict_to: [can_lock_services];
+            set_deleveraging            => restrict_to: [can_manage_services, OWNER];
+            set_max_value_loss          => restrict_to: [can_manage_services, OWNER];
+            // Shares
+            deposit       => PUBLIC;
+            withdraw      => PUBLIC;
+            process_queue => PUBLIC;
+            claim         => PUBLIC;
+            // Execution
+            start_execution => restrict_to: [can_manage_services, OWNER];
+            end_execution   => restrict_to: [can_manage_services, OWNER];
+        }
+    }
+
+    //] -------------- Vault Blueprint ------------- */
+    struct YieldMultiplierWeftV2Vault {
+        // Authorisation
+        component_address: ComponentAddress,
+        // Platform link
+        platform_address: ComponentAddress,
+        link: NonFungibleVault,
+        // Vault
+        supply: ResourceAddress,
+        debt: ResourceAddress,
+        deleveraging: bool,
+        max_value_loss: Decimal,
+        services: VaultServiceManager,
+        idle_vault: FungibleVault,
+        cdp_vault: NonFungibleVault,
+        // Shares
+        share_manager: FungibleResourceManager,
+        // Queue
+        ticket_manager: NonFungibleResourceManager,
+        queue: KeyValueStore<u64, QueueEntry>,
+        queue_head: u64,
+        queue_tail: u64,
+        queued_deposits: FungibleVault,
+        queued_shares: FungibleVault,
+        claimable_supply: FungibleVault,
+        claimable_shares: FungibleVault,
+        // Execution
+        execution_term_manager: NonFungibleResourceManager,
+        // Integration
+        weft_market_address: ComponentAddress,
+        cdp_manager: NonFungibleResourceManager,
+    }
+
+    impl YieldMultiplierWeftV2Vault {
+        /// Instantiates a new `YieldMultiplierWeftV2Vault` component, pooling deposits of the supply asset
+        /// into a single leveraged WeftV2 CDP against fungible share tokens.
+        ///
+        /// # Parameters
+        /// - `owner_rule`: Access rule defining the owner of the vault.
+        /// - `admin_rule`: Access rule defining the admins of the vault, which also manage its leverage.
+        /// - `platform_address`: The component address of the platform to which this vault links.
+        /// - `supply`: Resource address of the supply asset.
+        /// - `debt`: Resource address of the debt asset.
+        /// - `weft_market_address`: The component address of the WeftV2 lending market.
+        /// - `cdp`: The vault's WeftV2 CDP; must be empty.
+        ///
+        /// # Panics
+        /// - If the CDP is invalid (amount != 1, not empty).
+        ///
+        /// # Returns
+        /// A globally accessible `YieldMultiplierWeftV2Vault` component instance.
+        pub fn instantiate(
+            // Authorisation
+            owner_rule: AccessRule,
+            admin_rule: AccessRule,
+            // Link
+            platform_address: ComponentAddress,
+            // Vault
+            supply: ResourceAddress,
+            debt: ResourceAddress,
+            // Integration
+            weft_market_address: ComponentAddress,
+            cdp: NonFungibleBucket,
+        ) -> Global<YieldMultiplierWeftV2Vault> {
+            // Reserve component address
+            let (address_reservation, component_address) = Runtime::allocate_component_address(YieldMultiplierWeftV2Vault::blueprint_id());
+
+            let platform: Global<AnyComponent> = platform_address.into();
+            let link_resource = platform.call_raw::<ResourceAddress>("get_link_badge_address", scrypto_args!());
+            let dapp_definition_address: GlobalAddress = platform
+                .get_metadata("dapp_definition")
+                .unwrap_or(None)
+                .expect("Platform has no dApp definition");
+
+            // Validate the CDP
+            assert_eq!(cdp.amount(), dec!(1), "Invalid CDP amount; must contain 1 NFT");
+
+            let cdp_data = cdp.non_fungible::<CDPData>().data();
+            assert!(
+                cdp_data.collaterals.is_empty() && cdp_data.loans.is_empty() && cdp_data.nft_collaterals.is_empty(),
+                "CDP must be empty"
+            );
+
+            //] Authorisation
+            // Component
+            let component_access_rule: AccessRule = rule!(require(global_caller(component_address)));
+
+            // Component owner
+            let owner_role: OwnerRole = OwnerRole::Fixed(owner_rule.clone());
+
+            //] Resources
+            let share_manager = ResourceBuilder::new_fungible(owner_role.clone())
+                .divisibility(DIVISIBILITY_MAXIMUM)
+                .metadata(metadata! {
+                    init {
+                        "name"            => "L3//Yield Multiplier Vault Share - WeftV2", locked;
+                        "symbol"          => "l3YMV", locked;
+                        "description"     => "Share of a Lattic3 pooled Yield Multiplier vault, redeemable for the vault's supply asset.", locked;
+                        "dapp_definition" => dapp_definition_address, updatable;
+                    }
+                })
+                .mint_roles(mint_roles! {
+                    minter         => component_access_rule.clone();
+                    minter_updater => rule!(deny_all);
+                })
+                .burn_roles(burn_roles! {
+                    burner         => component_access_rule.clone();
+                    burner_updater => rule!(deny_all);
+                })
+                .create_with_no_initial_supply();
+            let share_resource = share_manager.address();
+
+            let ticket_manager = ResourceBuilder::new_integer_non_fungible::<QueueTicket>(owner_role.clone())
+                .metadata(metadata! {
+                    init {
+                        "name"            => "L3//Yield Multiplier Vault Queue Ticket - WeftV2", locked;
+                        "description"     => "Ticket for a queued deposit or withdrawal of a Lattic3 pooled Yield Multiplier vault.", locked;
+                        "dapp_definition" => dapp_definition_address, updatable;
+                    }
+                })
+                .mint_roles(mint_roles! {
+                    minter         => component_access_rule.clone();
+                    minter_updater => rule!(deny_all);
+                })
+                .burn_roles(burn_roles! {
+                    burner         => component_access_rule.clone();
+                    burner_updater => rule!(deny_all);
+                })
+                .create_with_no_initial_supply();
+
+            let execution_term_manager = ResourceBuilder::new_ruid_non_fungible::<ExecutionTerms>(owner_role.clone())
+                .metadata(metadata! {init {
+                    "dapp_definition" => dapp_definition_address, updatable;
+                }})
+                .mint_roles(mint_roles! {
+                    minter         => component_access_rule.clone();
+                    minter_updater => rule!(deny_all);
+                })
+                .burn_roles(burn_roles! {
+                    burner         => component_access_rule.clone();
+                    burner_updater => rule!(deny_all);
+                })
+                .deposit_roles(deposit_roles! {
+                    depositor         => rule!(deny_all);
+                    depositor_updater => rule!(deny_all);
+                })
+                .create_with_no_initial_supply();
+
+            //] Component Instantisation
+            // Metadata
+            let component_metadata = metadata! {
+                roles {
+                    metadata_setter         => OWNER;
+                    metadata_setter_updater => OWNER;
+                    metadata_locker         => OWNER;
+                    metadata_locker_updater => rule!(deny_all);
+                },
+                init {
+                    "name"            => "L3//Yield Multiplier Vault - WeftV2", locked;
+                    "description"     => "Lattic3 pooled vault for the Yield Multiplier strategy, built on top of the Weft V2 lending platform.", locked;
+                    "supply"          => supply, locked;
+                    "debt"            => debt, locked;
+                    "shares"          => share_resource, locked;
+                    "dapp_definition" => dapp_definition_address, updatable;
+                }
+            };
+
+            // Roles
+            let component_roles = roles! {
+                can_manage_services => admin_rule;
+                can_lock_services   => OWNER;
+            };
+
+            // Instantisation
+            let initial_state = Self {
+                component_address,
+                platform_address,
+                link: NonFungibleVault::new(link_resource),
+                supply,
+                debt,
+                deleveraging: false,
+                max_value_loss: dec!(0.01),
+                services: VaultServiceManager::new(ServicePolicy::DefaultOn),
+                idle_vault: FungibleVault::new(supply),
+                cdp_manager: cdp.resource_address().into(),
+                cdp_vault: NonFungibleVault::with_bucket(cdp),
+                share_manager,
+                ticket_manager,
+                queue: KeyValueStore::new(),
+                queue_head: 0,
+                queue_tail: 0,
+                queued_deposits: FungibleVault::new(supply),
+                queued_shares: FungibleVault::new(share_resource),
+                claimable_supply: FungibleVault::new(supply),
+                claimable_shares: FungibleVault::new(share_resource),
+                execution_term_manager,
+                weft_market_address,
+            };
+
+            let component: Global<YieldMultiplierWeftV2Vault> = initial_state
+                .instantiate()
+                .prepare_to_globalize(owner_role)
+                .roles(component_roles)
+                .metadata(component_metadata)
+                .with_address(address_reservation)
+                .globalize();
+
+            component
+        }
+
+        //] ------------------- Links ------------------ */
+        /// Handles the reception of a link badge. Initiated by the platform's link_cluster() method.
+        ///
+        /// # Parameters
+        /// - `bucket`: The bucket containing the link badge.
+        ///
+        /// # Panics
+        /// - If the Link service is disabled
+        /// - If the link badge is invalid (amount != 1, incorrect resource address)
+        /// - If the vault is already linked
+        pub fn handle_link(&mut self, bucket: NonFungibleBucket) {
+            // Check operating service
+            assert!(self.services.get(VaultService::Link).value, "VaultService::Link disabled");
+
+            // Sanity checks
+            assert_eq!(self.link.amount(), dec!(0), "Platform already linked");
+            assert_eq!(bucket.amount(), dec!(1), "Invalid bucket amount; must contain 1 link badge");
+            assert_eq!(
+                self.link.resource_address(),
+                bucket.resource_address(),
+                "Invalid link badge resource address"
+            );
+
+            // Link platform
+            self.link.put(bucket);
+        }
+
+        //] Private
+        /// Returns whether the linked platform has been paused; read from the `paused` flag of the link badge.
+        /// An unlinked vault is never considered paused.
+        fn __platform_paused(&self) -> bool {
+            if self.link.amount() == dec!(0) {
+                return false;
+            }
+
+            let link: Link = self.link.non_fungible::<Link>().data();
+            link.paused
+        }
+
+        //] ------------------- Vault ------------------ */
+        /// Returns the addresses of the vault's entities; the component and its resources. They are labelled with
+        /// the platform's dApp definition, and are to be registered in its `claimed_entities`.
+        pub fn get_claimed_entities(&self) -> Vec<GlobalAddress> {
+            vec![
+                self.component_address.into(),
+                self.share_manager.address().into(),
+                self.ticket_manager.address().into(),
+                self.execution_term_manager.address().into(),
+            ]
+        }
+
+        /// Returns general information about the vault, including the net value backing its shares.
+        ///
+        /// # Returns
+        /// A `VaultInfo` struct; see its fields for details.
+        pub fn get_vault_info(&self) -> VaultInfo {
+            let cdp_id = self.cdp_vault.non_fungible_local_id();
+            let (collateral, debt, health) = match self.__cdp_health(&cdp_id) {
+                Some(cdp_health) => (cdp_health.total_collateral_value, cdp_health.total_loan_value, cdp_health.liquidation_ltv),
+                None => (dec!(0), dec!(0), dec!(0)),
+            };
+
+            let net_value = self.__net_value();
+            let share_supply = self.__share_supply();
+            let share_price = if share_supply > dec!(0) { net_value / share_supply } else { dec!(1) };
+
+            let queue = QueueInfo {
+                head: self.queue_head,
+                tail: self.queue_tail,
+                queued_deposits: self.queued_deposits.amount(),
+                queued_shares: self.queued_shares.amount(),
+            };
+
+            VaultInfo {
+                platform_address: self.platform_address,
+                vault_address: self.component_address,
+                linked: self.link.amount() > dec!(0),
+                supply_res: self.supply,
+                debt_res: self.debt,
+                share_res: self.share_manager.address(),
+                ticket_res: self.ticket_manager.address(),
+                cdp_id,
+                net_value,
+                share_supply,
+                share_price,
+                idle: self.idle_vault.amount(),
+                collateral,
+                debt,
+                health,
+                deleveraging: self.deleveraging,
+                queue,
+                max_value_loss: self.max_value_loss,
+            }
+        }
+
+        //] Services
+        /// Returns every vault service, alongside its value and lock state.
+        pub fn get_services(&self) -> IndexMap<VaultService, ServiceValue> {
+            self.services.list()
+        }
+
+        /// Updates a vault service, assuming it is not locked.
+        ///
+        /// # Parameters
+        /// - `service`: The service to update.
+        /// - `value`: The value to set the service to.
+        /// - `enable_at`: Optional time at which the service is enabled.
+        /// - `disable_at`: Optional time at which the service is disabled.
+        ///
+        /// # Panics
+        /// - If the service is currently locked.
+        pub fn update_service(&mut self, service: VaultService, value: bool, enable_at: Option<Instant>, disable_at: Option<Instant>) {
+            self.services.update(service, value, enable_at, disable_at, SetLock::None);
+        }
+
+        /// Updates a vault service and sets the lock state.
+        ///
+        /// # Parameters
+        /// - `service`: The service to update.
+        /// - `value`: The value to set the service to.
+        /// - `locked`: The value to which the lock status of the service is set to.
+        /// - `enable_at`: Optional time at which the service is enabled.
+        /// - `disable_at`: Optional time at which the service is disabled.
+        pub fn update_service_and_set_lock(
+            &mut self,
+            service: VaultService,
+            value: bool,
+            locked: bool,
+            enable_at: Option<Instant>,
+            disable_at: Option<Instant>,
+        ) {
+            self.services.update(service, value, enable_at, disable_at, SetLock::Update(locked));
+        }
+
+        //] Management
+        /// Sets whether the vault is deleveraging. While deleveraging, deposits and withdrawals are queued
+        /// rather than settled immediately, and queued deposits are held until deleveraging ends.
+        ///
+        /// # Parameters
+        /// - `deleveraging`: Whether the vault is deleveraging.
+        pub fn set_deleveraging(&mut self, deleveraging: bool) {
+            self.deleveraging = deleveraging;
+        }
+
+        /// Sets the maximum share of the vault's net value an execution may lose, e.g. to swap slippage.
+        ///
+        /// # Parameters
+        /// - `max_value_loss`: The maximum loss, between 0 and 1.
+        ///
+        /// # Panics
+        /// - If the maximum loss is not within [0, 1].
+        pub fn set_max_value_loss(&mut self, max_value_loss: Decimal) {
+            assert!(
+                max_value_loss >= dec!(0) && max_value_loss <= dec!(1),
+                "Maximum value loss must be between 0 and 1"
+            );
+
+            self.max_value_loss = max_value_loss;
+        }
+
+        //] ------------------ Shares ------------------ */
+        /// Deposits the supply asset into the vault. Shares are minted immediately at the current share price,
+        /// unless the vault is deleveraging; the deposit is then queued, and a queue ticket is returned instead.
+        ///
+        /// # Parameters
+        /// - `deposit`: A `FungibleBucket` containing the supply asset.
+        ///
+        /// # Panics
+        /// - If the VaultService::Deposit is disabled.
+        /// - If the platform is paused.
+        /// - If the vault is executing.
+        /// - If the deposit is invalid (wrong type, empty).
+        ///
+        /// # Returns
+        /// - A `Bucket` containing either the minted shares, or a queue ticket.
+        pub fn deposit(&mut self, deposit: FungibleBucket) -> Bucket {
+            // Check operating service
+            assert!(self.services.get(VaultService::Deposit).value, "VaultService::Deposit disabled");
+            assert!(!self.__platform_paused(), "Platform paused");
+            assert!(!self.__executing(), "Vault executing");
+
+            // Validate the deposit
+            assert_eq!(deposit.resource_address(), self.supply, "Invalid deposit resource address");
+            assert!(deposit.amount() > dec!(0), "No deposit provided");
+
+            if self.deleveraging {
+                let ticket = self.__enqueue(QueueKind::Deposit, deposit.amount());
+                self.queued_deposits.put(deposit);
+
+                return ticket.into();
+            }
+
+            // Mint shares against the net value before the deposit
+            let shares = self.__shares_for(deposit.amount());
+            self.idle_vault.put(deposit);
+
+            self.share_manager.mint(shares).into()
+        }
+
+        /// Redeems shares for the supply asset. Withdrawals are paid immediately from the vault's idle funds,
+        /// unless the vault is deleveraging, holds too little idle supply, or already has queued requests; the
+        /// shares are then queued, and a queue ticket is returned instead.
+        ///
+        /// # Parameters
+        /// - `shares`: A `FungibleBucket` containing the vault's shares.
+        ///
+        /// # Panics
+        /// - If the VaultService::Withdraw is disabled.
+        /// - If the platform is paused.
+        /// - If the vault is executing.
+        /// - If the shares are invalid (wrong type, empty).
+        ///
+        /// # Returns
+        /// - A `Bucket` containing either the supply asset, or a queue ticket.
+        pub fn withdraw(&mut self, shares: FungibleBucket) -> Bucket {
+            // Check operating service
+            assert!(self.services.get(VaultService::Withdraw).value, "VaultService::Withdraw disabled");
+            assert!(!self.__platform_paused(), "Platform paused");
+            assert!(!self.__executing(), "Vault executing");
+
+            // Validate the shares
+            assert_eq!(shares.resource_address(), self.share_manager.address(), "Invalid share resource address");
+            assert!(shares.amount() > dec!(0), "No shares provided");
+
+            let payout = self.__value_of(shares.amount());
+            let pending = self.queue_head < self.queue_tail;
+
+            if self.deleveraging || pending || payout > self.idle_vault.amount() {
+                let ticket = self.__enqueue(QueueKind::Withdraw, shares.amount());
+                self.queued_shares.put(shares);
+
+                return ticket.into();
+            }
+
+            // Burn the shares, and pay out from the idle funds
+            shares.burn();
+
+            self.idle_vault.take(payout).into()
+        }
+
+        /// Processes queued deposits and withdrawals in order. Deposits are skipped while the vault is deleveraging,
+        /// left queued for a later call; processing stops at the first withdrawal exceeding the idle funds.
+        ///
+        /// # Parameters
+        /// - `limit`: The maximum number of requests to visit, processed or skipped.
+        ///
+        /// # Panics
+        /// - If the VaultService::ProcessQueue is disabled.
+        /// - If the vault is executing.
+        ///
+        /// # Returns
+        /// - The number of requests processed.
+        pub fn process_queue(&mut self, limit: u64) -> u64 {
+            // Check operating service
+            assert!(self.services.get(VaultService::ProcessQueue).value, "VaultService::ProcessQueue disabled");
+            assert!(!self.__executing(), "Vault executing");
+
+            let mut processed = 0;
+            let mut visited = 0;
+            let mut index = self.queue_head;
+
+            while visited < limit && index < self.queue_tail {
+                // Skip requests already settled, or claimed, out of order
+                let entry = match self.queue.get(&index) {
+                    Some(entry) if entry.payout.is_none() => entry.clone(),
+                    _ => {
+                        index += 1;
+                        continue;
+                    }
+                };
+                visited += 1;
+
+                let payout = match entry.kind {
+                    QueueKind::Deposit => {
+                        if self.deleveraging {
+                            index += 1;
+                            continue;
+                        }
+
+                        let shares = self.__shares_for(entry.amount);
+                        self.idle_vault.put(self.queued_deposits.take(entry.amount));
+                        self.claimable_shares.put(self.share_manager.mint(shares));
+
+                        shares
+                    }
+                    QueueKind::Withdraw => {
+                        let payout = self.__value_of(entry.amount);
+                        if payout > self.idle_vault.amount() {
+                            break;
+                        }
+
+                        self.queued_shares.take(entry.amount).burn();
+                        self.claimable_supply.put(self.idle_vault.take(payout));
+
+                        payout
+                    }
+                };
+
+                self.queue.get_mut(&index).unwrap().payout = Some(payout);
+                index += 1;
+                processed += 1;
+            }
+
+            // Advance the head past the settled requests at the front of the queue
+            while self.queue_head < self.queue_tail {
+                match self.queue.get(&self.queue_head) {
+                    Some(entry) if entry.payout.is_none() => break,
+                    _ => self.queue_head += 1,
+                }
+            }
+
+            processed
+        }
+
+        /// Claims the shares or supply asset owed on a processed queue ticket, and burns the ticket.
+        ///
+        /// # Parameters
+        /// - `ticket`: A `NonFungibleBucket` containing the queue ticket.
+        ///
+        /// # Panics
+        /// - If the ticket is invalid (wrong type, amount != 1).
+        /// - If the ticket has not been processed yet.
+        ///
+        /// # Returns
+        /// - A `FungibleBucket` containing the minted shares for a deposit, or the supply asset for a withdrawal.
+        pub fn claim(&mut self, ticket: NonFungibleBucket) -> FungibleBucket {
+            // Validate the ticket
+            assert_eq!(
+                ticket.resource_address(),
+                self.ticket_manager.address(),
+                "Invalid ticket resource address"
+            );
+            assert_eq!(ticket.amount(), dec!(1), "Invalid ticket amount; must contain 1 ticket");
+
+            let index = match ticket.non_fungible_local_id() {
+                NonFungibleLocalId::Integer(id) => id.value(),
+                _ => panic!("Invalid ticket local ID"),
+            };
+
+            let entry = self.queue.remove(&index).expect("Missing queue entry");
+            let payout = entry.payout.expect("Ticket not processed yet");
+
+            // Burn the ticket, and pay out
+            ticket.burn();
+
+            match entry.kind {
+                QueueKind::Deposit => self.claimable_shares.take(payout),
+                QueueKind::Withdraw => self.claimable_supply.take(payout),
+            }
+        }
+
+        //] Private
+        /// Issues a queue ticket for a request, and appends it to the queue.
+        fn __enqueue(&mut self, kind: QueueKind, amount: Decimal) -> NonFungibleBucket {
+            let index = self.queue_tail;
+
+            self.queue.insert(index, QueueEntry::new(kind, amount));
+            self.queue_tail += 1;
+
+            let ticket = QueueTicket { kind, amount, queued_at: Instant::new(now()) };
+            self.ticket_manager.mint_non_fungible(&NonFungibleLocalId::integer(index), ticket)
+        }
+
+        /// Returns the total supply of shares, including queued withdrawals yet to be burnt.
+        fn __share_supply(&self) -> Decimal {
+            self.share_manager.total_supply().unwrap_or(dec!(0))
+        }
+
+        /// Returns the shares minted for a deposit of the given amount of the supply asset.
+        /// The first deposit, or any deposit into a vault without value, is minted at a share price of 1.
+        fn __shares_for(&self, amount: Decimal) -> Decimal {
+            let net_value = self.__net_value();
+            let share_supply = self.__share_supply();
+
+            if share_supply == dec!(0) || net_value == dec!(0) {
+                return amount;
+            }
+
+            amount * share_supply / net_value
+        }
+
+        /// Returns the value, in the supply asset, of the given amount of shares.
+        fn __value_of(&self, shares: Decimal) -> Decimal {
+            let share_supply = self.__share_supply();
+            assert!(share_supply > dec!(0), "No shares in circulation");
+
+            shares * self.__net_value() / share_supply
+        }
+
+        /// Returns whether the vault's CDP is out on an execution.
+        fn __executing(&self) -> bool {
+            self.cdp_vault.amount() == dec!(0)
+        }
+
+        //] ----------------- Execution ---------------- */
+        /// Withdraws the vault's CDP and idle funds for the manager to adjust its leverage, alongside a
+        /// transient badge which must be returned through `end_execution` within the same transaction.
+        ///
+        /// # Parameters
+        /// - `amount`: The amount of idle supply to withdraw; e.g. to be added as collateral.
+        ///
+        /// # Panics
+        /// - If the VaultService::Execute is disabled.
+        /// - If the platform is paused.
+        /// - If the vault is already executing.
+        /// - If the amount exceeds the idle funds.
+        ///
+        /// # Returns
+        /// - A `NonFungibleBucket` containing the vault's CDP.
+        /// - A `FungibleBucket` containing the withdrawn idle supply.
+        /// - A `NonFungibleBucket` containing the execution terms transient badge.
+        pub fn start_execution(&mut self, amount: Decimal) -> (NonFungibleBucket, FungibleBucket, NonFungibleBucket) {
+            // Check operating service
+            assert!(self.services.get(VaultService::Execute).value, "VaultService::Execute disabled");
+            assert!(!self.__platform_paused(), "Platform paused");
+            assert!(!self.__executing(), "Vault executing");
+            assert!(amount <= self.idle_vault.amount(), "Insufficient idle funds");
+
+            let cdp_id = self.cdp_vault.non_fungible_local_id();
+            let terms = ExecutionTerms { cdp_id, net_value: self.__net_value() };
+            let execution_terms = self.execution_term_manager.mint_ruid_non_fungible(terms);
+
+            (self.cdp_vault.take_all(), self.idle_vault.take(amount), execution_terms)
+        }
+
+        /// Counterpart to `start_execution`, returns the vault's CDP and any supply asset, and validates that
+        /// the execution has not lost more of the vault's net value than allowed.
+        ///
+        /// # Parameters
+        /// - `cdp_bucket`: A `NonFungibleBucket` containing the vault's CDP.
+        /// - `terms_bucket`: A `NonFungibleBucket` containing the execution terms transient badge.
+        /// - `idle`: A `FungibleBucket` containing the supply asset to return to the idle funds.
+        ///
+        /// # Panics
+        /// - If the execution terms are invalid.
+        /// - If the CDP is not the one withdrawn by the execution, or holds assets other than the supply and debt assets.
+        /// - If the supply asset is invalid.
+        /// - If the net value of the vault dropped by more than the maximum value loss.
+        pub fn end_execution(&mut self, cdp_bucket: NonFungibleBucket, terms_bucket: NonFungibleBucket, idle: FungibleBucket) {
+            // Validate the execution terms
+            assert_eq!(
+                terms_bucket.resource_address(),
+                self.execution_term_manager.address(),
+                "Invalid execution terms"
+            );
+            let terms = terms_bucket.non_fungible::<ExecutionTerms>().data();
+
+            // Validate and return the CDP and idle funds
+            assert_eq!(cdp_bucket.resource_address(), self.cdp_manager.address(), "Invalid CDP resource address");
+            assert_eq!(idle.resource_address(), self.supply, "Invalid supply resource address");
+
+            let cdp_id = cdp_bucket.non_fungible_local_id();
+            assert_eq!(cdp_id, terms.cdp_id, "CDP does not match the execution terms");
+            assert!(self.__validate_cdp(&cdp_id), "Invalid CDP");
+
+            self.cdp_vault.put(cdp_bucket);
+            self.idle_vault.put(idle);
+
+            // Validate the change in net value
+            let net_value = self.__net_value();
+            assert!(
+                net_value >= terms.net_value * (dec!(1) - self.max_value_loss),
+                "Execution exceeds the maximum value loss"
+            );
+
+            // Burn the execution terms
+            self.execution_term_manager.burn(terms_bucket);
+        }
+
+        //] ------------------- Weft ------------------- */
+        /// Returns the net value of the vault, in the supply asset: the idle funds, plus the value of the CDP's
+        /// collateral less its debt, floored at zero. Queued deposits and claimable funds are excluded.
+        fn __net_value(&self) -> Decimal {
+            let idle = self.idle_vault.amount();
+
+            let cdp_health = match self.__cdp_health(&self.cdp_vault.non_fungible_local_id()) {
+                Some(cdp_health) => cdp_health,
+                None => return idle,
+            };
+
+            // A CDP with debt but no supply collateral, or underwater, holds no equity
+            let equity = match cdp_health.collateral_positions.get(&self.supply) {
+                Some(position) => ((cdp_health.total_collateral_value - cdp_health.total_loan_value) / position.price).max(dec!(0)),
+                None => dec!(0),
+            };
+
+            idle + equity
+        }
+
+        /// Fetches the health of the CDP from Weft, or `None` if the CDP has no positions.
+        fn __cdp_health(&self, cdp_id: &NonFungibleLocalId) -> Option<CDPHealthChecker> {
+            let cdp = self.cdp_manager.get_non_fungible_data::<CDPData>(cdp_id);
+            if cdp.collaterals.is_empty() && cdp.loans.is_empty() {
+                return None;
+            }
+
+            Some(WeftMarket::new(self.weft_market_address).get_cdp(cdp_id))
+        }
+
+        /// Validates the given CDP by checking its contents.
+        ///
+        /// # Validation Criteria
+        /// - The CDP must not have any NFT collaterals.
+        /// - The only collateral asset in the CDP must be the vault's supply asset.
+        /// - The only debt asset in the CDP must be the vault's debt asset.
+        fn __validate_cdp(&self, local_id: &NonFungibleLocalId) -> bool {
+            let cdp = self.cdp_manager.get_non_fungible_data::<CDPData>(local_id);
+
+            if !cdp.nft_collaterals.is_empty() {
+                info!("CDP with local_id {:?} has NFT collateral(s)", local_id);
+                return false;
+            }
+
+            if cdp.collaterals.keys().any(|&resource| resource != self.supply) {
+                info!("CDP with local_id {:?} has an invalid collateral asset", local_id);
+                return false;
+            }
+
+            if cdp.loans.keys().any(|&resource| resource != self.debt) {
+                info!("CDP with local_id {:?} has an invalid debt asset", local_id);
+                return false;
+            }
+
+            true
+        }
+    }
+}
@@ -0,0 +1,7 @@
+/* ------------------ Imports ----------------- */
+use scrypto_test::prelude::*;
+use yield_multiplier_vault_weftv2::vault::yield_multiplier_weftv2_vault_test::*;
+
+/* ------------------- Tests ------------------ */
+#[test]
+fn test() {}
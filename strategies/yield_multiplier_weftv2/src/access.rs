@@ -0,0 +1,58 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+
+/* --------------- Access Mode ---------------- */
+/// Who may open an account on a cluster; e.g. to soft-launch a strategy to selected users.
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessMode {
+    /// Any user may open an account.
+    Open,
+    /// Only users whose badge id is on the cluster's allow-list may open an account.
+    AllowList,
+    /// Only users presenting a proof of the given resource in their auth zone may open an account.
+    AccessResource(ResourceAddress),
+}
+
+/* ---------------- Allow-List ---------------- */
+/// Set of user badge ids granted access to a cluster in `AccessMode::AllowList`.
+#[derive(ScryptoSbor)]
+pub struct AllowList {
+    pub entries: KeyValueStore<NonFungibleLocalId, ()>,
+    pub size: u64,
+}
+
+impl Default for AllowList {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AllowList {
+    pub fn new() -> Self {
+        Self { entries: KeyValueStore::new(), size: 0 }
+    }
+
+    /// Adds the given user badge ids; ids already on the list are skipped.
+    pub fn add(&mut self, user_ids: Vec<NonFungibleLocalId>) {
+        for user_id in user_ids {
+            if self.entries.get(&user_id).is_none() {
+                self.entries.insert(user_id, ());
+                self.size += 1;
+            }
+        }
+    }
+
+    /// Removes the given user badge ids; ids not on the list are skipped.
+    pub fn remove(&mut self, user_ids: Vec<NonFungibleLocalId>) {
+        for user_id in user_ids {
+            if self.entries.remove(&user_id).is_some() {
+                self.size -= 1;
+            }
+        }
+    }
+
+    /// Whether the given user badge id is on the list.
+    pub fn contains(&self, user_id: &NonFungibleLocalId) -> bool {
+        self.entries.get(user_id).is_some()
+    }
+}
@@ -1,15 +1,170 @@
 /* ------------------ Imports ----------------- */
+use crate::invariants::ExecutionInvariants;
+use crate::journal::Journal;
+use crate::triggers::Triggers;
+use crate::weft::CDPHealthChecker;
 use scrypto::prelude::*;
-// use shared::utils::now;
+use shared::utils::now;
 
 /* ------------------- User ------------------- */
-#[derive(ScryptoSbor, Debug)]
+#[derive(ScryptoSbor)]
 pub struct AccountData {
     pub cdp_vault: NonFungibleVault,
+    pub delegation: Option<Delegation>,
+    pub state: AccountState,
+    pub cost_basis: CostBasis,
+    pub journal: Journal,
+    pub triggers: Triggers,
+    /// Collateral value at the account's last valuation; its share of the cluster's total collateral value.
+    pub collateral_value: Decimal,
 }
 
 impl AccountData {
-    pub fn new(cdp_vault: NonFungibleVault) -> Self {
-        Self { cdp_vault }
+    pub fn new(cdp_vault: NonFungibleVault, cost_basis: CostBasis) -> Self {
+        Self {
+            cdp_vault,
+            delegation: None,
+            state: AccountState::Idle,
+            cost_basis,
+            journal: Journal::new(),
+            triggers: Triggers::default(),
+            collateral_value: dec!(0),
+        }
+    }
+
+    /// Moves the account to the given state.
+    ///
+    /// # Panics
+    /// - If the transition from the current state is not allowed.
+    pub fn transition(&mut self, to: AccountState) {
+        assert!(
+            self.state.can_transition(to),
+            "Invalid account state transition: {:?} -> {:?}",
+            self.state,
+            to
+        );
+
+        self.state = to;
+    }
+}
+
+/* ---------------- Cost Basis ---------------- */
+/// Record of the value a user has put into an account, from which its profit and loss is derived.
+/// Values are in the lending market's price unit, and cover the cluster's supply and debt positions.
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct CostBasis {
+    /// Net value put into the account; collateral minus debt at entry, adjusted by the net deposits and
+    /// withdrawals of each execution.
+    pub value: Decimal,
+    /// Debt drawn, in amounts of the loan asset, excluding accrued interest.
+    pub principal: Decimal,
+    /// Profit and loss realised by withdrawals from the account.
+    pub realised_pnl: Decimal,
+}
+
+impl CostBasis {
+    /// Cost basis of an account opened with a CDP of the given entry value.
+    pub fn priced(entry: PositionValue) -> Self {
+        Self { value: entry.net, principal: entry.debt, realised_pnl: dec!(0) }
+    }
+
+    /// Records the change of the account's positions over an execution, from `before` to `after`. An increase of the net
+    /// value is a deposit, adding to the basis; a decrease is a withdrawal, realising the matching share of the basis.
+    pub fn record(&mut self, before: PositionValue, after: PositionValue) {
+        let flow = after.net - before.net;
+
+        if flow >= dec!(0) {
+            self.value += flow;
+        } else if before.net > dec!(0) {
+            let share = (-flow / before.net).min(dec!(1));
+            let released = self.value * share;
+
+            self.realised_pnl += -flow - released;
+            self.value -= released;
+        }
+
+        if after.debt >= before.debt {
+            self.principal += after.debt - before.debt;
+        } else if before.debt > dec!(0) {
+            self.principal -= self.principal * ((before.debt - after.debt) / before.debt);
+        }
+    }
+}
+
+/// Net value and debt amount of an account's supply and debt positions, at the prices at which it was taken.
+#[derive(ScryptoSbor, Debug, Clone, Copy, Default)]
+pub struct PositionValue {
+    pub net: Decimal,
+    pub debt: Decimal,
+}
+
+impl PositionValue {
+    /// Values the positions of the given collateral and loan assets in the CDP's health breakdown.
+    pub fn new(cdp_health: &CDPHealthChecker, collateral: ResourceAddress, loan: ResourceAddress) -> Self {
+        let collateral_value = cdp_health
+            .collateral_positions
+            .get(&collateral)
+            .map_or(dec!(0), |position| position.value);
+        let (loan_value, debt) = cdp_health
+            .loan_positions
+            .get(&loan)
+            .map_or((dec!(0), dec!(0)), |position| (position.value, position.amount));
+
+        Self { net: collateral_value - loan_value, debt }
+    }
+}
+
+/* ------------------- State ------------------ */
+/// Lifecycle of an account. The CDP is held by the cluster only while the account is `Idle`.
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountState {
+    /// The CDP is held by the cluster.
+    Idle,
+    /// The CDP is out on an `ExecutionTerms` badge, and must be returned through `end_execution`.
+    Executing,
+    /// The CDP is being withdrawn from the cluster.
+    Closing,
+    /// The CDP has been withdrawn; the account can be reopened with a new CDP.
+    Closed,
+}
+
+impl AccountState {
+    pub fn can_transition(&self, to: AccountState) -> bool {
+        matches!(
+            (self, to),
+            (AccountState::Idle, AccountState::Executing)
+                | (AccountState::Executing, AccountState::Idle)
+                | (AccountState::Idle, AccountState::Closing)
+                | (AccountState::Closing, AccountState::Closed)
+                | (AccountState::Closed, AccountState::Idle)
+        )
+    }
+}
+
+/* ---------------- Delegation ---------------- */
+/// Rights granted by a user to a delegate (e.g. a bot or keeper), allowing it to run executions on the user's account.
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct Delegation {
+    /// Access rule the delegate's proof must satisfy; e.g. a badge.
+    pub rule: AccessRule,
+    /// Limits of a delegated execution, enforced in `end_execution` over every position of the CDP: the maximum
+    /// increase of each debt, and the minimum share of each collateral retained.
+    pub limits: ExecutionInvariants,
+    /// Time at which the delegation expires.
+    pub expires_at: Option<Instant>,
+}
+
+impl Delegation {
+    pub fn new(rule: AccessRule, max_debt_increase: Option<Decimal>, min_collateral_retained: Option<Decimal>, expires_at: Option<Instant>) -> Self {
+        let limits = ExecutionInvariants::new(max_debt_increase, min_collateral_retained, None, false);
+
+        Self { rule, limits, expires_at }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        match self.expires_at {
+            Some(expires_at) => now() >= expires_at.seconds_since_unix_epoch,
+            None => false,
+        }
     }
 }
@@ -0,0 +1,128 @@
+/* ------------------ Imports ----------------- */
+use crate::weft::{CDPData, CDPHealthChecker};
+use scrypto::prelude::*;
+
+/* ------------------ Basket ------------------ */
+/// Set of collateral and debt resources a cluster accepts in its CDPs, each with a weight cap.
+///
+/// A weight cap is the maximum share of an asset's value in the total value of its side of the CDP
+/// (collateral or loans); e.g. `0.5` for 50%. A cap of `1` leaves the asset uncapped.
+/// NFT collaterals (e.g. validator claim NFTs) are only accepted once configured, and are capped
+/// on the combined value of all NFTs of a resource.
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct Basket {
+    pub collaterals: IndexMap<ResourceAddress, Decimal>,
+    pub loans: IndexMap<ResourceAddress, Decimal>,
+    pub nft_collaterals: IndexMap<ResourceAddress, Decimal>,
+}
+
+impl Basket {
+    /// Creates a new basket from the given resources and weight caps.
+    ///
+    /// # Panics
+    /// - If either side of the basket is empty.
+    /// - If any weight cap is not within (0, 1].
+    pub fn new(collaterals: IndexMap<ResourceAddress, Decimal>, loans: IndexMap<ResourceAddress, Decimal>) -> Self {
+        assert!(!collaterals.is_empty(), "Basket must contain at least one collateral");
+        assert!(!loans.is_empty(), "Basket must contain at least one loan");
+
+        Self::__assert_caps(&collaterals);
+        Self::__assert_caps(&loans);
+
+        Self { collaterals, loans, nft_collaterals: IndexMap::new() }
+    }
+
+    /// Replaces the accepted NFT collateral resources; an empty map disables NFT collateral.
+    ///
+    /// # Panics
+    /// - If any weight cap is not within (0, 1].
+    pub fn set_nft_collaterals(&mut self, nft_collaterals: IndexMap<ResourceAddress, Decimal>) {
+        Self::__assert_caps(&nft_collaterals);
+
+        self.nft_collaterals = nft_collaterals;
+    }
+
+    /// Creates a basket of a single, uncapped supply and debt pair.
+    pub fn single(supply: ResourceAddress, debt: ResourceAddress) -> Self {
+        Self::new(indexmap! { supply => dec!(1) }, indexmap! { debt => dec!(1) })
+    }
+
+    /// The primary supply asset; the first collateral of the basket.
+    pub fn supply(&self) -> ResourceAddress {
+        *self.collaterals.keys().next().unwrap()
+    }
+
+    /// The primary debt asset; the first loan of the basket.
+    pub fn debt(&self) -> ResourceAddress {
+        *self.loans.keys().next().unwrap()
+    }
+
+    /// Whether any asset of the basket has a weight cap below 1.
+    pub fn is_capped(&self) -> bool {
+        self.collaterals
+            .values()
+            .chain(self.loans.values())
+            .chain(self.nft_collaterals.values())
+            .any(|&cap| cap < dec!(1))
+    }
+
+    /// Returns whether every collateral, loan and NFT collateral entry of the CDP is part of the basket.
+    pub fn contains(&self, cdp: &CDPData) -> bool {
+        if cdp.collaterals.keys().any(|resource| !self.collaterals.contains_key(resource)) {
+            info!("CDP has a collateral asset outside of the basket");
+            return false;
+        }
+
+        if cdp.loans.keys().any(|resource| !self.loans.contains_key(resource)) {
+            info!("CDP has a debt asset outside of the basket");
+            return false;
+        }
+
+        if cdp.nft_collaterals.keys().any(|resource| !self.nft_collaterals.contains_key(resource)) {
+            info!("CDP has an NFT collateral outside of the basket");
+            return false;
+        }
+
+        true
+    }
+
+    /// Returns whether the value of every position of the CDP is within its weight cap.
+    /// Collateral positions backed by NFTs are capped through their NFT resource instead.
+    pub fn within_caps(&self, cdp_health: &CDPHealthChecker) -> bool {
+        for (resource, position) in cdp_health.collateral_positions.iter().filter(|(_, position)| !position.is_from_nft) {
+            let cap = self.collaterals.get(resource).copied().unwrap_or(dec!(0));
+
+            if position.value > cdp_health.total_collateral_value * cap {
+                info!("CDP collateral {:?} exceeds its weight cap", resource);
+                return false;
+            }
+        }
+
+        for (resource, position) in cdp_health.loan_positions.iter() {
+            let cap = self.loans.get(resource).copied().unwrap_or(dec!(0));
+
+            if position.value > cdp_health.total_loan_value * cap {
+                info!("CDP loan {:?} exceeds its weight cap", resource);
+                return false;
+            }
+        }
+
+        for (resource, positions) in cdp_health.nft_collateral_positions.iter() {
+            let cap = self.nft_collaterals.get(resource).copied().unwrap_or(dec!(0));
+            let value = positions.values().fold(dec!(0), |total, position| total + position.value.value);
+
+            if value > cdp_health.total_collateral_value * cap {
+                info!("CDP NFT collateral {:?} exceeds its weight cap", resource);
+                return false;
+            }
+        }
+
+        true
+    }
+
+    fn __assert_caps(caps: &IndexMap<ResourceAddress, Decimal>) {
+        for (_, &cap) in caps.iter() {
+            assert!(cap > dec!(0) && cap <= dec!(1), "Weight cap must be between 0 and 1");
+        }
+    }
+}
@@ -1,12 +1,22 @@
 /* ------------------ Imports ----------------- */
 // Modules
-use crate::accounts::AccountData;
+use crate::access::{AccessMode, AllowList};
+use crate::accounts::{AccountData, AccountState, CostBasis, Delegation, PositionValue};
+use crate::basket::Basket;
 use crate::fees::FeeStructure;
-use crate::info::{AccountInfo, ClusterInfo, FeeInfo};
+use crate::info::{AccountInfo, ClusterInfo, FeeInfo, NFTPositionInfo, PositionInfo};
+use crate::invariants::{ExecutionInvariants, ExecutionSnapshot};
+use crate::journal::{JournalEntry, JournalEvent, JournalValuation};
+use crate::limits::RiskLimits;
+use crate::mode::LeverageMode;
 use crate::services::{ClusterService, ClusterServiceManager};
+use crate::triggers::{Claimable, Triggers};
 use crate::weft::*;
 // Shared Modules
-use shared::services::{ServiceValue, SetLock};
+use shared::flash_loans::{FlashLoanProvider, FlashLoanSource};
+use shared::links::Link;
+use shared::oracles::{OracleSource, PriceOracle};
+use shared::services::{ServicePolicy, ServiceValue, SetLock};
 // use shared::utils::{now, SECONDS_PER_YEAR};
 // Libraries
 use scrypto::prelude::*;
@@ -18,6 +28,10 @@ type Unit = ();
 #[derive(NonFungibleData, ScryptoSbor)]
 pub struct ExecutionTerms {
     pub user_id: NonFungibleLocalId,
+    pub cdp_id: NonFungibleLocalId,
+    pub delegation: Option<ExecutionInvariants>,
+    pub snapshot: ExecutionSnapshot,
+    pub entry: PositionValue,
 }
 
 #[blueprint]
@@ -35,15 +49,38 @@ pub struct ExecutionTerms {
     i64,
     FeeStructure,
     ExecutionTerms,
+    Delegation,
+    AccountState,
+    CostBasis,
+    PositionValue,
+    JournalEntry,
+    JournalEvent,
+    JournalValuation,
+    Triggers,
+    Claimable,
+    PriceOracle,
+    OracleSource,
+    FlashLoanProvider,
+    FlashLoanSource,
+    ExecutionInvariants,
+    ExecutionSnapshot,
+    RiskLimits,
+    AccessMode,
+    AllowList,
+    Basket,
+    LeverageMode,
     // Services
     ClusterServiceManager,
     ClusterService,
     ServiceValue,
+    ServicePolicy,
     SetLock,
     // State Returns
     AccountInfo,
     ClusterInfo,
     FeeInfo,
+    PositionInfo,
+    NFTPositionInfo,
     // WeftV2 Integration
     CDPData,
     CDPHealthChecker,
@@ -75,17 +112,41 @@ mod yield_multiplier_weftv2_cluster {
             // Links
             handle_link => PUBLIC;
             // Cluster
-            get_cluster_info => PUBLIC;
+            get_cluster_info     => PUBLIC;
+            get_claimed_entities => PUBLIC;
+            get_services         => PUBLIC;
             update_service              => restrict_to: [can_manage_services, can_lock_services];
             update_service_and_set_lock => restrict_to: [can_lock_services];
             set_fee_structure           => restrict_to: [can_manage_fees];
             collect_fees                => restrict_to: [can_manage_fees];
+            set_execution_invariants    => restrict_to: [can_manage_services, OWNER];
+            set_risk_limits             => restrict_to: [can_manage_services, OWNER];
+            set_access_mode             => restrict_to: [can_manage_services, OWNER];
+            add_to_allow_list           => restrict_to: [can_manage_services, OWNER];
+            remove_from_allow_list      => restrict_to: [can_manage_services, OWNER];
+            set_nft_collaterals         => restrict_to: [can_manage_services, OWNER];
+            set_oracle                  => restrict_to: [can_manage_services, OWNER];
+            set_flash_loan_provider     => restrict_to: [can_manage_services, OWNER];
+            get_flash_loan_quote        => PUBLIC;
+            take_flash_loan             => PUBLIC;
+            repay_flash_loan            => PUBLIC;
             // Accounts
             open_account     => PUBLIC;
             close_account    => PUBLIC;
             get_account_info => PUBLIC;
+            get_account_history => PUBLIC;
             start_execution  => PUBLIC;
             end_execution    => PUBLIC;
+            // Delegation
+            set_delegate    => PUBLIC;
+            revoke_delegate => PUBLIC;
+            // Triggers
+            set_triggers       => PUBLIC;
+            set_trigger_bounty => restrict_to: [can_manage_fees];
+            execute_trigger    => PUBLIC;
+            claim              => PUBLIC;
+            // Refinancing
+            import_account => PUBLIC;
         }
     }
 
@@ -100,11 +161,21 @@ mod yield_multiplier_weftv2_cluster {
         // Cluster
         supply: ResourceAddress,
         debt: ResourceAddress,
+        mode: LeverageMode,
+        basket: Basket,
         services: ClusterServiceManager,
         execution_term_manager: NonFungibleResourceManager,
+        invariants: ExecutionInvariants,
+        limits: RiskLimits,
+        access_mode: AccessMode,
+        allow_list: AllowList,
+        oracle: Option<PriceOracle>,
+        flash_loan: Option<FlashLoanProvider>,
         // Accounts
         accounts: KeyValueStore<NonFungibleLocalId, AccountData>,
         account_count: u64,
+        total_collateral_value: Decimal,
+        claimable: KeyValueStore<NonFungibleLocalId, Claimable>,
         // Fees
         fee_structure: FeeStructure,
         fee_vault: FungibleVault,
@@ -124,6 +195,7 @@ mod yield_multiplier_weftv2_cluster {
         /// - `user_resource`: Resource address for the user badge required for user operations.
         /// - `supply`: Resource address for the supply asset of the cluster.
         /// - `debt`: Resource address for the debt asset of the cluster.
+        /// - `mode`: The direction in which the supply and debt assets are levered.
         /// - `cdp_resource`:Resource address of the WeftV2 CDP NFT.
         ///
         /// # Returns
@@ -137,16 +209,94 @@ mod yield_multiplier_weftv2_cluster {
             // Cluster
             supply: ResourceAddress,
             debt: ResourceAddress,
+            mode: LeverageMode,
             // Integration
             weft_market_address: ComponentAddress,
             cdp_resource: ResourceAddress,
         ) -> Global<YieldMultiplierWeftV2Cluster> {
+            let (collateral, loan) = mode.cdp_roles(supply, debt);
+
+            Self::__instantiate(
+                owner_rule,
+                admin_rule,
+                platform_address,
+                mode,
+                Basket::single(collateral, loan),
+                weft_market_address,
+                cdp_resource,
+            )
+        }
+
+        /// Instantiates a new basket `YieldMultiplierWeftV2Cluster` component, accepting CDPs with any combination
+        /// of the allowed collateral and debt assets. The first collateral and debt assets are the cluster's
+        /// primary supply and debt assets, used for its metadata, execution invariants and delegation limits.
+        /// Basket clusters always lever up their supply assets; see `LeverageMode::Long`.
+        ///
+        /// # Parameters
+        /// - `owner_rule`: Access rule defining the owner of the cluster.
+        /// - `admin_rule`: Access rule defining the admins of the cluster.
+        /// - `platform_address`: The component address of the platform to which this cluster links.
+        /// - `collaterals`: Allowed collateral assets, mapped to their weight caps within (0, 1].
+        /// - `loans`: Allowed debt assets, mapped to their weight caps within (0, 1].
+        /// - `weft_market_address`: The component address of the WeftV2 market.
+        /// - `cdp_resource`:Resource address of the WeftV2 CDP NFT.
+        ///
+        /// # Panics
+        /// - If either side of the basket is empty, or a weight cap is out of range.
+        ///
+        /// # Returns
+        /// A globally accessible `YieldMultiplierWeftV2Cluster` component instance.
+        pub fn instantiate_basket(
+            // Authorisation
+            owner_rule: AccessRule,
+            admin_rule: AccessRule,
+            // Link
+            platform_address: ComponentAddress,
+            // Cluster
+            collaterals: IndexMap<ResourceAddress, Decimal>,
+            loans: IndexMap<ResourceAddress, Decimal>,
+            // Integration
+            weft_market_address: ComponentAddress,
+            cdp_resource: ResourceAddress,
+        ) -> Global<YieldMultiplierWeftV2Cluster> {
+            Self::__instantiate(
+                owner_rule,
+                admin_rule,
+                platform_address,
+                LeverageMode::Long,
+                Basket::new(collaterals, loans),
+                weft_market_address,
+                cdp_resource,
+            )
+        }
+
+        /// Instantiates the cluster with the given basket; shared by `instantiate` and `instantiate_basket`.
+        fn __instantiate(
+            // Authorisation
+            owner_rule: AccessRule,
+            admin_rule: AccessRule,
+            // Link
+            platform_address: ComponentAddress,
+            // Cluster
+            mode: LeverageMode,
+            basket: Basket,
+            // Integration
+            weft_market_address: ComponentAddress,
+            cdp_resource: ResourceAddress,
+        ) -> Global<YieldMultiplierWeftV2Cluster> {
+            // The basket holds the CDP's collateral and loan assets; map them back onto the supply and debt assets
+            let (supply, debt) = mode.cdp_roles(basket.supply(), basket.debt());
+
             // Reserve component address
             let (address_reservation, component_address) = Runtime::allocate_component_address(YieldMultiplierWeftV2Cluster::blueprint_id());
 
             let platform: Global<AnyComponent> = platform_address.into();
             let link_resource = platform.call_raw::<ResourceAddress>("get_link_badge_address", scrypto_args!());
             let user_resource = platform.call_raw::<ResourceAddress>("get_user_badge_address", scrypto_args!());
+            let dapp_definition_address: GlobalAddress = platform
+                .get_metadata("dapp_definition")
+                .unwrap_or(None)
+                .expect("Platform has no dApp definition");
 
             //] Authorisation
             // Component
@@ -156,6 +306,9 @@ mod yield_multiplier_weftv2_cluster {
             let owner_role: OwnerRole = OwnerRole::Fixed(owner_rule.clone());
 
             let execution_term_manager = ResourceBuilder::new_ruid_non_fungible::<ExecutionTerms>(owner_role.clone())
+                .metadata(metadata! {init {
+                    "dapp_definition" => dapp_definition_address, updatable;
+                }})
                 .mint_roles(mint_roles! {
                     minter         => component_access_rule.clone();
                     minter_updater => rule!(deny_all);
@@ -184,7 +337,7 @@ mod yield_multiplier_weftv2_cluster {
                     "description"     => "Lattic3 cluster component for the Yield Multiplier strategy, built on top of the Weft V2 lending platform.", locked;
                     "supply"          => supply, locked;
                     "debt"            => debt, locked;
-                    // "dapp_definition" => dapp_definition_address, updatable;
+                    "dapp_definition" => dapp_definition_address, updatable;
                 }
             };
 
@@ -203,10 +356,20 @@ mod yield_multiplier_weftv2_cluster {
                 user_resource,
                 supply,
                 debt,
+                mode,
+                basket,
                 accounts: KeyValueStore::new(),
                 account_count: 0,
+                total_collateral_value: dec!(0),
+                claimable: KeyValueStore::new(),
                 execution_term_manager,
-                services: ClusterServiceManager::new(),
+                invariants: ExecutionInvariants::none(),
+                limits: RiskLimits::none(),
+                access_mode: AccessMode::Open,
+                allow_list: AllowList::new(),
+                oracle: None,
+                flash_loan: None,
+                services: ClusterServiceManager::new(ServicePolicy::DefaultOn),
                 fee_structure: FeeStructure::default(),
                 fee_vault: FungibleVault::new(XRD),
                 weft_market_address,
@@ -236,7 +399,7 @@ mod yield_multiplier_weftv2_cluster {
         /// - If the cluster is already linked
         pub fn handle_link(&mut self, bucket: NonFungibleBucket) {
             // Check operating service
-            assert!(self.services.get(ClusterService::Link), "ClusterService::Link disabled");
+            assert!(self.services.get(ClusterService::Link).value, "ClusterService::Link disabled");
 
             // Sanity checks
             assert_eq!(self.link.amount(), dec!(0), "Platform already linked");
@@ -263,7 +426,7 @@ mod yield_multiplier_weftv2_cluster {
         /// - If the CallLinked service is disabled
         fn __with_link<F: FnOnce(Global<AnyComponent>, NonFungibleProof)>(&self, func: F) {
             assert!(self.link.amount() > dec!(0), "Cluster not linked");
-            assert!(self.services.get(ClusterService::CallLinked), "ClusterService::CallLinked disabled");
+            assert!(self.services.get(ClusterService::CallLinked).value, "ClusterService::CallLinked disabled");
 
             // Arrange call
             let link_local_id = self.link.non_fungible_local_id();
@@ -274,7 +437,24 @@ mod yield_multiplier_weftv2_cluster {
             func(platform, link_badge);
         }
 
+        /// Returns whether the linked platform has been paused; read from the `paused` flag of the link badge.
+        /// An unlinked cluster is never considered paused.
+        fn __platform_paused(&self) -> bool {
+            if self.link.amount() == dec!(0) {
+                return false;
+            }
+
+            let link: Link = self.link.non_fungible::<Link>().data();
+            link.paused
+        }
+
         //] ------------------ Cluster ----------------- */
+        /// Returns the addresses of the cluster's entities; the component and its resources. They are labelled with
+        /// the platform's dApp definition, and are to be registered in its `claimed_entities`.
+        pub fn get_claimed_entities(&self) -> Vec<GlobalAddress> {
+            vec![self.component_address.into(), self.execution_term_manager.address().into()]
+        }
+
         /// Returns general information about the cluster.
         ///
         /// # Returns
@@ -285,39 +465,71 @@ mod yield_multiplier_weftv2_cluster {
         /// - `account_count`: The number of accounts open on the cluster.
         /// - `supply_res`: The resource address of the supply asset.
         /// - `debt_res`: The resource address of the debt asset.
+        /// - `mode`: The direction in which the supply and debt assets are levered.
+        /// - `long_res`: The resource address of the asset held long by the cluster's positions.
+        /// - `short_res`: The resource address of the asset held short by the cluster's positions.
+        /// - `basket`: The allowed collateral and debt assets, with their weight caps.
+        /// - `invariants`: The invariants enforced at the end of every execution.
+        /// - `oracle`: The price oracle against which triggers are verified, if configured.
+        /// - `flash_loan`: The provider from which the cluster flash borrows its debt asset, if configured.
+        /// - `risk_limits`: The caps on the number and size of accounts, and on the cluster's total collateral value.
+        /// - `total_collateral_value`: The aggregate collateral value of every account, each as of its own last valuation;
+        ///   an approximation, as accounts are valued at different times and prices.
+        /// - `access_mode`: Who may open an account on the cluster.
+        /// - `allow_list_size`: The number of user badge ids on the cluster's allow-list.
         pub fn get_cluster_info(&self) -> ClusterInfo {
             // Return the fee amounts
             let fee_info = FeeInfo {
                 open: self.fee_structure.open,
                 close: self.fee_structure.close,
                 execute: self.fee_structure.execute,
+                bounty: self.fee_structure.bounty,
             };
 
-            let info = ClusterInfo {
+            // The CDP's collateral is held long, and its loan short
+            let (long_res, short_res) = self.mode.cdp_roles(self.supply, self.debt);
+
+            ClusterInfo {
                 platform_address: self.platform_address,
                 cluster_address: self.component_address,
                 linked: self.link.amount() > dec!(0),
                 supply_res: self.supply,
                 debt_res: self.debt,
+                mode: self.mode,
+                long_res,
+                short_res,
+                basket: self.basket.clone(),
                 account_count: self.account_count,
                 execution_term_manager: self.execution_term_manager,
+                invariants: self.invariants.clone(),
+                oracle: self.oracle.clone(),
+                flash_loan: self.flash_loan,
+                risk_limits: self.limits.clone(),
+                total_collateral_value: self.total_collateral_value,
+                access_mode: self.access_mode,
+                allow_list_size: self.allow_list.size,
                 fee_info,
-            };
-
-            info
+            }
         }
 
         //] Services
+        /// Returns every cluster service, alongside its value and lock state.
+        pub fn get_services(&self) -> IndexMap<ClusterService, ServiceValue> {
+            self.services.list()
+        }
+
         /// Updates a cluster service, assuming it is not locked.
         ///
         /// # Parameters
         /// - `service`: The service to update.
         /// - `value`: The value to set the service to.
+        /// - `enable_at`: Optional time at which the service is enabled.
+        /// - `disable_at`: Optional time at which the service is disabled.
         ///
         /// # Panics
         /// - If the service is currently locked.
-        pub fn update_service(&mut self, service: ClusterService, value: bool) {
-            self.services.update(service, value, SetLock::None);
+        pub fn update_service(&mut self, service: ClusterService, value: bool, enable_at: Option<Instant>, disable_at: Option<Instant>) {
+            self.services.update(service, value, enable_at, disable_at, SetLock::None);
         }
 
         /// Updates a cluster service and sets the lock state.
@@ -326,8 +538,195 @@ mod yield_multiplier_weftv2_cluster {
         /// - `service`: The service to update.
         /// - `value`: The value to set the service to.
         /// - `locked`: The value to which the lock status of the service is set to.
-        pub fn update_service_and_set_lock(&mut self, service: ClusterService, value: bool, locked: bool) {
-            self.services.update(service, value, SetLock::Update(locked));
+        /// - `enable_at`: Optional time at which the service is enabled.
+        /// - `disable_at`: Optional time at which the service is disabled.
+        pub fn update_service_and_set_lock(
+            &mut self,
+            service: ClusterService,
+            value: bool,
+            locked: bool,
+            enable_at: Option<Instant>,
+            disable_at: Option<Instant>,
+        ) {
+            self.services.update(service, value, enable_at, disable_at, SetLock::Update(locked));
+        }
+
+        //] Invariants
+        /// Sets the invariants enforced on a CDP at the end of every execution, relative to its state at the start.
+        ///
+        /// # Parameters
+        /// - `max_debt_increase`: Optional maximum increase of each debt of the basket, in loan units.
+        /// - `min_collateral_retained`: Optional minimum share of each collateral of the basket to be retained, between 0 and 1.
+        /// - `max_leverage_change`: Optional maximum relative change of the leverage (primary debt units per unit of primary
+        ///   collateral).
+        /// - `freeze_config`: Whether the efficiency mode and config versions of the positions must remain unchanged.
+        ///
+        /// # Panics
+        /// - If any of the limits is out of range.
+        pub fn set_execution_invariants(
+            &mut self,
+            max_debt_increase: Option<Decimal>,
+            min_collateral_retained: Option<Decimal>,
+            max_leverage_change: Option<Decimal>,
+            freeze_config: bool,
+        ) {
+            self.invariants = ExecutionInvariants::new(max_debt_increase, min_collateral_retained, max_leverage_change, freeze_config);
+        }
+
+        //] Risk Limits
+        /// Sets the caps on the number and size of accounts, checked when an account is opened and at the end of
+        /// every execution. Accounts already beyond a new limit are held to it on their next execution.
+        ///
+        /// # Parameters
+        /// - `max_accounts`: Optional maximum number of accounts open at once.
+        /// - `max_total_collateral_value`: Optional maximum aggregate collateral value, in the lending market's price unit.
+        /// - `min_collateral`: Optional minimum collateral of an account with open positions, in amounts of the collateral asset.
+        /// - `max_collateral`: Optional maximum collateral of an account, in amounts of the collateral asset.
+        /// - `min_debt`: Optional minimum debt of an account with an open loan, in loan units.
+        /// - `max_debt`: Optional maximum debt of an account, in loan units.
+        ///
+        /// # Panics
+        /// - If any of the limits is negative, or a minimum exceeds its maximum.
+        pub fn set_risk_limits(
+            &mut self,
+            max_accounts: Option<u64>,
+            max_total_collateral_value: Option<Decimal>,
+            min_collateral: Option<Decimal>,
+            max_collateral: Option<Decimal>,
+            min_debt: Option<Decimal>,
+            max_debt: Option<Decimal>,
+        ) {
+            self.limits = RiskLimits::new(
+                max_accounts,
+                max_total_collateral_value,
+                min_collateral,
+                max_collateral,
+                min_debt,
+                max_debt,
+            );
+        }
+
+        //] Access
+        /// Sets who may open an account on the cluster. Accounts already open are unaffected.
+        ///
+        /// # Parameters
+        /// - `access_mode`: `Open` to any user, restricted to the `AllowList`, or to holders of an `AccessResource`.
+        pub fn set_access_mode(&mut self, access_mode: AccessMode) {
+            self.access_mode = access_mode;
+        }
+
+        /// Adds user badge ids to the allow-list, consulted in `AccessMode::AllowList`.
+        ///
+        /// # Parameters
+        /// - `user_ids`: The local IDs of the users' badges.
+        pub fn add_to_allow_list(&mut self, user_ids: Vec<NonFungibleLocalId>) {
+            self.allow_list.add(user_ids);
+        }
+
+        /// Removes user badge ids from the allow-list. Accounts already open are unaffected.
+        ///
+        /// # Parameters
+        /// - `user_ids`: The local IDs of the users' badges.
+        pub fn remove_from_allow_list(&mut self, user_ids: Vec<NonFungibleLocalId>) {
+            self.allow_list.remove(user_ids);
+        }
+
+        //] NFT Collaterals
+        /// Sets the NFT collateral resources accepted in CDPs, e.g. validator claim NFTs, replacing any existing set.
+        /// An empty map disables NFT collateral. Accounts holding NFTs which are no longer accepted must remove
+        /// them in their next execution.
+        ///
+        /// # Parameters
+        /// - `nft_collaterals`: Accepted NFT collateral resources, mapped to their weight caps within (0, 1].
+        ///
+        /// # Panics
+        /// - If any weight cap is out of range.
+        pub fn set_nft_collaterals(&mut self, nft_collaterals: IndexMap<ResourceAddress, Decimal>) {
+            self.basket.set_nft_collaterals(nft_collaterals);
+        }
+
+        //] Oracle
+        /// Sets the price oracle of the cluster, against which triggers are verified; no sources removes the oracle.
+        ///
+        /// # Parameters
+        /// - `sources`: The price feeds to read, the first being the primary; e.g. a Root oracle guarded by others.
+        /// - `max_staleness`: Maximum age of a source's price, in seconds.
+        /// - `max_deviation`: Maximum relative deviation of a source's price from the primary's; e.g. `0.02` for 2%.
+        ///
+        /// # Panics
+        /// - If the maximum staleness or deviation is negative.
+        pub fn set_oracle(&mut self, sources: Vec<OracleSource>, max_staleness: i64, max_deviation: Decimal) {
+            self.oracle = match sources.is_empty() {
+                true => None,
+                false => Some(PriceOracle::new(sources, max_staleness, max_deviation)),
+            };
+        }
+
+        //] Flash Loans
+        /// Sets the provider from which the cluster flash borrows its debt asset; no source removes the provider.
+        ///
+        /// # Parameters
+        /// - `source`: The flash loan source; Weft, Root or a platform-owned liquidity vault.
+        /// - `fee_rate`: The fee Weft or Root charges on the borrowed amount; e.g. `0.0005` for 0.05%. The platform's pool
+        ///   charges the cluster its linked rate instead.
+        ///
+        /// # Panics
+        /// - If the fee rate is negative, or not below 1.
+        pub fn set_flash_loan_provider(&mut self, source: Option<FlashLoanSource>, fee_rate: Decimal) {
+            self.flash_loan = source.map(|source| FlashLoanProvider::new(source, fee_rate));
+        }
+
+        /// Returns the fee, in the debt asset, for flash borrowing `amount` from the cluster's provider.
+        ///
+        /// # Panics
+        /// - If no flash loan provider is configured.
+        pub fn get_flash_loan_quote(&self, amount: Decimal) -> Decimal {
+            self.flash_loan.expect("No flash loan provider configured").quote(amount)
+        }
+
+        /// Flash borrows `amount` of the loan asset from the cluster's provider, as a cluster linked to the platform;
+        /// for use within an execution, to lever or unwind the CDP in a single transaction.
+        ///
+        /// # Parameters
+        /// - `terms_proof`: A `NonFungibleProof` of the execution terms transient badge.
+        /// - `amount`: The amount of the loan asset to borrow.
+        ///
+        /// # Panics
+        /// - If the proof is not of execution terms.
+        /// - If no flash loan provider is configured, or the cluster is not linked.
+        ///
+        /// # Returns
+        /// - A `FungibleBucket` containing the borrowed funds.
+        /// - A `NonFungibleBucket` containing the flash loan's transient terms, to be returned to `repay_flash_loan`.
+        pub fn take_flash_loan(&mut self, terms_proof: NonFungibleProof, amount: Decimal) -> (FungibleBucket, NonFungibleBucket) {
+            terms_proof.check_with_message(self.execution_term_manager.address(), "Invalid execution terms");
+
+            let provider = self.flash_loan.expect("No flash loan provider configured");
+            assert!(self.link.amount() > dec!(0), "Cluster not linked");
+
+            let link_local_id = self.link.non_fungible_local_id();
+            let link_badge = self.link.create_proof_of_non_fungibles(&indexset![link_local_id]);
+            let (_, loan) = self.mode.cdp_roles(self.supply, self.debt);
+
+            provider.take(link_badge, loan, amount)
+        }
+
+        /// Repays a flash loan taken through `take_flash_loan`, returning its terms to the provider.
+        ///
+        /// # Parameters
+        /// - `repayment`: A `FungibleBucket` of the loan asset, covering the loan and the fee recorded in its terms.
+        /// - `terms`: A `NonFungibleBucket` containing the flash loan's transient terms.
+        ///
+        /// # Panics
+        /// - If no flash loan provider is configured.
+        /// - If the provider rejects the terms, or the repayment does not cover the amount due.
+        ///
+        /// # Returns
+        /// - A `FungibleBucket` containing the part of the repayment in excess of the amount due.
+        pub fn repay_flash_loan(&mut self, repayment: FungibleBucket, terms: NonFungibleBucket) -> FungibleBucket {
+            let provider = self.flash_loan.expect("No flash loan provider configured");
+
+            provider.repay(repayment, terms)
         }
 
         //] Fees
@@ -360,14 +759,22 @@ mod yield_multiplier_weftv2_cluster {
         /// # Panics
         /// - If the cluster is not linked.
         /// - If the ClusterService::OpenAccount is disabled.
-        /// - If the user already has an account.
-        /// - If the CDP is invalid.
+        /// - If the platform is paused.
+        /// - If the user already has an open account.
+        /// - If the CDP is invalid, or exceeds the basket's weight caps.
+        /// - If the cluster is at its maximum number of accounts, the CDP is outside the per-account limits, or raises the
+        ///   cluster past its total collateral value cap.
+        /// - If the user is not granted access by the cluster's access mode.
         ///
         /// # Returns
         /// A `FungibleBucket` containing the remainder of the fee.
         pub fn open_account(&mut self, user_badge: NonFungibleProof, cdp: NonFungibleBucket, mut fee_payment: FungibleBucket) -> FungibleBucket {
             // Check operating service
-            assert!(self.services.get(ClusterService::OpenAccount), "ClusterService::OpenAccount disabled");
+            assert!(
+                self.services.get(ClusterService::OpenAccount).value,
+                "ClusterService::OpenAccount disabled"
+            );
+            assert!(!self.__platform_paused(), "Platform paused");
 
             // Validate own link badge
             assert_eq!(self.link.amount(), dec!(1), "Cluster does not have a link badge");
@@ -379,6 +786,18 @@ mod yield_multiplier_weftv2_cluster {
             let cdp_id = cdp.non_fungible_local_id();
             let cdp_valid = self.__validate_cdp(cdp_id.clone());
             assert!(cdp_valid, "Invalid CDP");
+            assert!(self.__within_caps(&cdp_id), "CDP exceeds the basket's weight caps");
+
+            // Enforce the per-account risk limits
+            let snapshot = self.__snapshot(&cdp_id);
+            self.limits.check_accounts(self.account_count);
+            self.limits.check_position(&snapshot);
+
+            // Value the CDP as the entry of the cost basis; an empty CDP enters at zero
+            let (value, valuation) = self.__valuate(&cdp_id, &snapshot);
+            let collateral_value = valuation.collateral_value;
+            let cost_basis = CostBasis::priced(value);
+            let journal_entry = JournalEntry::new(JournalEvent::Open, &snapshot, Some(valuation));
 
             // Take fee payment
             let fee = self.fee_structure.open;
@@ -387,18 +806,35 @@ mod yield_multiplier_weftv2_cluster {
             // Update the user's badge
             let valid_user = self.__validate_user(user_badge);
             let user_id = valid_user.non_fungible_local_id();
+            self.__assert_access(&user_id);
+
+            // Only a closed account may be reopened
+            if let Some(account) = self.accounts.get(&user_id) {
+                assert_eq!(account.state, AccountState::Closed, "User already has an open account");
+            }
+
             self.__with_link(|platform, link_badge| platform.call_raw("open_account", scrypto_args!(link_badge, user_id.clone())));
 
             if self.accounts.get(&user_id).is_some() {
                 let mut account = self.accounts.get_mut(&user_id).unwrap();
-                assert!(account.cdp_vault.amount() == dec!(0), "User already has an account");
+                account.transition(AccountState::Idle);
 
                 account.cdp_vault.put(cdp);
+                account.delegation = None;
+                account.cost_basis = cost_basis;
+                account.triggers = Triggers::default();
+                account.journal.append(journal_entry);
             } else {
-                let account = AccountData::new(NonFungibleVault::with_bucket(cdp));
-                self.accounts.insert(user_id, account);
+                let mut account = AccountData::new(NonFungibleVault::with_bucket(cdp), cost_basis);
+                account.journal.append(journal_entry);
+                self.accounts.insert(user_id.clone(), account);
             }
 
+            // Count the CDP's collateral value towards the cluster's, enforcing its cap
+            let total_before = self.total_collateral_value;
+            self.__set_collateral_value(&user_id, collateral_value);
+            self.limits.check_total_collateral_value(total_before, self.total_collateral_value);
+
             // Update the account count
             self.account_count += 1;
             fee_payment
@@ -413,7 +849,8 @@ mod yield_multiplier_weftv2_cluster {
         /// # Panics
         /// - If the cluster is not linked.
         /// - If the ClusterService::CloseAccount is disabled.
-        /// - If the user does not have an account.
+        /// - If the platform is paused.
+        /// - If the user does not have an open account, or it is in an execution.
         /// - If the fee payment is invalid (wrong type, insufficient amount).
         ///
         /// # Returns
@@ -421,7 +858,11 @@ mod yield_multiplier_weftv2_cluster {
         /// - A `FungibleBucket` containing the remainder of the fee.
         pub fn close_account(&mut self, user_badge: NonFungibleProof, mut fee_payment: FungibleBucket) -> (NonFungibleBucket, FungibleBucket) {
             // Check operating service
-            assert!(self.services.get(ClusterService::CloseAccount), "ClusterService::CloseAccount disabled");
+            assert!(
+                self.services.get(ClusterService::CloseAccount).value,
+                "ClusterService::CloseAccount disabled"
+            );
+            assert!(!self.__platform_paused(), "Platform paused");
             assert!(self.account_count > 0, "No accounts to close");
 
             // Validate own link badge
@@ -436,12 +877,29 @@ mod yield_multiplier_weftv2_cluster {
             self.fee_vault.put(fee_payment.take(fee));
 
             // Extract the CDP and remove it from the cluster
-            let cdp_bucket = self.accounts.get_mut(&user_id).expect("User has no open account").cdp_vault.take_all();
+            let cdp_bucket = {
+                let mut account = self.accounts.get_mut(&user_id).expect("User has no open account");
+                account.transition(AccountState::Closing);
+
+                account.cdp_vault.take_all()
+            };
+
+            // Journal the position withdrawn
+            let cdp_id = cdp_bucket.non_fungible_local_id();
+            let snapshot = self.__snapshot(&cdp_id);
+            let (_, valuation) = self.__valuate(&cdp_id, &snapshot);
 
             // Update the user's badge
-            self.__with_link(|platform, link_badge| platform.call_raw("close_account", scrypto_args!(link_badge, user_id)));
+            self.__with_link(|platform, link_badge| platform.call_raw("close_account", scrypto_args!(link_badge, user_id.clone())));
+            {
+                let mut account = self.accounts.get_mut(&user_id).unwrap();
+                account.transition(AccountState::Closed);
+
+                account.journal.append(JournalEntry::new(JournalEvent::Close, &snapshot, Some(valuation)));
+            }
 
             // Remove the account
+            self.__set_collateral_value(&user_id, dec!(0));
             self.account_count -= 1;
             (cdp_bucket, fee_payment)
         }
@@ -458,32 +916,86 @@ mod yield_multiplier_weftv2_cluster {
 
             // Fetch and parse the CDP
             let cdp_id = account.cdp_vault.non_fungible_local_id();
-            let weft_market: Global<AnyComponent> = self.weft_market_address.into();
+            let cdp_health = self.__get_cdp_health(&cdp_id);
 
-            let cdp_health_map =
-                weft_market.call_raw::<IndexMap<NonFungibleLocalId, CDPHealthChecker>>("get_cdp", scrypto_args!(indexset![cdp_id.clone()]));
-            let cdp_health = cdp_health_map.get(&cdp_id.clone()).unwrap();
+            let (collateral, loan) = self.mode.cdp_roles(self.supply, self.debt);
 
-            let supply = match cdp_health.collateral_positions.get(&self.supply) {
+            let supply = match cdp_health.collateral_positions.get(&collateral) {
                 Some(collateral) => collateral.amount,
                 None => dec!(0),
             };
-            let debt = match cdp_health.loan_positions.get(&self.debt) {
+            let debt = match cdp_health.loan_positions.get(&loan) {
                 Some(loan) => loan.amount,
                 None => dec!(0),
             };
 
+            // Break down the positions per asset
+            let collaterals = cdp_health
+                .collateral_positions
+                .iter()
+                .map(|(&resource, position)| (resource, PositionInfo::new(position.amount, position.price, position.value)))
+                .collect();
+            let loans = cdp_health
+                .loan_positions
+                .iter()
+                .map(|(&resource, position)| (resource, PositionInfo::new(position.amount, position.price, position.value)))
+                .collect();
+
+            let nft_collaterals = cdp_health
+                .nft_collateral_positions
+                .iter()
+                .map(|(&resource, positions)| {
+                    let positions = positions
+                        .iter()
+                        .map(|(local_id, position)| (local_id.clone(), NFTPositionInfo::from(position)))
+                        .collect();
+
+                    (resource, positions)
+                })
+                .collect();
+
+            // Derive the profit and loss from the cost basis
+            let current = PositionValue::new(&cdp_health, collateral, loan);
+            let cost_basis = &account.cost_basis;
+
             // Construct and emit the account info
-            let info = AccountInfo {
+            AccountInfo {
                 cdp_id,
                 supply,
                 supply_value: cdp_health.total_collateral_value,
                 debt,
                 debt_value: cdp_health.total_loan_value,
                 health: cdp_health.liquidation_ltv,
-            };
+                collaterals,
+                loans,
+                nft_collaterals,
+                nft_value: cdp_health.discounted_nft_collateral_value,
+                triggers: account.triggers.clone(),
+                cost_basis: cost_basis.value,
+                unrealised_pnl: current.net - cost_basis.value,
+                realised_pnl: cost_basis.realised_pnl,
+                principal: cost_basis.principal,
+                accrued_interest: (current.debt - cost_basis.principal).max(dec!(0)),
+            }
+        }
+
+        /// Returns a page of an account's history, recorded on open, at the end of every execution, and on close.
+        ///
+        /// # Parameters
+        /// - `local_id`: The local ID of the account to query.
+        /// - `offset`: The sequence number of the first entry to return.
+        /// - `limit`: The maximum number of entries to return; at most `MAX_PAGE_SIZE`.
+        ///
+        /// # Panics
+        /// - If the user has never opened an account on the cluster.
+        /// - If the limit exceeds `MAX_PAGE_SIZE`.
+        ///
+        /// # Returns
+        /// - A `Vec<JournalEntry>` of the entries in sequence, empty past the end of the history.
+        pub fn get_account_history(&self, local_id: NonFungibleLocalId, offset: u64, limit: u64) -> Vec<JournalEntry> {
+            let account = self.accounts.get(&local_id).expect("User has no account history");
 
-            info
+            account.journal.page(offset, limit)
         }
 
         /// Starts an execution on the cluster, allowing the user to perform arbitrary
@@ -491,31 +1003,48 @@ mod yield_multiplier_weftv2_cluster {
         /// one transaction, and the CPD must be returned to the user at the end by
         /// calling the `end_execution` method.
         ///
+        /// A delegate of the user starts the execution with a proof satisfying the delegation's access rule instead of
+        /// the user's badge, and is held to the delegation's limits by `end_execution`.
+        ///
         /// # Parameters
-        /// - `user_badge`: A `NonFungibleProof` of the user's badge.
+        /// - `user_badge`: A `NonFungibleProof` of the user's badge; `None` for a delegated execution.
+        /// - `delegate`: The local ID of the user's badge, and a proof satisfying the access rule of their delegate;
+        ///   `None` for the user's own execution.
         ///
         /// # Panics
         /// - If the cluster is not linked to the platform.
         /// - If the ClusterService::Execute is disabled.
-        /// - If the user does not have an open account.
+        /// - If the platform is paused.
+        /// - If neither or both of the user's badge and a delegate proof are given.
+        /// - If the user does not have an open account, or it is already in an execution.
+        /// - If the user has no delegate, the delegation has expired, or the proof does not satisfy its access rule.
         ///
         /// # Returns
         /// - A `NonFungibleBucket` containing the user's CDP.
         /// - A `NonFungibleBucket` containing the execution terms transient badge.
-        pub fn start_execution(&mut self, user_badge: NonFungibleProof) -> (NonFungibleBucket, NonFungibleBucket) {
+        pub fn start_execution(
+            &mut self,
+            user_badge: Option<NonFungibleProof>,
+            delegate: Option<(NonFungibleLocalId, Proof)>,
+        ) -> (NonFungibleBucket, NonFungibleBucket) {
             // Check ClusterService::Execute enabled
-            assert!(self.services.get(ClusterService::Execute), "ClusterService::Execute disabled");
+            assert!(self.services.get(ClusterService::Execute).value, "ClusterService::Execute disabled");
+            assert!(!self.__platform_paused(), "Platform paused");
 
-            // Validate the user
-            let user_id = self.__validate_user(user_badge).non_fungible_local_id();
-
-            // Return CDP and execution terms
-            let cdp_bucket = self.accounts.get_mut(&user_id).expect("User has no open account").cdp_vault.take_all();
+            match (user_badge, delegate) {
+                (Some(user_badge), None) => {
+                    // Validate the user
+                    let user_id = self.__validate_user(user_badge).non_fungible_local_id();
 
-            let terms = ExecutionTerms { user_id };
-            let execution_terms = self.execution_term_manager.mint_ruid_non_fungible(terms);
-
-            (cdp_bucket, execution_terms)
+                    self.__start_execution(user_id, None)
+                }
+                (None, Some((user_id, delegate_proof))) => {
+                    // Validate the delegate
+                    let delegation = self.__validate_delegate(&user_id, delegate_proof);
+                    self.__start_execution(user_id, Some(delegation.limits))
+                }
+                _ => panic!("Execution must be started by either the user or their delegate"),
+            }
         }
 
         /// Counterpart to `start_execution`, returns the user's CDP to the cluster
@@ -527,7 +1056,11 @@ mod yield_multiplier_weftv2_cluster {
         ///
         /// # Panics
         /// - If the user does not have an open account.
-        /// - If the CDP is invalid (wrong type, insufficient amount).
+        /// - If the CDP is invalid (wrong type, insufficient amount), or does not match the execution terms.
+        /// - If the CDP exceeds the basket's weight caps.
+        /// - If the change of the CDP violates the cluster's execution invariants.
+        /// - If a delegated execution increased a debt, or withdrew a collateral, past the delegation's limits.
+        /// - If the CDP is outside the per-account limits, or raises the cluster past its total collateral value cap.
         ///
         /// # Returns
         /// - A `FungibleBucket` containing the remainder of the fee.
@@ -543,19 +1076,47 @@ mod yield_multiplier_weftv2_cluster {
 
             // Validate the CDP
             let cdp_id = cdp_bucket.non_fungible_local_id();
+            assert_eq!(cdp_id, terms.cdp_id, "CDP does not match the execution terms");
+
             let cdp_valid = self.__validate_cdp(cdp_id.clone());
             assert!(cdp_valid, "Invalid CDP");
+            assert!(self.__within_caps(&cdp_id), "CDP exceeds the basket's weight caps");
+
+            // Enforce the cluster's execution invariants
+            let snapshot = self.__snapshot(&cdp_id);
+            self.invariants.check(&terms.snapshot, &snapshot);
+
+            // Enforce the limits of a delegated execution
+            if let Some(delegation) = &terms.delegation {
+                delegation.check(&terms.snapshot, &snapshot);
+            }
+
+            // Enforce the per-account risk limits
+            self.limits.check_position(&snapshot);
 
             // Calculate the fee
             let fee = self.fee_structure.execute;
             self.fee_vault.put(fee_payment.take(fee));
 
+            // Record the execution's net deposits and withdrawals against the cost basis, and journal the new position
+            let (exit, valuation) = self.__valuate(&cdp_id, &snapshot);
+
+            // Count the new collateral value towards the cluster's, enforcing its cap
+            let total_before = self.total_collateral_value;
+            self.__set_collateral_value(&terms.user_id, valuation.collateral_value);
+            self.limits.check_total_collateral_value(total_before, self.total_collateral_value);
+
             // Return the CDP
-            self.accounts
-                .get_mut(&terms.user_id)
-                .expect("User has no open account")
-                .cdp_vault
-                .put(cdp_bucket);
+            {
+                let mut account = self.accounts.get_mut(&terms.user_id).expect("User has no open account");
+                account.transition(AccountState::Idle);
+
+                account.cost_basis.record(terms.entry, exit);
+                account
+                    .journal
+                    .append(JournalEntry::new(JournalEvent::Execution, &snapshot, Some(valuation)));
+                account.cdp_vault.put(cdp_bucket);
+            }
 
             // Burn the execution terms
             self.execution_term_manager.burn(terms_bucket);
@@ -563,7 +1124,373 @@ mod yield_multiplier_weftv2_cluster {
             fee_payment
         }
 
+        //] Delegation
+        /// Grants a delegate the right to run executions on the user's account, replacing any existing delegate.
+        /// The delegate starts executions through `start_execution`, with a proof satisfying the access rule.
+        ///
+        /// # Parameters
+        /// - `user_badge`: Proof of the user's badge from the platform.
+        /// - `rule`: Access rule the delegate's proof must satisfy; e.g. a badge.
+        /// - `max_debt_increase`: Optional maximum increase of each debt, in loan units, over a single execution.
+        /// - `min_collateral_retained`: Optional minimum share of each collateral retained over a single execution.
+        /// - `expires_at`: Optional time at which the delegation expires.
+        ///
+        /// # Panics
+        /// - If the user's account is not idle.
+        /// - If the maximum debt increase is negative, or the minimum collateral retained is not within [0, 1].
+        pub fn set_delegate(
+            &mut self,
+            user_badge: NonFungibleProof,
+            rule: AccessRule,
+            max_debt_increase: Option<Decimal>,
+            min_collateral_retained: Option<Decimal>,
+            expires_at: Option<Instant>,
+        ) {
+            let user_id = self.__validate_user(user_badge).non_fungible_local_id();
+
+            let mut account = self.accounts.get_mut(&user_id).expect("User has no open account");
+            assert_eq!(account.state, AccountState::Idle, "Account is not idle");
+
+            account.delegation = Some(Delegation::new(rule, max_debt_increase, min_collateral_retained, expires_at));
+        }
+
+        /// Revokes the delegate of the user's account.
+        ///
+        /// # Parameters
+        /// - `user_badge`: Proof of the user's badge from the platform.
+        ///
+        /// # Panics
+        /// - If the user does not have an account.
+        pub fn revoke_delegate(&mut self, user_badge: NonFungibleProof) {
+            let user_id = self.__validate_user(user_badge).non_fungible_local_id();
+
+            self.accounts.get_mut(&user_id).expect("User has no open account").delegation = None;
+        }
+
+        //] Triggers
+        /// Sets the stop-loss and take-profit triggers of the user's account, replacing any previous triggers.
+        /// Triggers are set on the price of the supply asset in the debt asset, and cleared when the account closes;
+        /// in `Short` mode the position loses as the price rises, so the stop-loss sits above the take-profit.
+        ///
+        /// # Parameters
+        /// - `user_badge`: A `NonFungibleProof` of the user's badge.
+        /// - `stop_loss`: Price past which a keeper may unwind the account at a loss; `None` to clear.
+        /// - `take_profit`: Price past which a keeper may unwind the account at a profit; `None` to clear.
+        ///
+        /// # Panics
+        /// - If the user does not have an open account, or it is in an execution.
+        /// - If either price is not positive, or the stop-loss is not on the losing side of the take-profit.
+        pub fn set_triggers(&mut self, user_badge: NonFungibleProof, stop_loss: Option<Decimal>, take_profit: Option<Decimal>) {
+            let valid_user = self.__validate_user(user_badge);
+            let user_id = valid_user.non_fungible_local_id();
+
+            let mut account = self.accounts.get_mut(&user_id).expect("User has no open account");
+            assert_eq!(account.state, AccountState::Idle, "Account is not idle");
+
+            account.triggers = Triggers::new(self.mode, stop_loss, take_profit);
+        }
+
+        /// Sets the bounty, in XRD, paid out of the cluster's fees to the keeper of each executed trigger.
+        pub fn set_trigger_bounty(&mut self, bounty: Decimal) {
+            self.fee_structure.set_bounty(bounty);
+        }
+
+        /// Unwinds a user's account once the oracle's price reaches one of its triggers. Permissionless; the keeper
+        /// repays the CDP's debt, and is reimbursed in collateral at the oracle's price, plus the bounty out of the
+        /// cluster's fees. The remaining collateral and the emptied CDP are held for the user to `claim`.
+        ///
+        /// # Parameters
+        /// - `user_id`: The local ID of the user's badge.
+        /// - `repayment`: A `FungibleBucket` of the loan asset with which to repay the CDP's debt.
+        ///
+        /// # Panics
+        /// - If the ClusterService::Trigger is disabled.
+        /// - If the platform is paused, or the cluster is not linked.
+        /// - If no oracle is configured, or its price is stale or its sources deviate.
+        /// - If the user does not have an idle account, or it has no triggers.
+        /// - If the oracle's price has not reached a trigger.
+        /// - If the CDP holds positions other than the cluster's supply and debt.
+        /// - If the repayment is not the loan asset, or does not cover the debt.
+        ///
+        /// # Returns
+        /// - A `FungibleBucket` of collateral reimbursing the keeper's repayment.
+        /// - A `FungibleBucket` containing the remainder of the repayment.
+        /// - A `FungibleBucket` containing the keeper's bounty, in XRD.
+        pub fn execute_trigger(
+            &mut self,
+            user_id: NonFungibleLocalId,
+            mut repayment: FungibleBucket,
+        ) -> (FungibleBucket, FungibleBucket, FungibleBucket) {
+            // Check operating service
+            assert!(self.services.get(ClusterService::Trigger).value, "ClusterService::Trigger disabled");
+            assert!(!self.__platform_paused(), "Platform paused");
+            assert_eq!(self.link.amount(), dec!(1), "Cluster does not have a link badge");
+
+            let (collateral, loan) = self.mode.cdp_roles(self.supply, self.debt);
+            assert_eq!(repayment.resource_address(), loan, "Repayment must be in the loan asset");
+
+            // Verify the trigger against the oracle
+            let oracle = self.oracle.clone().expect("No oracle configured");
+            let triggers = {
+                let account = self.accounts.get(&user_id).expect("User has no open account");
+                assert_eq!(account.state, AccountState::Idle, "Account is not idle");
+
+                account.triggers.clone()
+            };
+            assert!(triggers.is_set(), "Account has no triggers");
+
+            let price = oracle.get_price(self.supply, self.debt);
+            assert!(triggers.is_hit(self.mode, price), "Price has not reached a trigger");
+
+            // Extract the CDP
+            let cdp_bucket = {
+                let mut account = self.accounts.get_mut(&user_id).unwrap();
+                account.transition(AccountState::Closing);
+
+                account.cdp_vault.take_all()
+            };
+
+            let cdp_id = cdp_bucket.non_fungible_local_id();
+            let cdp = self.cdp_manager.get_non_fungible_data::<CDPData>(&cdp_id);
+            assert!(
+                cdp.collaterals.keys().all(|&resource| resource == collateral)
+                    && cdp.loans.keys().all(|&resource| resource == loan)
+                    && cdp.nft_collaterals.is_empty(),
+                "CDP holds positions outside of the cluster's supply and debt"
+            );
+
+            let snapshot = self.__snapshot(&cdp_id);
+            let (value, valuation) = self.__valuate(&cdp_id, &snapshot);
+            let weft_market = WeftMarket::new(self.weft_market_address);
+
+            // Repay the debt
+            let initial = repayment.amount();
+            if snapshot.debt > dec!(0) {
+                let payment = repayment.take(repayment.amount());
+
+                for excess in weft_market.repay(cdp_bucket.create_proof_of_all(), vec![payment.into()]) {
+                    repayment.put(excess.as_fungible());
+                }
+            }
+            assert_eq!(self.__snapshot(&cdp_id).debt, dec!(0), "Repayment does not cover the debt");
+            let repaid = initial - repayment.amount();
+
+            // Withdraw the collateral, reimbursing the keeper at the oracle's price
+            let mut proceeds = match snapshot.collateral > dec!(0) {
+                true => {
+                    let mut withdrawn =
+                        weft_market.remove_collateral(cdp_bucket.create_proof_of_all(), vec![(collateral, snapshot.collateral, false)]);
+                    withdrawn.pop().expect("No collateral withdrawn").as_fungible()
+                }
+                false => FungibleBucket::new(collateral),
+            };
+
+            let owed = (repaid * oracle.get_price(loan, collateral)).min(proceeds.amount());
+            let reimbursement = proceeds.take_advanced(owed, WithdrawStrategy::Rounded(RoundingMode::ToZero));
+
+            // Hold the remaining collateral and the emptied CDP for the user
+            if self.claimable.get(&user_id).is_none() {
+                self.claimable
+                    .insert(user_id.clone(), Claimable::new(collateral, self.cdp_manager.address()));
+            }
+            {
+                let mut claimable = self.claimable.get_mut(&user_id).unwrap();
+                claimable.collateral.put(proceeds);
+                claimable.cdps.put(cdp_bucket);
+            }
+
+            // Close the account and update the user's badge
+            self.__with_link(|platform, link_badge| platform.call_raw("close_account", scrypto_args!(link_badge, user_id.clone())));
+            {
+                let mut account = self.accounts.get_mut(&user_id).unwrap();
+                account.transition(AccountState::Closed);
+
+                account.triggers = Triggers::default();
+                account.cost_basis.record(value, PositionValue::default());
+                account.journal.append(JournalEntry::new(JournalEvent::Close, &snapshot, Some(valuation)));
+            }
+            self.__set_collateral_value(&user_id, dec!(0));
+            self.account_count -= 1;
+
+            // Pay the keeper's bounty out of the fees
+            let bounty = self.fee_vault.take(self.fee_structure.bounty.min(self.fee_vault.amount()));
+
+            (reimbursement, repayment, bounty)
+        }
+
+        /// Claims the proceeds of the user's accounts unwound by triggers.
+        ///
+        /// # Parameters
+        /// - `user_badge`: A `NonFungibleProof` of the user's badge.
+        ///
+        /// # Panics
+        /// - If the user has never had an account unwound.
+        ///
+        /// # Returns
+        /// - A `FungibleBucket` containing the remaining collateral.
+        /// - A `NonFungibleBucket` containing the emptied CDPs.
+        pub fn claim(&mut self, user_badge: NonFungibleProof) -> (FungibleBucket, NonFungibleBucket) {
+            let valid_user = self.__validate_user(user_badge);
+            let user_id = valid_user.non_fungible_local_id();
+
+            let mut claimable = self.claimable.get_mut(&user_id).expect("Nothing to claim");
+
+            (claimable.collateral.take_all(), claimable.cdps.take_all())
+        }
+
+        //] Refinancing
+        /// Opens an account for a user from the collateral of a refinanced position; deposits the collateral into the CDP
+        /// and re-borrows the debt repaid on the source cluster. Only callable by the platform, as part of its `refinance`
+        /// flow, which moves the account on the user's badge.
+        ///
+        /// # Parameters
+        /// - `user_id`: The local ID of the user's badge.
+        /// - `cdp`: A `NonFungibleBucket` containing the CDP to open the account with.
+        /// - `collateral`: A `FungibleBucket` of the supply asset to deposit into the CDP.
+        /// - `debt_amount`: The amount of the debt asset to borrow against the CDP.
+        ///
+        /// # Panics
+        /// - If the ClusterService::Refinance is disabled.
+        /// - If the platform is paused.
+        /// - If the caller is not the linked platform.
+        /// - If the cluster is not in `LeverageMode::Long`.
+        /// - If the CDP or collateral is invalid.
+        /// - If the user already has an open account.
+        /// - If the imported position is outside the cluster's risk limits.
+        /// - If the user is not granted access by the cluster's allow-list; an access resource cannot be presented here.
+        ///
+        /// # Returns
+        /// - A `FungibleBucket` containing the borrowed debt asset.
+        pub fn import_account(
+            &mut self,
+            user_id: NonFungibleLocalId,
+            cdp: NonFungibleBucket,
+            collateral: FungibleBucket,
+            debt_amount: Decimal,
+        ) -> FungibleBucket {
+            // Check operating service
+            assert!(self.services.get(ClusterService::Refinance).value, "ClusterService::Refinance disabled");
+            assert!(!self.__platform_paused(), "Platform paused");
+
+            // Validate the caller
+            assert_eq!(self.link.amount(), dec!(1), "Cluster does not have a link badge");
+            Runtime::assert_access_rule(rule!(require(global_caller(self.platform_address))));
+
+            assert_eq!(self.mode, LeverageMode::Long, "Refinancing requires LeverageMode::Long");
+            self.__assert_access(&user_id);
+
+            // Only a closed account may be reopened
+            if let Some(account) = self.accounts.get(&user_id) {
+                assert_eq!(account.state, AccountState::Closed, "User already has an open account");
+            }
+
+            // Validate the CDP and collateral
+            assert_eq!(cdp.amount(), dec!(1), "Invalid CDP amount; must contain 1 NFT");
+            assert_eq!(cdp.resource_address(), self.cdp_manager.address(), "Invalid CDP resource address");
+            assert_eq!(collateral.resource_address(), self.supply, "Collateral must be in the supply asset");
+
+            // Rebuild the position
+            let weft_market = WeftMarket::new(self.weft_market_address);
+            weft_market.add_collateral(cdp.create_proof_of_all(), vec![collateral.into()]);
+
+            let borrowed = match debt_amount > dec!(0) {
+                true => {
+                    let mut loans = weft_market.borrow(cdp.create_proof_of_all(), vec![(self.debt, debt_amount)]);
+                    loans.pop().expect("Nothing borrowed").as_fungible()
+                }
+                false => FungibleBucket::new(self.debt),
+            };
+
+            let cdp_id = cdp.non_fungible_local_id();
+            assert!(self.__validate_cdp(cdp_id.clone()), "Invalid CDP");
+            assert!(self.__within_caps(&cdp_id), "CDP exceeds the basket's weight caps");
+
+            let snapshot = self.__snapshot(&cdp_id);
+            self.limits.check_accounts(self.account_count);
+            self.limits.check_position(&snapshot);
+
+            let (value, valuation) = self.__valuate(&cdp_id, &snapshot);
+            let collateral_value = valuation.collateral_value;
+            let cost_basis = CostBasis::priced(value);
+            let journal_entry = JournalEntry::new(JournalEvent::Open, &snapshot, Some(valuation));
+
+            // Open the account
+            if self.accounts.get(&user_id).is_some() {
+                let mut account = self.accounts.get_mut(&user_id).unwrap();
+                account.transition(AccountState::Idle);
+
+                account.cdp_vault.put(cdp);
+                account.delegation = None;
+                account.cost_basis = cost_basis;
+                account.triggers = Triggers::default();
+                account.journal.append(journal_entry);
+            } else {
+                let mut account = AccountData::new(NonFungibleVault::with_bucket(cdp), cost_basis);
+                account.journal.append(journal_entry);
+                self.accounts.insert(user_id.clone(), account);
+            }
+
+            let total_before = self.total_collateral_value;
+            self.__set_collateral_value(&user_id, collateral_value);
+            self.limits.check_total_collateral_value(total_before, self.total_collateral_value);
+
+            self.account_count += 1;
+            borrowed
+        }
+
         //] Private
+        /// Withdraws the user's CDP, and mints the execution terms transient badge which must accompany its return.
+        fn __start_execution(
+            &mut self,
+            user_id: NonFungibleLocalId,
+            delegation: Option<ExecutionInvariants>,
+        ) -> (NonFungibleBucket, NonFungibleBucket) {
+            let cdp_bucket = {
+                let mut account = self.accounts.get_mut(&user_id).expect("User has no open account");
+                account.transition(AccountState::Executing);
+
+                account.cdp_vault.take_all()
+            };
+
+            let cdp_id = cdp_bucket.non_fungible_local_id();
+            let snapshot = self.__snapshot(&cdp_id);
+
+            // Value the positions at entry
+            let (entry, _) = self.__valuate(&cdp_id, &snapshot);
+
+            let terms = ExecutionTerms { user_id, cdp_id, delegation, snapshot, entry };
+            let execution_terms = self.execution_term_manager.mint_ruid_non_fungible(terms);
+
+            (cdp_bucket, execution_terms)
+        }
+
+        /// Asserts that the user may open an account under the cluster's access mode. An access resource must be
+        /// presented as a proof in the caller's auth zone.
+        fn __assert_access(&self, user_id: &NonFungibleLocalId) {
+            match self.access_mode {
+                AccessMode::Open => {}
+                AccessMode::AllowList => assert!(self.allow_list.contains(user_id), "User is not on the allow-list"),
+                AccessMode::AccessResource(resource) => Runtime::assert_access_rule(rule!(require(resource))),
+            }
+        }
+
+        /// Validates the delegate of the user's idle account against the given proof, returning the delegation.
+        fn __validate_delegate(&self, user_id: &NonFungibleLocalId, delegate_proof: Proof) -> Delegation {
+            let delegation = {
+                let account = self.accounts.get(user_id).expect("User has no open account");
+                assert_eq!(account.state, AccountState::Idle, "Account is not idle");
+
+                account.delegation.clone().expect("User has no delegate")
+            };
+            assert!(!delegation.is_expired(), "Delegation expired");
+
+            // Check the delegation's access rule against the delegate's proof
+            LocalAuthZone::push(delegate_proof);
+            Runtime::assert_access_rule(delegation.rule.clone());
+
+            delegation
+        }
+
         /// Validates the user's badge and returns the checked proof.
         fn __validate_user(&self, user_badge: NonFungibleProof) -> CheckedNonFungibleProof {
             // assert_eq!(user_badge.resource_address(), self.user_resource, "Invalid user badge resource address");
@@ -585,10 +1512,9 @@ mod yield_multiplier_weftv2_cluster {
         ///
         /// # Validation Criteria
         /// - The CDP must have a valid ResourceAddress.
-        /// - The CDP must contain a maximum of one collateral asset and one loan asset.
-        /// - The CDP must not have any NFT collaterals.
-        /// - The collateral asset in the CDP must match the expected supply asset.
-        /// - The debt asset in the CDP must match the expected debt asset.
+        /// - The collateral assets in the CDP must be part of the basket's collaterals.
+        /// - The debt assets in the CDP must be part of the basket's loans.
+        /// - The NFT collaterals in the CDP must be part of the basket's NFT collaterals; none by default.
         fn __validate_cdp(&self, local_id: NonFungibleLocalId) -> bool {
             // Parse CDP data or return false if fetching the data panics
             // Panic occurs if the cdp_manager cannot find an NFT with a matching local_id
@@ -600,57 +1526,70 @@ mod yield_multiplier_weftv2_cluster {
                 }
             };
 
-            // Validate supply & debt amounts
-            if cdp.collaterals.len() > 1 {
-                info!("CDP with local_id {:?} has more than one collateral", local_id);
-                return false;
-            }
-
-            if cdp.loans.len() > 1 {
-                info!("CDP with local_id {:?} has more than one loan", local_id);
-                return false;
-            }
-
-            // Validate that there are no NFT collaterals
-            if cdp.nft_collaterals.len() != 0 {
-                info!("CDP with local_id {:?} has NFT collateral(s)", local_id);
-                return false;
-            }
-
             //? Check for (unlikely) invalid CDP states
             // if cdp.loans.len() == 1 && cdp.collaterals.len() == 0 {
             //     info!("Invalid CDP state: 1 loan, 0 collateral");
             //     return false;
             // }
 
-            // Validate that all supply and debt assets are valid
-            // for (&resource, _) in cdp.collaterals.iter() {
-            //     if resource != self.supply {
-            //         info!("CDP with local_id {:?} has an invalid collateral asset", local_id);
-            //         return false;
-            //     }
-            // }
-            if cdp.collaterals.len() == 1 {
-                if !cdp.collaterals.contains_key(&self.supply) {
-                    info!("CDP with local_id {:?} has an invalid collateral asset", local_id);
-                    return false;
-                }
+            // Validate that all supply, debt and NFT collateral assets are part of the basket
+            if !self.basket.contains(&cdp) {
+                info!("CDP with local_id {:?} has an invalid collateral or debt asset", local_id);
+                return false;
             }
 
-            // for (&resource, _) in cdp.loans.iter() {
-            //     if resource != self.debt {
-            //         info!("CDP with local_id {:?} has an invalid debt asset", local_id);
-            //         return false;
-            //     }
-            // }
-            if cdp.loans.len() == 1 {
-                if !cdp.loans.contains_key(&self.debt) {
-                    info!("CDP with local_id {:?} has an invalid debt asset", local_id);
-                    return false;
-                }
+            true
+        }
+
+        /// Returns whether the positions of the given CDP are within the basket's weight caps.
+        /// Uncapped baskets are not checked, saving the call to Weft.
+        fn __within_caps(&self, local_id: &NonFungibleLocalId) -> bool {
+            if !self.basket.is_capped() {
+                return true;
             }
 
-            true
+            self.basket.within_caps(&self.__get_cdp_health(local_id))
+        }
+
+        /// Returns the health breakdown of the given CDP, queried from Weft using their `get_cdp` method.
+        fn __get_cdp_health(&self, local_id: &NonFungibleLocalId) -> CDPHealthChecker {
+            WeftMarket::new(self.weft_market_address).get_cdp(local_id)
+        }
+
+        /// Values the supply and debt positions of the given CDP at current prices, for its cost basis and journal.
+        /// Empty CDPs are valued at zero, saving the call to Weft.
+        fn __valuate(&self, local_id: &NonFungibleLocalId, snapshot: &ExecutionSnapshot) -> (PositionValue, JournalValuation) {
+            if snapshot.is_empty() {
+                return (PositionValue::default(), JournalValuation::default());
+            }
+
+            let cdp_health = self.__get_cdp_health(local_id);
+            let (collateral, loan) = self.mode.cdp_roles(self.supply, self.debt);
+
+            (
+                PositionValue::new(&cdp_health, collateral, loan),
+                JournalValuation::new(&cdp_health, collateral, loan),
+            )
+        }
+
+        /// Sets the collateral value of the user's account, and updates the cluster's total to match.
+        ///
+        /// The total is a running sum of each account's value as of its own last valuation, taken at different times
+        /// and prices; it approximates the cluster's collateral value rather than revaluing every account, which would
+        /// not be bounded by the number of accounts. Accounts left untouched drift from it as prices move.
+        fn __set_collateral_value(&mut self, user_id: &NonFungibleLocalId, value: Decimal) {
+            let mut account = self.accounts.get_mut(user_id).expect("User has no open account");
+
+            self.total_collateral_value = self.total_collateral_value - account.collateral_value + value;
+            account.collateral_value = value;
+        }
+
+        /// Returns a snapshot of the collateral and loan positions of the given CDP.
+        fn __snapshot(&self, local_id: &NonFungibleLocalId) -> ExecutionSnapshot {
+            let cdp = self.cdp_manager.get_non_fungible_data::<CDPData>(local_id);
+            let (collateral, loan) = self.mode.cdp_roles(self.supply, self.debt);
+
+            ExecutionSnapshot::new(&cdp, collateral, loan)
         }
     }
 }
@@ -3,21 +3,30 @@ use scrypto::prelude::*;
 
 /* -------------- Fee Breakpoints ------------- */
 #[derive(ScryptoSbor, Debug, Clone, Copy)]
-/// Indicates fee amounts (in XRD) for each operation.
+/// Indicates fee amounts (in XRD) for each operation, and the bounty paid out of the fees to keepers.
 pub struct FeeStructure {
     pub open: Decimal,
     pub close: Decimal,
     pub execute: Decimal,
+    pub bounty: Decimal,
 }
 
-impl FeeStructure {
-    pub fn default() -> Self {
-        Self { open: dec!(16), close: dec!(4), execute: dec!(4) }
+impl Default for FeeStructure {
+    fn default() -> Self {
+        Self { open: dec!(16), close: dec!(4), execute: dec!(4), bounty: dec!(10) }
     }
+}
 
+impl FeeStructure {
     pub fn set(&mut self, open: Option<Decimal>, close: Option<Decimal>, execute: Option<Decimal>) {
         self.open = open.unwrap_or(self.open);
         self.close = close.unwrap_or(self.close);
         self.execute = execute.unwrap_or(self.execute);
     }
+
+    pub fn set_bounty(&mut self, bounty: Decimal) {
+        assert!(bounty >= dec!(0), "Bounty cannot be negative");
+
+        self.bounty = bounty;
+    }
 }
@@ -1,5 +1,14 @@
 /* ------------------ Imports ----------------- */
+use crate::access::AccessMode;
+use crate::basket::Basket;
+use crate::invariants::ExecutionInvariants;
+use crate::limits::RiskLimits;
+use crate::mode::LeverageMode;
+use crate::triggers::Triggers;
+use crate::weft::NFTCollateralPositionData;
 use scrypto::prelude::*;
+use shared::flash_loans::FlashLoanProvider;
+use shared::oracles::PriceOracle;
 
 /* ------------------ Cluster ----------------- */
 #[derive(ScryptoSbor, Debug, Clone)]
@@ -9,8 +18,19 @@ pub struct ClusterInfo {
     pub linked: bool,
     pub supply_res: ResourceAddress,
     pub debt_res: ResourceAddress,
+    pub mode: LeverageMode,
+    pub long_res: ResourceAddress,
+    pub short_res: ResourceAddress,
+    pub basket: Basket,
     pub account_count: u64,
     pub execution_term_manager: NonFungibleResourceManager,
+    pub invariants: ExecutionInvariants,
+    pub oracle: Option<PriceOracle>,
+    pub flash_loan: Option<FlashLoanProvider>,
+    pub risk_limits: RiskLimits,
+    pub total_collateral_value: Decimal,
+    pub access_mode: AccessMode,
+    pub allow_list_size: u64,
     pub fee_info: FeeInfo,
 }
 
@@ -19,6 +39,7 @@ pub struct FeeInfo {
     pub open: Decimal,
     pub close: Decimal,
     pub execute: Decimal,
+    pub bounty: Decimal,
 }
 
 /* ------------------ Account ----------------- */
@@ -30,4 +51,50 @@ pub struct AccountInfo {
     pub debt: Decimal,
     pub debt_value: Decimal,
     pub health: Decimal,
+    pub collaterals: IndexMap<ResourceAddress, PositionInfo>,
+    pub loans: IndexMap<ResourceAddress, PositionInfo>,
+    pub nft_collaterals: IndexMap<ResourceAddress, IndexMap<NonFungibleLocalId, NFTPositionInfo>>,
+    pub nft_value: Decimal,
+    pub triggers: Triggers,
+    pub cost_basis: Decimal,
+    pub unrealised_pnl: Decimal,
+    pub realised_pnl: Decimal,
+    pub principal: Decimal,
+    pub accrued_interest: Decimal,
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct PositionInfo {
+    pub amount: Decimal,
+    pub price: Decimal,
+    pub value: Decimal,
+}
+
+impl PositionInfo {
+    pub fn new(amount: Decimal, price: Decimal, value: Decimal) -> Self {
+        Self { amount, price, value }
+    }
+}
+
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct NFTPositionInfo {
+    pub value: Decimal,
+    pub discounted_value: Decimal,
+    pub underlying: IndexMap<ResourceAddress, PositionInfo>,
+}
+
+impl From<&NFTCollateralPositionData> for NFTPositionInfo {
+    fn from(position: &NFTCollateralPositionData) -> Self {
+        let underlying = position
+            .underlying_positions
+            .iter()
+            .map(|(&resource, underlying)| (resource, PositionInfo::new(underlying.amount, underlying.price, underlying.value)))
+            .collect();
+
+        Self {
+            value: position.value.value,
+            discounted_value: position.value.discounted_value,
+            underlying,
+        }
+    }
 }
@@ -0,0 +1,170 @@
+/* ------------------ Imports ----------------- */
+use crate::weft::{CDPData, CollateralConfigVersion};
+use scrypto::prelude::*;
+
+/* ---------------- Invariants ---------------- */
+/// Constraints on the change of a CDP over a single execution, enforced in `end_execution`.
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct ExecutionInvariants {
+    /// Maximum increase of each debt, in loan units.
+    pub max_debt_increase: Option<Decimal>,
+    /// Minimum share of each collateral which must be retained; e.g. `0.9` for 90%.
+    pub min_collateral_retained: Option<Decimal>,
+    /// Maximum relative change of the leverage, measured as primary debt units per unit of primary collateral; e.g.
+    /// `0.1` for 10%.
+    pub max_leverage_change: Option<Decimal>,
+    /// Whether the efficiency mode and config versions of the positions must remain unchanged.
+    pub freeze_config: bool,
+}
+
+impl ExecutionInvariants {
+    pub fn none() -> Self {
+        Self {
+            max_debt_increase: None,
+            min_collateral_retained: None,
+            max_leverage_change: None,
+            freeze_config: false,
+        }
+    }
+
+    pub fn new(
+        max_debt_increase: Option<Decimal>,
+        min_collateral_retained: Option<Decimal>,
+        max_leverage_change: Option<Decimal>,
+        freeze_config: bool,
+    ) -> Self {
+        if let Some(max_debt_increase) = max_debt_increase {
+            assert!(max_debt_increase >= dec!(0), "Maximum debt increase cannot be negative");
+        }
+        if let Some(min_collateral_retained) = min_collateral_retained {
+            assert!(
+                min_collateral_retained >= dec!(0) && min_collateral_retained <= dec!(1),
+                "Minimum collateral retained must be between 0 and 1"
+            );
+        }
+        if let Some(max_leverage_change) = max_leverage_change {
+            assert!(max_leverage_change >= dec!(0), "Maximum leverage change cannot be negative");
+        }
+
+        Self { max_debt_increase, min_collateral_retained, max_leverage_change, freeze_config }
+    }
+
+    /// Asserts that the change of a CDP from `before` to `after` satisfies every invariant. The debt, collateral and
+    /// config invariants apply to every position of the basket; the leverage invariant to the primary positions.
+    pub fn check(&self, before: &ExecutionSnapshot, after: &ExecutionSnapshot) {
+        if let Some(max_debt_increase) = self.max_debt_increase {
+            for (resource, &units) in after.loans.iter() {
+                assert!(
+                    units <= before.loan_of(resource) + max_debt_increase,
+                    "Execution exceeds the maximum debt increase"
+                );
+            }
+        }
+
+        if let Some(min_collateral_retained) = self.min_collateral_retained {
+            for (resource, &amount) in before.collaterals.iter() {
+                assert!(
+                    after.collateral_of(resource) >= amount * min_collateral_retained,
+                    "Execution does not retain the minimum collateral"
+                );
+            }
+        }
+
+        if let Some(max_leverage_change) = self.max_leverage_change {
+            let (leverage_before, leverage_after) = (before.leverage(), after.leverage());
+
+            if leverage_before == dec!(0) {
+                assert_eq!(leverage_after, dec!(0), "Execution exceeds the maximum leverage change");
+            } else {
+                let change = (leverage_after / leverage_before - dec!(1)).checked_abs().unwrap();
+                assert!(change <= max_leverage_change, "Execution exceeds the maximum leverage change");
+            }
+        }
+
+        if self.freeze_config {
+            for (resource, config_before) in before.collateral_configs.iter() {
+                if let Some(config_after) = after.collateral_configs.get(resource) {
+                    assert_eq!(config_before, config_after, "Execution changed the collateral config version");
+                }
+            }
+            for (resource, config_before) in before.loan_configs.iter() {
+                if let Some(config_after) = after.loan_configs.get(resource) {
+                    assert_eq!(config_before, config_after, "Execution changed the loan config version");
+                }
+            }
+        }
+    }
+}
+
+/* ----------------- Snapshot ----------------- */
+/// State of every position of a CDP, taken from its `CDPData`. The primary supply and debt positions are broken out
+/// for the per-account limits and leverage; the maps hold every collateral and loan of the basket.
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct ExecutionSnapshot {
+    pub collateral: Decimal,
+    pub debt: Decimal,
+    /// Amount of each collateral asset.
+    pub collaterals: IndexMap<ResourceAddress, Decimal>,
+    /// Loan units of each debt asset.
+    pub loans: IndexMap<ResourceAddress, Decimal>,
+    /// Local IDs of each NFT collateral resource.
+    pub nft_collaterals: IndexMap<ResourceAddress, IndexSet<NonFungibleLocalId>>,
+    pub collateral_configs: IndexMap<ResourceAddress, CollateralConfigVersion>,
+    pub loan_configs: IndexMap<ResourceAddress, u64>,
+}
+
+impl ExecutionSnapshot {
+    pub fn new(cdp: &CDPData, supply: ResourceAddress, debt: ResourceAddress) -> Self {
+        let collaterals: IndexMap<ResourceAddress, Decimal> = cdp
+            .collaterals
+            .iter()
+            .map(|(&resource, collateral)| (resource, collateral.amount))
+            .collect();
+        let loans: IndexMap<ResourceAddress, Decimal> = cdp.loans.iter().map(|(&resource, loan)| (resource, loan.units)).collect();
+        let nft_collaterals = cdp
+            .nft_collaterals
+            .iter()
+            .map(|(&resource, collateral)| (resource, collateral.nft_ids.clone()))
+            .collect();
+
+        Self {
+            collateral: collaterals.get(&supply).copied().unwrap_or(dec!(0)),
+            debt: loans.get(&debt).copied().unwrap_or(dec!(0)),
+            collaterals,
+            loans,
+            nft_collaterals,
+            collateral_configs: cdp
+                .collaterals
+                .iter()
+                .map(|(&resource, collateral)| (resource, collateral.config_version))
+                .collect(),
+            loan_configs: cdp.loans.iter().map(|(&resource, loan)| (resource, loan.config_version)).collect(),
+        }
+    }
+
+    /// Whether the CDP holds no positions at all.
+    pub fn is_empty(&self) -> bool {
+        self.collaterals.values().all(|&amount| amount == dec!(0))
+            && self.loans.values().all(|&units| units == dec!(0))
+            && self.nft_collaterals.values().all(|ids| ids.is_empty())
+    }
+
+    /// Amount of the given collateral asset; zero if there is no such position.
+    pub fn collateral_of(&self, resource: &ResourceAddress) -> Decimal {
+        self.collaterals.get(resource).copied().unwrap_or(dec!(0))
+    }
+
+    /// Loan units of the given debt asset; zero if there is no such position.
+    pub fn loan_of(&self, resource: &ResourceAddress) -> Decimal {
+        self.loans.get(resource).copied().unwrap_or(dec!(0))
+    }
+
+    /// Debt units per unit of collateral of the primary positions; zero if there is no collateral.
+    pub fn leverage(&self) -> Decimal {
+        if self.collateral == dec!(0) {
+            return dec!(0);
+        }
+
+        self.debt / self.collateral
+    }
+}
@@ -0,0 +1,107 @@
+/* ------------------ Imports ----------------- */
+use crate::invariants::ExecutionSnapshot;
+use crate::weft::CDPHealthChecker;
+use scrypto::prelude::*;
+use shared::utils::now;
+
+/// Maximum number of entries returned by a single page of an account's history.
+pub const MAX_PAGE_SIZE: u64 = 100;
+
+/* ------------------ Journal ----------------- */
+/// Append-only history of an account's position, kept across reopens of the account.
+#[derive(ScryptoSbor)]
+pub struct Journal {
+    pub entries: KeyValueStore<u64, JournalEntry>,
+    pub length: u64,
+}
+
+impl Default for Journal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Journal {
+    pub fn new() -> Self {
+        Self { entries: KeyValueStore::new(), length: 0 }
+    }
+
+    /// Appends an entry under the next sequence number.
+    pub fn append(&mut self, entry: JournalEntry) {
+        self.entries.insert(self.length, entry);
+        self.length += 1;
+    }
+
+    /// Returns up to `limit` entries, in sequence, starting at `offset`.
+    ///
+    /// # Panics
+    /// - If the limit exceeds `MAX_PAGE_SIZE`.
+    pub fn page(&self, offset: u64, limit: u64) -> Vec<JournalEntry> {
+        assert!(limit <= MAX_PAGE_SIZE, "Page size exceeds the maximum of {}", MAX_PAGE_SIZE);
+
+        let end = offset.saturating_add(limit).min(self.length);
+
+        (offset..end).map(|sequence| self.entries.get(&sequence).unwrap().clone()).collect()
+    }
+}
+
+/* ------------------ Entries ----------------- */
+/// Event of an account's lifecycle at which a journal entry is recorded.
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JournalEvent {
+    Open,
+    Execution,
+    Close,
+}
+
+/// State of an account's supply and debt positions at a journal event.
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct JournalEntry {
+    pub event: JournalEvent,
+    pub timestamp: Instant,
+    /// Amount of the supply asset held as collateral.
+    pub collateral: Decimal,
+    /// Loan units of the debt asset.
+    pub debt_units: Decimal,
+    /// Valuation of the positions by Weft at the event; taken on every event, including on open.
+    pub valuation: Option<JournalValuation>,
+}
+
+impl JournalEntry {
+    pub fn new(event: JournalEvent, snapshot: &ExecutionSnapshot, valuation: Option<JournalValuation>) -> Self {
+        Self {
+            event,
+            timestamp: Instant::new(now()),
+            collateral: snapshot.collateral,
+            debt_units: snapshot.debt,
+            valuation,
+        }
+    }
+}
+
+/// Valuation of an account's supply and debt positions by Weft.
+#[derive(ScryptoSbor, Debug, Clone, Default)]
+pub struct JournalValuation {
+    /// Amount of the debt asset owed, including accrued interest.
+    pub debt: Decimal,
+    pub collateral_value: Decimal,
+    pub debt_value: Decimal,
+    /// Weft's health LTV of the CDP; the loan value over the collateral value weighted by the LTV ratios.
+    pub health: Decimal,
+}
+
+impl JournalValuation {
+    /// Values the positions of the given collateral and loan assets in the CDP's health breakdown.
+    pub fn new(cdp_health: &CDPHealthChecker, collateral: ResourceAddress, loan: ResourceAddress) -> Self {
+        let collateral_value = cdp_health
+            .collateral_positions
+            .get(&collateral)
+            .map_or(dec!(0), |position| position.value);
+        let (debt, debt_value) = cdp_health
+            .loan_positions
+            .get(&loan)
+            .map_or((dec!(0), dec!(0)), |position| (position.amount, position.value));
+
+        Self { debt, collateral_value, debt_value, health: cdp_health.health_ltv }
+    }
+}
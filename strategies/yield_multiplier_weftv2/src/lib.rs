@@ -1,6 +1,16 @@
+// Scrypto's generated test bindings take an extra `env` argument beyond each function's own
+#![allow(clippy::too_many_arguments)]
+
+pub mod access;
 pub mod accounts;
+pub mod basket;
 pub mod cluster;
 pub mod fees;
 pub mod info;
+pub mod invariants;
+pub mod journal;
+pub mod limits;
+pub mod mode;
 pub mod services;
+pub mod triggers;
 pub mod weft;
@@ -0,0 +1,105 @@
+/* ------------------ Imports ----------------- */
+use crate::invariants::ExecutionSnapshot;
+use scrypto::prelude::*;
+
+/* ---------------- Risk Limits --------------- */
+/// Caps on the size of the cluster and of each account, enforced in `open_account` and `end_execution`.
+#[derive(ScryptoSbor, Debug, Clone)]
+pub struct RiskLimits {
+    /// Maximum number of accounts open at once.
+    pub max_accounts: Option<u64>,
+    /// Maximum aggregate collateral value of every account, in the lending market's price unit. Checked against the
+    /// sum of each account's value as of its last valuation, so it is a soft cap while prices move.
+    pub max_total_collateral_value: Option<Decimal>,
+    /// Minimum collateral of an account with open positions, in amounts of the collateral asset.
+    pub min_collateral: Option<Decimal>,
+    /// Maximum collateral of an account, in amounts of the collateral asset.
+    pub max_collateral: Option<Decimal>,
+    /// Minimum debt of an account with an open loan, in loan units.
+    pub min_debt: Option<Decimal>,
+    /// Maximum debt of an account, in loan units.
+    pub max_debt: Option<Decimal>,
+}
+
+impl RiskLimits {
+    pub fn none() -> Self {
+        Self {
+            max_accounts: None,
+            max_total_collateral_value: None,
+            min_collateral: None,
+            max_collateral: None,
+            min_debt: None,
+            max_debt: None,
+        }
+    }
+
+    pub fn new(
+        max_accounts: Option<u64>,
+        max_total_collateral_value: Option<Decimal>,
+        min_collateral: Option<Decimal>,
+        max_collateral: Option<Decimal>,
+        min_debt: Option<Decimal>,
+        max_debt: Option<Decimal>,
+    ) -> Self {
+        for limit in [max_total_collateral_value, min_collateral, max_collateral, min_debt, max_debt]
+            .into_iter()
+            .flatten()
+        {
+            assert!(limit >= dec!(0), "Risk limits cannot be negative");
+        }
+        if let (Some(min_collateral), Some(max_collateral)) = (min_collateral, max_collateral) {
+            assert!(min_collateral <= max_collateral, "Minimum collateral exceeds the maximum");
+        }
+        if let (Some(min_debt), Some(max_debt)) = (min_debt, max_debt) {
+            assert!(min_debt <= max_debt, "Minimum debt exceeds the maximum");
+        }
+
+        Self {
+            max_accounts,
+            max_total_collateral_value,
+            min_collateral,
+            max_collateral,
+            min_debt,
+            max_debt,
+        }
+    }
+
+    /// Asserts that another account can be opened alongside the `account_count` already open.
+    pub fn check_accounts(&self, account_count: u64) {
+        if let Some(max_accounts) = self.max_accounts {
+            assert!(account_count < max_accounts, "Cluster has reached the maximum number of accounts");
+        }
+    }
+
+    /// Asserts that the positions of a CDP are within the per-account limits. An empty CDP, or one without a loan,
+    /// is exempt from the respective minimum, so positions can always be unwound.
+    pub fn check_position(&self, snapshot: &ExecutionSnapshot) {
+        if let Some(max_collateral) = self.max_collateral {
+            assert!(snapshot.collateral <= max_collateral, "Account exceeds the maximum collateral");
+        }
+        if let Some(min_collateral) = self.min_collateral {
+            assert!(
+                snapshot.is_empty() || snapshot.collateral >= min_collateral,
+                "Account is below the minimum collateral"
+            );
+        }
+
+        if let Some(max_debt) = self.max_debt {
+            assert!(snapshot.debt <= max_debt, "Account exceeds the maximum debt");
+        }
+        if let Some(min_debt) = self.min_debt {
+            assert!(snapshot.debt == dec!(0) || snapshot.debt >= min_debt, "Account is below the minimum debt");
+        }
+    }
+
+    /// Asserts that the aggregate collateral value is within its cap. Only enforced when it grows, so accounts can
+    /// always deleverage after the cap is lowered, or prices rise past it.
+    pub fn check_total_collateral_value(&self, before: Decimal, after: Decimal) {
+        if let Some(max_total_collateral_value) = self.max_total_collateral_value {
+            assert!(
+                after <= before || after <= max_total_collateral_value,
+                "Cluster exceeds the maximum total collateral value"
+            );
+        }
+    }
+}
@@ -0,0 +1,25 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+
+/* ------------------- Mode ------------------- */
+/// Direction in which a cluster levers its supply and debt assets, fixed at instantiation.
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeverageMode {
+    /// Levers up the supply asset: the supply asset is the CDP's collateral, and the debt asset is borrowed
+    /// and swapped into it, as in `open_position.rtm`.
+    Long,
+    /// The inverse of `Long`: the debt asset is the CDP's collateral, and the supply asset is borrowed
+    /// and swapped into it.
+    Short,
+}
+
+impl LeverageMode {
+    /// Returns the CDP's collateral and loan asset for the given supply and debt assets.
+    /// The mapping is its own inverse; applied to a collateral and loan asset, it returns the supply and debt asset.
+    pub fn cdp_roles(&self, supply: ResourceAddress, debt: ResourceAddress) -> (ResourceAddress, ResourceAddress) {
+        match self {
+            LeverageMode::Long => (supply, debt),
+            LeverageMode::Short => (debt, supply),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+/* ------------------ Imports ----------------- */
+use crate::mode::LeverageMode;
+use scrypto::prelude::*;
+
+/* ----------------- Triggers ----------------- */
+/// Price levels at which a keeper may unwind an account, set on the price of the supply asset in the debt asset.
+/// A `Long` position loses as the price falls, and a `Short` position as it rises; the side of each trigger
+/// follows the cluster's mode.
+#[derive(ScryptoSbor, Debug, Clone, Default)]
+pub struct Triggers {
+    /// Price at which the position is closed at a loss; at or below it when `Long`, at or above it when `Short`.
+    pub stop_loss: Option<Decimal>,
+    /// Price at which the position is closed at a profit; at or above it when `Long`, at or below it when `Short`.
+    pub take_profit: Option<Decimal>,
+}
+
+impl Triggers {
+    /// Creates a new set of triggers for a position levered in the given mode.
+    ///
+    /// # Panics
+    /// - If either price is not positive.
+    /// - If the stop-loss is not on the losing side of the take-profit; below it when `Long`, above it when `Short`.
+    pub fn new(mode: LeverageMode, stop_loss: Option<Decimal>, take_profit: Option<Decimal>) -> Self {
+        for price in [stop_loss, take_profit].into_iter().flatten() {
+            assert!(price > dec!(0), "Trigger price must be positive");
+        }
+        if let (Some(stop_loss), Some(take_profit)) = (stop_loss, take_profit) {
+            match mode {
+                LeverageMode::Long => assert!(stop_loss < take_profit, "Stop-loss must be below the take-profit"),
+                LeverageMode::Short => assert!(stop_loss > take_profit, "Stop-loss must be above the take-profit"),
+            }
+        }
+
+        Self { stop_loss, take_profit }
+    }
+
+    /// Whether any trigger is set.
+    pub fn is_set(&self) -> bool {
+        self.stop_loss.is_some() || self.take_profit.is_some()
+    }
+
+    /// Whether the given price reaches either trigger of a position levered in the given mode.
+    pub fn is_hit(&self, mode: LeverageMode, price: Decimal) -> bool {
+        match mode {
+            LeverageMode::Long => {
+                self.stop_loss.is_some_and(|stop_loss| price <= stop_loss) || self.take_profit.is_some_and(|take_profit| price >= take_profit)
+            }
+            LeverageMode::Short => {
+                self.stop_loss.is_some_and(|stop_loss| price >= stop_loss) || self.take_profit.is_some_and(|take_profit| price <= take_profit)
+            }
+        }
+    }
+}
+
+/* ----------------- Claimable ---------------- */
+/// Proceeds of a user's unwound accounts, held by the cluster until claimed.
+#[derive(ScryptoSbor, Debug)]
+pub struct Claimable {
+    pub collateral: FungibleVault,
+    pub cdps: NonFungibleVault,
+}
+
+impl Claimable {
+    pub fn new(collateral: ResourceAddress, cdp: ResourceAddress) -> Self {
+        Self { collateral: FungibleVault::new(collateral), cdps: NonFungibleVault::new(cdp) }
+    }
+}
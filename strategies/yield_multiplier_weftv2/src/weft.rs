@@ -175,3 +175,44 @@ pub struct LoanInfo {
     pub units: Decimal,
     pub config_version: u64,
 }
+
+/* ------------------ Market ------------------ */
+/// Thin wrapper around the Weft V2 lending market, for the calls made by the cluster on its CDPs.
+pub struct WeftMarket {
+    component: Global<AnyComponent>,
+}
+
+impl WeftMarket {
+    pub fn new(address: ComponentAddress) -> Self {
+        Self { component: address.into() }
+    }
+
+    /// Deposits collateral into the CDP.
+    pub fn add_collateral(&self, cdp_proof: NonFungibleProof, collaterals: Vec<Bucket>) {
+        self.component.call_raw::<()>("add_collateral", scrypto_args!(cdp_proof, collaterals));
+    }
+
+    /// Withdraws collateral from the CDP; each withdrawal is a resource and an amount.
+    pub fn remove_collateral(&self, cdp_proof: NonFungibleProof, withdrawals: Vec<(ResourceAddress, Decimal, bool)>) -> Vec<Bucket> {
+        self.component.call_raw("remove_collateral", scrypto_args!(cdp_proof, withdrawals))
+    }
+
+    /// Borrows against the CDP; each loan is a resource and an amount.
+    pub fn borrow(&self, cdp_proof: NonFungibleProof, loans: Vec<(ResourceAddress, Decimal)>) -> Vec<Bucket> {
+        self.component.call_raw("borrow", scrypto_args!(cdp_proof, loans))
+    }
+
+    /// Repays the CDP's loans, returning any excess payment.
+    pub fn repay(&self, cdp_proof: NonFungibleProof, payments: Vec<Bucket>) -> Vec<Bucket> {
+        self.component.call_raw("repay", scrypto_args!(cdp_proof, payments))
+    }
+
+    /// Returns the health breakdown of the given CDP.
+    pub fn get_cdp(&self, cdp_id: &NonFungibleLocalId) -> CDPHealthChecker {
+        let mut cdp_health_map = self
+            .component
+            .call_raw::<IndexMap<NonFungibleLocalId, CDPHealthChecker>>("get_cdp", scrypto_args!(indexset![cdp_id.clone()]));
+
+        cdp_health_map.swap_remove(cdp_id).expect("CDP not found")
+    }
+}
@@ -0,0 +1,2 @@
+pub mod pools;
+pub mod router;
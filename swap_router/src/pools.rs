@@ -0,0 +1,103 @@
+/* ------------------ Imports ----------------- */
+use scrypto::prelude::*;
+
+/* ---------------- Interfaces ---------------- */
+/// The swap interface exposed by a DEX pool, as the router must call each one differently.
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PoolInterface {
+    /// Ociswap basic and precision pools; `swap(input) -> output`.
+    Ociswap,
+    /// DefiPlaza V2 pairs; `swap(input, output_resource) -> (output, remainder)`.
+    DefiPlaza,
+    /// Caviarnine QuickSwap pools; `swap(input) -> (output, remainder)`.
+    Caviarnine,
+}
+
+/* ------------------- Legs ------------------- */
+/// A single hop of a route, swapping `input` for `output` through a pool.
+#[derive(ScryptoSbor, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RouteLeg {
+    pub pool: ComponentAddress,
+    pub interface: PoolInterface,
+    pub input: ResourceAddress,
+    pub output: ResourceAddress,
+}
+
+impl RouteLeg {
+    /// Swaps the input through the leg's pool.
+    ///
+    /// # Returns
+    /// - `FungibleBucket`: The output of the swap.
+    /// - `Option<FungibleBucket>`: Any part of the input the pool did not consume.
+    ///
+    /// # Panics
+    /// - If the input is not the leg's input resource.
+    /// - If the pool returns anything other than the leg's output resource.
+    pub fn swap(&self, input: FungibleBucket) -> (FungibleBucket, Option<FungibleBucket>) {
+        assert_eq!(input.resource_address(), self.input, "Input does not match the route leg");
+
+        let pool: Global<AnyComponent> = self.pool.into();
+        let (output, remainder) = match self.interface {
+            PoolInterface::Ociswap => (pool.call_raw::<FungibleBucket>("swap", scrypto_args!(input)), None),
+            PoolInterface::DefiPlaza => pool.call_raw::<(FungibleBucket, Option<FungibleBucket>)>("swap", scrypto_args!(input, self.output)),
+            PoolInterface::Caviarnine => {
+                let (output, remainder) = pool.call_raw::<(FungibleBucket, FungibleBucket)>("swap", scrypto_args!(input));
+                (output, Some(remainder))
+            }
+        };
+        assert_eq!(output.resource_address(), self.output, "Pool returned an unexpected resource");
+
+        // Drop empty remainders
+        let remainder = match remainder {
+            Some(remainder) if remainder.is_empty() => {
+                remainder.drop_empty();
+                None
+            }
+            remainder => remainder,
+        };
+
+        (output, remainder)
+    }
+}
+
+/* ------------------ Routes ------------------ */
+/// Maximum number of legs a route may have.
+pub const MAX_ROUTE_LEGS: usize = 4;
+
+/// An ordered sequence of legs, each swapping the previous leg's output.
+#[derive(ScryptoSbor, Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    pub legs: Vec<RouteLeg>,
+}
+
+impl Route {
+    /// Creates a new route from its legs.
+    ///
+    /// # Panics
+    /// - If there are no legs, or more than `MAX_ROUTE_LEGS`.
+    /// - If a leg swaps a resource for itself.
+    /// - If a leg's input is not the previous leg's output.
+    pub fn new(legs: Vec<RouteLeg>) -> Self {
+        assert!(!legs.is_empty(), "Route must have at least one leg");
+        assert!(legs.len() <= MAX_ROUTE_LEGS, "Route cannot have more than {} legs", MAX_ROUTE_LEGS);
+
+        for leg in legs.iter() {
+            assert_ne!(leg.input, leg.output, "Route leg cannot swap a resource for itself");
+        }
+        for pair in legs.windows(2) {
+            assert_eq!(pair[0].output, pair[1].input, "Route legs are not contiguous");
+        }
+
+        Self { legs }
+    }
+
+    /// Returns the resource the route takes in.
+    pub fn input(&self) -> ResourceAddress {
+        self.legs.first().unwrap().input
+    }
+
+    /// Returns the resource the route pays out.
+    pub fn output(&self) -> ResourceAddress {
+        self.legs.last().unwrap().output
+    }
+}
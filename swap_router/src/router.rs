@@ -0,0 +1,146 @@
+/* ------------------ Imports ----------------- */
+// Modules
+use crate::pools::{PoolInterface, Route, RouteLeg};
+// Libraries
+use scrypto::prelude::*;
+
+/* ----------------- Blueprint ---------------- */
+type Unit = ();
+
+#[blueprint]
+#[types(Unit, ResourceAddress, ComponentAddress, Decimal, u64, Route, RouteLeg, PoolInterface)]
+mod swap_router {
+    //] --------------- Scrypto Setup -------------- */
+    enable_method_auth! {
+        roles {
+            can_manage_routes => updatable_by: [OWNER];
+        },
+        methods {
+            // Routes
+            set_route    => restrict_to: [can_manage_routes, OWNER];
+            remove_route => restrict_to: [can_manage_routes, OWNER];
+            get_route    => PUBLIC;
+            // Swaps
+            swap => PUBLIC;
+        }
+    }
+
+    //] ------------- Router Blueprint ------------- */
+    struct SwapRouter {
+        routes: KeyValueStore<(ResourceAddress, ResourceAddress), Route>,
+        route_count: u64,
+    }
+
+    impl SwapRouter {
+        /// Instantiates a new `SwapRouter` component, routing swaps between resources through configured DEX pools.
+        ///
+        /// # Parameters
+        /// - `owner_rule`: Access rule defining the owner of the router.
+        /// - `admin_rule`: Access rule defining the admins of the router, who manage its routes.
+        ///
+        /// # Returns
+        /// A globally accessible `SwapRouter` component instance.
+        pub fn instantiate(owner_rule: AccessRule, admin_rule: AccessRule) -> Global<SwapRouter> {
+            // Component owner
+            let owner_role: OwnerRole = OwnerRole::Fixed(owner_rule);
+
+            //] Component Instantisation
+            // Metadata
+            let component_metadata = metadata! {
+                roles {
+                    metadata_setter         => OWNER;
+                    metadata_setter_updater => OWNER;
+                    metadata_locker         => OWNER;
+                    metadata_locker_updater => rule!(deny_all);
+                },
+                init {
+                    "name"        => "L3//Swap Router", locked;
+                    "description" => "Lattic3 component routing swaps across Radix DEX pools.", locked;
+                }
+            };
+
+            // Roles
+            let component_roles = roles! {
+                can_manage_routes => admin_rule;
+            };
+
+            // Instantisation
+            let initial_state = Self { routes: KeyValueStore::new(), route_count: 0 };
+
+            initial_state
+                .instantiate()
+                .prepare_to_globalize(owner_role)
+                .roles(component_roles)
+                .metadata(component_metadata)
+                .globalize()
+        }
+
+        //] ------------------ Routes ------------------ */
+        /// Sets the route for swapping the first leg's input for the last leg's output, replacing any existing route.
+        ///
+        /// # Parameters
+        /// - `legs`: The ordered legs of the route.
+        ///
+        /// # Panics
+        /// - If the legs do not form a valid route.
+        pub fn set_route(&mut self, legs: Vec<RouteLeg>) {
+            let route = Route::new(legs);
+            let key = (route.input(), route.output());
+
+            if self.routes.get(&key).is_none() {
+                self.route_count += 1;
+            }
+            self.routes.insert(key, route);
+        }
+
+        /// Removes the route for swapping `input` for `output`.
+        ///
+        /// # Panics
+        /// - If there is no such route.
+        pub fn remove_route(&mut self, input: ResourceAddress, output: ResourceAddress) {
+            self.routes.remove(&(input, output)).expect("No route for this pair");
+            self.route_count -= 1;
+        }
+
+        /// Returns the route for swapping `input` for `output`, if any.
+        pub fn get_route(&self, input: ResourceAddress, output: ResourceAddress) -> Option<Route> {
+            self.routes.get(&(input, output)).map(|route| route.clone())
+        }
+
+        //] ------------------ Swaps ------------------- */
+        /// Swaps the input for the `output` resource along the configured route.
+        ///
+        /// # Parameters
+        /// - `input`: The resource to swap.
+        /// - `output`: The resource to receive.
+        /// - `min_output`: The minimum amount of `output` to receive.
+        ///
+        /// # Returns
+        /// - `FungibleBucket`: The output of the swap.
+        /// - `Vec<FungibleBucket>`: Any parts of the input, or of intermediate outputs, the pools did not consume.
+        ///
+        /// # Panics
+        /// - If there is no route for the pair.
+        /// - If the output is less than `min_output`.
+        pub fn swap(&mut self, input: FungibleBucket, output: ResourceAddress, min_output: Decimal) -> (FungibleBucket, Vec<FungibleBucket>) {
+            let route = self.get_route(input.resource_address(), output).expect("No route for this pair");
+
+            let mut remainders: Vec<FungibleBucket> = Vec::new();
+            let mut bucket = input;
+            for leg in route.legs.iter() {
+                let (leg_output, remainder) = leg.swap(bucket);
+                remainders.extend(remainder);
+                bucket = leg_output;
+            }
+
+            assert!(
+                bucket.amount() >= min_output,
+                "Output of {} is below the minimum of {}",
+                bucket.amount(),
+                min_output
+            );
+
+            (bucket, remainders)
+        }
+    }
+}
@@ -0,0 +1,7 @@
+/* ------------------ Imports ----------------- */
+use scrypto_test::prelude::*;
+use swap_router::router::swap_router_test::*;
+
+/* ------------------- Tests ------------------ */
+#[test]
+fn test() {}